@@ -18,7 +18,7 @@ use node_runtime::{
 	AuthorityDiscoveryConfig, BabeConfig, BalancesConfig, CouncilConfig,
 	DemocracyConfig,GrandpaConfig, ImOnlineConfig, SessionConfig, SessionKeys, StakerStatus,
 	StakingConfig, ElectionsConfig, IndicesConfig, SudoConfig, SystemConfig,
-	TechnicalCommitteeConfig, VestingConfig, wasm_binary_unwrap,
+	TechnicalCommitteeConfig, VestingConfig, RTokenRegistryConfig, wasm_binary_unwrap,
 };
 use node_runtime::Block;
 use node_runtime::constants::currency::*;
@@ -386,6 +386,10 @@ pub fn testnet_genesis(
 		pallet_membership_Instance1: Some(Default::default()),
 		pallet_treasury: Some(Default::default()),
 		pallet_vesting: Some(Default::default()),
+		rtoken_registry: Some(RTokenRegistryConfig {
+			assets: rtoken_registry::builtin_assets(),
+		}),
+		rdex_swap: Some(Default::default()),
 	}
 }
 
@@ -513,6 +517,10 @@ pub fn genesis(
 		pallet_vesting: Some(VestingConfig {
 			vesting: vesting,
 		}),
+		rtoken_registry: Some(RTokenRegistryConfig {
+			assets: rtoken_registry::builtin_assets(),
+		}),
+		rdex_swap: Some(Default::default()),
 	}
 }
 
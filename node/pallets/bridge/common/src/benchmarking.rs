@@ -0,0 +1,146 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the BridgeCommon Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+
+const SEED: u32 = 0;
+const DEST_ID: ChainId = ETH_CHAIN_ID;
+
+fn resource_id() -> ResourceId {
+    derive_resource_id(DEST_ID, b"benchmark")
+}
+
+/// A `remark` call of the system pallet, which is the first pallet of the runtime.
+/// Its own weight is added on top of `acknowledge_proposal`, so the cheapest call is used.
+fn remark_proposal<T: Trait>() -> Result<T::Proposal, &'static str> {
+    let mut call = vec![0u8];
+    frame_system::Call::<T>::remark(vec![]).encode_to(&mut call);
+    T::Proposal::decode(&mut &call[..]).map_err(|_| "remark is not a valid proposal")
+}
+
+benchmarks! {
+    _ { }
+
+    add_resource {
+        let method = b"Example.transfer".to_vec();
+    }: _(RawOrigin::Root, resource_id(), method.clone())
+    verify {
+        assert_eq!(Resources::get(resource_id()), Some(method));
+    }
+
+    remove_resource {
+        Resources::insert(resource_id(), b"Example.transfer".to_vec());
+    }: _(RawOrigin::Root, resource_id())
+    verify {
+        assert!(Resources::get(resource_id()).is_none());
+    }
+
+    map_resource_and_rsymbol {
+    }: _(RawOrigin::Root, resource_id(), RSymbol::RFIS)
+    verify {
+        assert_eq!(Module::<T>::rsymbol_resource(RSymbol::RFIS), Some(resource_id()));
+    }
+
+    unmap_resource_and_rsymbol {
+        registry::Module::<T>::set_resource_id(RSymbol::RFIS, Some(resource_id()))?;
+    }: _(RawOrigin::Root, resource_id(), RSymbol::RFIS)
+    verify {
+        assert!(Module::<T>::rsymbol_resource(RSymbol::RFIS).is_none());
+    }
+
+    map_resource_and_xsymbol {
+    }: _(RawOrigin::Root, resource_id(), XSymbol::WRA)
+    verify {
+        assert_eq!(XsymbolResource::get(XSymbol::WRA), Some(resource_id()));
+    }
+
+    unmap_resource_and_xsymbol {
+        ResourceXsymbol::insert(resource_id(), XSymbol::WRA);
+        XsymbolResource::insert(XSymbol::WRA, resource_id());
+    }: _(RawOrigin::Root, resource_id(), XSymbol::WRA)
+    verify {
+        assert!(XsymbolResource::get(XSymbol::WRA).is_none());
+    }
+
+    acknowledge_proposal {
+        let caller: T::AccountId = whitelisted_caller();
+        brelayers::Relayers::<T>::insert(DEST_ID, &caller, true);
+        brelayers::RelayerThreshold::insert(DEST_ID, 1);
+        Module::<T>::whitelist(DEST_ID)?;
+        Resources::insert(resource_id(), b"System.remark".to_vec());
+        let proposal = remark_proposal::<T>()?;
+    }: _(RawOrigin::Signed(caller), 1, DEST_ID, resource_id(), Box::new(proposal.clone()))
+    verify {
+        let votes = <Votes<T>>::get(DEST_ID, (1, proposal)).unwrap();
+        assert_eq!(votes.status, ProposalStatus::Executed);
+    }
+
+    whitelist_chain {
+    }: _(RawOrigin::Root, DEST_ID)
+    verify {
+        assert!(Module::<T>::chain_whitelisted(DEST_ID));
+    }
+
+    remove_whitelist_chain {
+        Module::<T>::whitelist(DEST_ID)?;
+    }: _(RawOrigin::Root, DEST_ID)
+    verify {
+        assert!(!Module::<T>::chain_whitelisted(DEST_ID));
+    }
+
+    set_proxy_accounts {
+        let proxy: T::AccountId = account("proxy", 0, SEED);
+    }: _(RawOrigin::Root, proxy.clone())
+    verify {
+        assert!(<ProxyAccounts<T>>::contains_key(&proxy));
+    }
+
+    remove_proxy_accounts {
+        let proxy: T::AccountId = account("proxy", 0, SEED);
+        <ProxyAccounts<T>>::insert(&proxy, 0);
+    }: _(RawOrigin::Root, proxy.clone())
+    verify {
+        assert!(!<ProxyAccounts<T>>::contains_key(&proxy));
+    }
+
+    set_chain_fees {
+        let proxy: T::AccountId = whitelisted_caller();
+        <ProxyAccounts<T>>::insert(&proxy, 0);
+        Module::<T>::whitelist(DEST_ID)?;
+    }: _(RawOrigin::Signed(proxy), DEST_ID, 1_000_000_000)
+    verify {
+        assert_eq!(ChainFees::get(DEST_ID), Some(1_000_000_000));
+    }
+
+    set_fees_recipient_account {
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+    }: _(RawOrigin::Root, recipient.clone())
+    verify {
+        assert_eq!(<FeesRecipientAccount<T>>::get(), Some(recipient));
+    }
+
+    set_is_pasued {
+    }: _(RawOrigin::Root, true)
+    verify {
+        assert!(IsPaused::get());
+    }
+
+    set_migrate_target {
+        Module::<T>::whitelist(DEST_ID)?;
+    }: _(RawOrigin::Root, RSymbol::RFIS, DEST_ID)
+    verify {
+        assert_eq!(MigrateTarget::get(RSymbol::RFIS), Some(DEST_ID));
+    }
+}
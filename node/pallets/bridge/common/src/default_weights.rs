@@ -0,0 +1,54 @@
+//! Default weights for the BridgeCommon Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn add_resource() -> Weight {
+        195_000_000 as Weight
+    }
+    fn remove_resource() -> Weight {
+        195_000_000 as Weight
+    }
+    fn map_resource_and_rsymbol() -> Weight {
+        10_000 as Weight
+    }
+    fn unmap_resource_and_rsymbol() -> Weight {
+        10_000 as Weight
+    }
+    fn map_resource_and_xsymbol() -> Weight {
+        10_000 as Weight
+    }
+    fn unmap_resource_and_xsymbol() -> Weight {
+        10_000 as Weight
+    }
+    fn acknowledge_proposal() -> Weight {
+        195_000_000 as Weight
+    }
+    fn whitelist_chain() -> Weight {
+        195_000_000 as Weight
+    }
+    fn remove_whitelist_chain() -> Weight {
+        195_000_000 as Weight
+    }
+    fn set_proxy_accounts() -> Weight {
+        100_000_000 as Weight
+    }
+    fn remove_proxy_accounts() -> Weight {
+        100_000_000 as Weight
+    }
+    fn set_chain_fees() -> Weight {
+        100_000_000 as Weight
+    }
+    fn set_fees_recipient_account() -> Weight {
+        100_000_000 as Weight
+    }
+    fn set_is_pasued() -> Weight {
+        100_000_000 as Weight
+    }
+    fn set_migrate_target() -> Weight {
+        100_000_000 as Weight
+    }
+}
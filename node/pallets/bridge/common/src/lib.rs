@@ -17,7 +17,8 @@ use frame_support::{
     Parameter, decl_error, decl_event, decl_module, decl_storage,
     dispatch::DispatchResult, ensure,
     traits::{EnsureOrigin, Get},
-    weights::{GetDispatchInfo, Pays},
+    weights::{GetDispatchInfo, Pays, Weight},
+    storage::IterableStorageMap,
 };
 
 use frame_system::{self as system, ensure_signed, ensure_root};
@@ -27,18 +28,22 @@ use sp_runtime::{
     traits::{AccountIdConversion, Dispatchable}
 };
 use node_primitives::{ChainId, ETH_CHAIN_ID, BSC_CHAIN_ID, Balance, RSymbol, XSymbol};
+pub use node_primitives::ResourceId;
 use bridge_relayers as brelayers;
+use rtoken_registry as registry;
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 const DEFAULT_RELAYER_THRESHOLD: u32 = 1;
 const MODULE_ID: ModuleId = ModuleId(*b"cb/bridg");
 
 pub type DepositNonce = u64;
-pub type ResourceId = [u8; 32];
 /// Helper function to concatenate a chain ID and some bytes to produce a resource ID.
 /// The common format is (31 bytes unique ID + 1 byte chain ID).
 pub fn derive_resource_id(chain: ChainId, id: &[u8]) -> ResourceId {
@@ -113,7 +118,25 @@ impl<AccountId, BlockNumber: Default> Default for ProposalVotes<AccountId, Block
     }
 }
 
-pub trait Trait: system::Trait + brelayers::Trait {
+pub trait WeightInfo {
+    fn add_resource() -> Weight;
+    fn remove_resource() -> Weight;
+    fn map_resource_and_rsymbol() -> Weight;
+    fn unmap_resource_and_rsymbol() -> Weight;
+    fn map_resource_and_xsymbol() -> Weight;
+    fn unmap_resource_and_xsymbol() -> Weight;
+    fn acknowledge_proposal() -> Weight;
+    fn whitelist_chain() -> Weight;
+    fn remove_whitelist_chain() -> Weight;
+    fn set_proxy_accounts() -> Weight;
+    fn remove_proxy_accounts() -> Weight;
+    fn set_chain_fees() -> Weight;
+    fn set_fees_recipient_account() -> Weight;
+    fn set_is_pasued() -> Weight;
+    fn set_migrate_target() -> Weight;
+}
+
+pub trait Trait: system::Trait + brelayers::Trait + registry::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
     /// Origin used to administer the pallet
     type AdminOrigin: EnsureOrigin<Self::Origin>;
@@ -124,6 +147,8 @@ pub trait Trait: system::Trait + brelayers::Trait {
     type ChainIdentity: Get<ChainId>;
 
     type ProposalLifetime: Get<Self::BlockNumber>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event! {
@@ -227,10 +252,10 @@ decl_storage! {
         /// Utilized by the bridge software to map resource IDs to actual methods
         pub Resources get(fn resources): map hasher(blake2_128_concat) ResourceId => Option<Vec<u8>>;
 
-        /// rId => Rsymbol
-        pub ResourceRsymbol get(fn resource_rsymbol): map hasher(blake2_128_concat) ResourceId => Option<RSymbol>;
-        /// Rsymbol => ResourceId
-        pub RsymbolResource get(fn rsymbol_resource): map hasher(blake2_128_concat) RSymbol => Option<ResourceId>;
+        /// rId => Rsymbol, moved into the rtoken registry and drained on runtime upgrade
+        ResourceRsymbol: map hasher(blake2_128_concat) ResourceId => Option<RSymbol>;
+        /// Rsymbol => ResourceId, moved into the rtoken registry and drained on runtime upgrade
+        RsymbolResource: map hasher(blake2_128_concat) RSymbol => Option<ResourceId>;
 
         /// rId => Xsymbol
         pub ResourceXsymbol get(fn resource_xsymbol): map hasher(blake2_128_concat) ResourceId => Option<XSymbol>;
@@ -252,12 +277,26 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            let weight = registry::Module::<T>::migrate_builtin_assets();
+            let mapped: Vec<(RSymbol, ResourceId)> = RsymbolResource::drain().collect();
+            let unmapped = ResourceRsymbol::drain().count() as Weight;
+            for (sym, resource_id) in mapped.iter() {
+                // every rsymbol mapped before the registry is a builtin one, so this can't fail
+                let _ = registry::Module::<T>::set_resource_id(*sym, Some(*resource_id));
+            }
+
+            // each mapping reads and writes the asset and its reverse resource entry
+            let count = mapped.len() as Weight * 3 + unmapped;
+            weight.saturating_add(T::DbWeight::get().reads_writes(count, count))
+        }
+
         /// Stores a method name on chain under an associated resource ID.
         ///
         /// # <weight>
         /// - O(1) write
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = <T as Trait>::WeightInfo::add_resource()]
         pub fn add_resource(origin, id: ResourceId, method: Vec<u8>) -> DispatchResult {
             Self::ensure_admin(origin)?;
             <Resources>::insert(id, method);
@@ -272,7 +311,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) removal
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = <T as Trait>::WeightInfo::remove_resource()]
         pub fn remove_resource(origin, id: ResourceId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             <Resources>::remove(id);
@@ -280,29 +319,22 @@ decl_module! {
         }
 
         /// Map resourceId to Rsymbol
-        #[weight = 10_000]
+        #[weight = <T as Trait>::WeightInfo::map_resource_and_rsymbol()]
         pub fn map_resource_and_rsymbol(origin, resource_id: ResourceId, sym: RSymbol) -> DispatchResult {
             Self::ensure_admin(origin)?;
-
-            <ResourceRsymbol>::insert(&resource_id, &sym);
-            <RsymbolResource>::insert(&sym, &resource_id);
-
-            Ok(())
+            registry::Module::<T>::set_resource_id(sym, Some(resource_id))
         }
 
         /// Unmap resourceId to Rsymbol
-        #[weight = 10_000]
+        #[weight = <T as Trait>::WeightInfo::unmap_resource_and_rsymbol()]
         pub fn unmap_resource_and_rsymbol(origin, resource_id: ResourceId, sym: RSymbol) -> DispatchResult {
             Self::ensure_admin(origin)?;
-
-            <ResourceRsymbol>::remove(&resource_id);
-            <RsymbolResource>::remove(&sym);
-
-            Ok(())
+            ensure!(Self::resource_rsymbol(resource_id) == Some(sym), Error::<T>::RsymbolNotMapped);
+            registry::Module::<T>::set_resource_id(sym, None)
         }
 
         /// Map resourceId to Xsymbol
-        #[weight = 10_000]
+        #[weight = <T as Trait>::WeightInfo::map_resource_and_xsymbol()]
         pub fn map_resource_and_xsymbol(origin, resource_id: ResourceId, sym: XSymbol) -> DispatchResult {
             Self::ensure_admin(origin)?;
 
@@ -313,7 +345,7 @@ decl_module! {
         }
 
         /// Unmap resourceId to Xsymbol
-        #[weight = 10_000]
+        #[weight = <T as Trait>::WeightInfo::unmap_resource_and_xsymbol()]
         pub fn unmap_resource_and_xsymbol(origin, resource_id: ResourceId, sym: XSymbol) -> DispatchResult {
             Self::ensure_admin(origin)?;
 
@@ -331,7 +363,7 @@ decl_module! {
         /// # <weight>
         /// - weight of proposed call, regardless of whether execution is performed
         /// # </weight>
-        #[weight = (call.get_dispatch_info().weight + 195_000_000, call.get_dispatch_info().class, Pays::Yes)]
+        #[weight = (call.get_dispatch_info().weight + <T as Trait>::WeightInfo::acknowledge_proposal(), call.get_dispatch_info().class, Pays::Yes)]
         pub fn acknowledge_proposal(origin, nonce: DepositNonce, src_id: ChainId, resource_id: ResourceId, call: Box<T::Proposal>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -348,7 +380,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = <T as Trait>::WeightInfo::whitelist_chain()]
         pub fn whitelist_chain(origin, id: ChainId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             Self::whitelist(id)
@@ -359,7 +391,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = <T as Trait>::WeightInfo::remove_whitelist_chain()]
         pub fn remove_whitelist_chain(origin, id: ChainId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             ensure!(Self::chain_whitelisted(id), Error::<T>::ChainNotWhitelisted);
@@ -374,7 +406,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 100_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_proxy_accounts()]
         pub fn set_proxy_accounts(origin, account: T::AccountId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             <ProxyAccounts<T>>::insert(account, 0);
@@ -387,7 +419,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 100_000_000]
+        #[weight = <T as Trait>::WeightInfo::remove_proxy_accounts()]
         pub fn remove_proxy_accounts(origin, account: T::AccountId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             <ProxyAccounts<T>>::remove(account);
@@ -400,7 +432,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 100_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_chain_fees()]
         pub fn set_chain_fees(origin, id: ChainId, fees: Balance) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -418,7 +450,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 100_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_fees_recipient_account()]
         pub fn set_fees_recipient_account(origin, account: T::AccountId) -> DispatchResult {
             Self::ensure_admin(origin)?;
 
@@ -432,7 +464,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 100_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_is_pasued()]
         pub fn set_is_pasued(origin, is_paused: bool) -> DispatchResult {
             Self::ensure_admin(origin)?;
 
@@ -446,7 +478,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 100_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_migrate_target()]
         pub fn set_migrate_target(origin, symbol: RSymbol, id: ChainId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             ensure!(Self::chain_whitelisted(id), Error::<T>::InvalidChainId);
@@ -482,12 +514,22 @@ impl<T: Trait> Module<T> {
     }
 
     pub fn ensure_admin(o: T::Origin) -> DispatchResult {
-        T::AdminOrigin::try_origin(o)
+        <T as Trait>::AdminOrigin::try_origin(o)
             .map(|_| ())
             .or_else(ensure_root)?;
         Ok(())
     }
 
+    /// rsymbol of a resource id, kept in the rtoken registry
+    pub fn resource_rsymbol(resource_id: ResourceId) -> Option<RSymbol> {
+        registry::Module::<T>::resource_asset(resource_id)
+    }
+
+    /// resource id of a rsymbol, kept in the rtoken registry
+    pub fn rsymbol_resource(sym: RSymbol) -> Option<ResourceId> {
+        registry::Module::<T>::resource_id(sym)
+    }
+
     /// Provides an AccountId for the pallet.
     /// This is used both as an origin check and deposit/withdrawal account.
     pub fn account_id() -> T::AccountId {
@@ -623,4 +665,9 @@ impl<T: Trait> EnsureOrigin<T::Origin> for EnsureBridge<T> {
             r => Err(T::Origin::from(r)),
         })
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> T::Origin {
+        T::Origin::from(system::RawOrigin::Signed(<Module<T>>::account_id()))
+    }
 }
@@ -102,12 +102,19 @@ parameter_types! {
 	pub const ProposalLifetime: BlockNumber = 50;
 }
 
+impl rtoken_registry::Trait for Test {
+	type Event = ();
+	type AdminOrigin = EnsureRoot<Self::AccountId>;
+	type WeightInfo = ();
+}
+
 impl Trait for Test {
 	type Event = ();
 	type AdminOrigin = EnsureRoot<Self::AccountId>;
 	type ChainIdentity = ChainIdentity;
 	type Proposal = Call;
 	type ProposalLifetime = ProposalLifetime;
+	type WeightInfo = ();
 }
 
 
@@ -145,5 +152,9 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 				],
 			}.assimilate_storage(&mut t).unwrap();
 
+	rtoken_registry::GenesisConfig {
+		assets: rtoken_registry::builtin_assets(),
+	}.assimilate_storage::<Test>(&mut t).unwrap();
+
 	t.into()
 }
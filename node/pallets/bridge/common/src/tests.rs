@@ -144,6 +144,39 @@ fn map_resource_to_rsymbol_should_work() {
     });
 }
 
+#[test]
+fn map_resource_to_unregistered_rsymbol_should_fail() {
+    new_test_ext().execute_with(|| {
+        let rid: ResourceId = [1; 32];
+
+        assert_noop!(
+			BridgeCommon::map_resource_and_rsymbol(Origin::root(), rid, RSymbol(100)),
+			rtoken_registry::Error::<Test>::AssetNotRegistered,
+        );
+        assert_ok!(BridgeCommon::map_resource_and_rsymbol(Origin::root(), rid, RSymbol::RFIS));
+        assert_noop!(
+			BridgeCommon::map_resource_and_rsymbol(Origin::root(), rid, RSymbol::RDOT),
+			rtoken_registry::Error::<Test>::ResourceIdInUse,
+        );
+    });
+}
+
+#[test]
+fn rsymbol_resource_migration_should_work() {
+    new_test_ext().execute_with(|| {
+        let rid: ResourceId = [1; 32];
+        let sym: RSymbol = RSymbol::RFIS;
+        ResourceRsymbol::insert(&rid, &sym);
+        RsymbolResource::insert(&sym, &rid);
+
+        <BridgeCommon as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+        assert_eq!(BridgeCommon::resource_rsymbol(rid), Some(sym));
+        assert_eq!(BridgeCommon::rsymbol_resource(sym), Some(rid));
+        assert_eq!(RsymbolResource::iter().count(), 0);
+        assert_eq!(ResourceRsymbol::iter().count(), 0);
+    });
+}
+
 #[test]
 fn set_proxy_accounts_should_work() {
 	new_test_ext().execute_with(|| {
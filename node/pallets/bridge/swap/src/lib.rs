@@ -96,7 +96,7 @@ decl_module! {
             let who = ensure_signed(origin)?;
 
             let (fee, receiver, bridger) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
-            let resource = <bridge::Module<T>>::rsymbol_resource(&symbol).ok_or(Error::<T>::RsymbolNotMapped)?;
+            let resource = <bridge::Module<T>>::rsymbol_resource(symbol).ok_or(Error::<T>::RsymbolNotMapped)?;
             let new_rbalance = T::RCurrency::free_balance(&who, symbol).checked_sub(amount)
                 .ok_or(Error::<T>::InsufficientRbalance)?;
             T::RCurrency::ensure_can_withdraw(&who, symbol, amount, new_rbalance)?;
@@ -123,7 +123,7 @@ decl_module! {
         #[weight = 195_000_000]
         pub fn transfer_rtoken_back(origin, recipient: T::AccountId, amount: u128, resource_id: ResourceId) -> DispatchResult {
             let bridge_id = T::BridgeOrigin::ensure_origin(origin)?;
-            let op_sym = <bridge::Module<T>>::resource_rsymbol(&resource_id);
+            let op_sym = <bridge::Module<T>>::resource_rsymbol(resource_id);
             ensure!(op_sym.is_some(), Error::<T>::ResourceNotMapped);
             let sym = op_sym.unwrap();
             if sym == RSymbol::RETH {
@@ -101,6 +101,12 @@ impl xtoken_balances::Trait for Test {
 	type Event = ();
 }
 
+impl rtoken_registry::Trait for Test {
+	type Event = ();
+	type AdminOrigin = EnsureRoot<Self::AccountId>;
+	type WeightInfo = ();
+}
+
 impl rtoken_balances::Trait for Test {
 	type Event = ();
 }
@@ -116,6 +122,7 @@ impl bridge_common::Trait for Test {
 	type ChainIdentity = ChainIdentity;
 	type Proposal = Call;
 	type ProposalLifetime = ProposalLifetime;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -139,6 +146,10 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 				],
 			}.assimilate_storage(&mut t).unwrap();
 
+	rtoken_registry::GenesisConfig {
+		assets: rtoken_registry::builtin_assets(),
+	}.assimilate_storage::<Test>(&mut t).unwrap();
+
 	t.into()
 }
 
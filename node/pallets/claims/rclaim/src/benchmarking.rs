@@ -0,0 +1,150 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the RClaim Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use hex_literal::hex;
+
+const SEED: u32 = 0;
+const SYMBOL: RSymbol = RSymbol::RDOT;
+const CYCLE: u32 = 1;
+const REWARD: u128 = 1_000_000_000_000;
+const FUNDS: u128 = 1_000_000_000_000_000;
+const LOCKED_BLOCKS: u32 = 100;
+/// the largest batch `update_reth_claim_info` accepts
+const MAX_CLAIM_INFOS: u32 = 199;
+/// ethereum address and its `personal_sign` signature of the ascii hex of the encoded `whitelisted_caller`
+const PUBKEY: [u8; 20] = hex!("f0ef2a8024889aeec1395a426bec79c8212c326a");
+const SIGNATURE: [u8; 65] = hex!("33ada26791170062a9347266a4ad3fef447d74cbed328549fd9c1a0affeeba21723d3f17bdda88be1315c9d72275c05ed1abc5f5cd3a764eae00e2cf6b246e131b");
+
+fn act(begin: BlockNumber, end: BlockNumber) -> MintRewardAct<BlockNumber, Balance> {
+	MintRewardAct {
+		begin,
+		end,
+		cycle: CYCLE,
+		reward_rate: RATEBASE,
+		total_reward: FUNDS,
+		left_amount: FUNDS,
+		user_limit: 0,
+		locked_blocks: LOCKED_BLOCKS,
+		total_rtoken_amount: 0,
+		total_native_token_amount: 0,
+	}
+}
+
+fn claim_info() -> ClaimInfo {
+	ClaimInfo {
+		mint_amount: REWARD,
+		native_token_amount: REWARD,
+		total_reward: REWARD,
+		total_claimed: 0,
+		latest_claimed_block: 0,
+		mint_block: 0,
+	}
+}
+
+/// funds the fund address and moves halfway through the locked blocks, so the reward is claimed pro rata
+fn prepare_claim<T: Trait>() {
+	let fund: T::AccountId = account("fund", 0, SEED);
+	T::Currency::make_free_balance_be(&fund, FUNDS.saturated_into());
+	<FundAddress<T>>::put(fund);
+	system::Module::<T>::set_block_number((LOCKED_BLOCKS / 2).into());
+}
+
+benchmarks! {
+	_ { }
+
+	set_reth_rewarder {
+		let rewarder: T::AccountId = account("rewarder", 0, SEED);
+	}: _(RawOrigin::Root, rewarder.clone())
+	verify {
+		assert_eq!(Module::<T>::reth_rewarder(), Some(rewarder));
+	}
+
+	set_fund_address {
+		let fund: T::AccountId = account("fund", 0, SEED);
+	}: _(RawOrigin::Root, fund.clone())
+	verify {
+		assert_eq!(Module::<T>::fund_address(), Some(fund));
+	}
+
+	add_rtoken_reward_act {
+		<Acts>::insert((SYMBOL, CYCLE), act(1, 10));
+		<ActLatestCycle>::insert(SYMBOL, CYCLE);
+	}: _(RawOrigin::Root, 11, 1_000_000, SYMBOL, FUNDS, REWARD, LOCKED_BLOCKS, RATEBASE)
+	verify {
+		assert_eq!(Module::<T>::act_latest_cycle(SYMBOL), CYCLE + 1);
+	}
+
+	update_rtoken_reward_act {
+		<Acts>::insert((SYMBOL, CYCLE), act(1, 10));
+	}: _(RawOrigin::Root, CYCLE, 1, 1_000_000, SYMBOL, FUNDS * 2, REWARD, LOCKED_BLOCKS, RATEBASE)
+	verify {
+		assert_eq!(Module::<T>::acts((SYMBOL, CYCLE)).unwrap().left_amount, FUNDS * 2);
+	}
+
+	add_reth_reward_act {
+		<REthActs>::insert(CYCLE, act(1, 10));
+		<REthActLatestCycle>::put(CYCLE);
+	}: _(RawOrigin::Root, 11, 1_000_000, FUNDS, REWARD, LOCKED_BLOCKS, RATEBASE)
+	verify {
+		assert_eq!(Module::<T>::reth_act_latest_cycle(), CYCLE + 1);
+	}
+
+	update_reth_reward_act {
+		<REthActs>::insert(CYCLE, act(1, 10));
+	}: _(RawOrigin::Root, CYCLE, 1, 1_000_000, FUNDS * 2, REWARD, LOCKED_BLOCKS, RATEBASE)
+	verify {
+		assert_eq!(Module::<T>::reth_acts(CYCLE).unwrap().left_amount, FUNDS * 2);
+	}
+
+	claim_rtoken_reward {
+		let caller: T::AccountId = whitelisted_caller();
+		<Acts>::insert((SYMBOL, CYCLE), act(1, 1_000_000));
+		<ClaimInfos<T>>::insert((caller.clone(), SYMBOL, CYCLE, 0), claim_info());
+		prepare_claim::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), SYMBOL, CYCLE, 0)
+	verify {
+		assert_eq!(Module::<T>::claim_infos((caller, SYMBOL, CYCLE, 0)).unwrap().total_claimed, REWARD / 2);
+	}
+
+	claim_reth_reward {
+		let caller: T::AccountId = whitelisted_caller();
+		<REthActs>::insert(CYCLE, act(1, 1_000_000));
+		<REthClaimInfos>::insert((PUBKEY.to_vec(), CYCLE, 0), claim_info());
+		prepare_claim::<T>();
+	}: _(RawOrigin::Signed(caller), PUBKEY.to_vec(), SIGNATURE.to_vec(), CYCLE, 0)
+	verify {
+		assert_eq!(Module::<T>::reth_claim_infos((PUBKEY.to_vec(), CYCLE, 0)).unwrap().total_claimed, REWARD / 2);
+	}
+
+	update_reth_claim_info {
+		let rewarder: T::AccountId = whitelisted_caller();
+		<REthRewarder<T>>::put(rewarder.clone());
+		<REthActs>::insert(CYCLE, act(1, 1_000_000));
+		<REthActLatestCycle>::put(CYCLE);
+		<REthActCurrentCycle>::put(CYCLE);
+		system::Module::<T>::set_block_number(LOCKED_BLOCKS.into());
+		let tx_hashs: Vec<Vec<u8>> = (0..MAX_CLAIM_INFOS).map(|i| i.encode()).collect();
+		let pubkeys: Vec<Vec<u8>> = (0..MAX_CLAIM_INFOS).map(|i| {
+			let mut pubkey = vec![0u8; 20];
+			pubkey[..4].copy_from_slice(&i.to_le_bytes());
+			pubkey
+		}).collect();
+		let values = vec![REWARD; MAX_CLAIM_INFOS as usize];
+	}: _(RawOrigin::Signed(rewarder), tx_hashs, pubkeys, values.clone(), values)
+	verify {
+		assert_eq!(Module::<T>::reth_acts(CYCLE).unwrap().total_rtoken_amount, REWARD * MAX_CLAIM_INFOS as u128);
+	}
+}
@@ -0,0 +1,36 @@
+//! Default weights for the RClaim Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+	fn set_reth_rewarder() -> Weight {
+		100_000 as Weight
+	}
+	fn set_fund_address() -> Weight {
+		100_000 as Weight
+	}
+	fn add_rtoken_reward_act() -> Weight {
+		100_000 as Weight
+	}
+	fn update_rtoken_reward_act() -> Weight {
+		100_000 as Weight
+	}
+	fn add_reth_reward_act() -> Weight {
+		100_000 as Weight
+	}
+	fn update_reth_reward_act() -> Weight {
+		100_000 as Weight
+	}
+	fn claim_rtoken_reward() -> Weight {
+		10_000_000_000 as Weight
+	}
+	fn claim_reth_reward() -> Weight {
+		10_000_000_000 as Weight
+	}
+	fn update_reth_claim_info() -> Weight {
+		100_000 as Weight
+	}
+}
@@ -19,6 +19,7 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	traits::{Currency, ExistenceRequirement::KeepAlive},
+	weights::Weight,
 };
 use frame_system::{self as system, ensure_root, ensure_signed};
 use general_signature::{ethereum_verify, to_ascii_hex, SigVerifyResult};
@@ -29,6 +30,9 @@ use sp_std::prelude::*;
 
 pub mod models;
 pub use models::*;
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 /// Configuration trait.
 pub trait Trait: system::Trait {
@@ -36,6 +40,20 @@ pub trait Trait: system::Trait {
 	type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 	/// The currency mechanism.
 	type Currency: Currency<Self::AccountId>;
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+	fn set_reth_rewarder() -> Weight;
+	fn set_fund_address() -> Weight;
+	fn add_rtoken_reward_act() -> Weight;
+	fn update_rtoken_reward_act() -> Weight;
+	fn add_reth_reward_act() -> Weight;
+	fn update_reth_reward_act() -> Weight;
+	fn claim_rtoken_reward() -> Weight;
+	fn claim_reth_reward() -> Weight;
+	fn update_reth_claim_info() -> Weight;
 }
 
 pub const RATEBASE: u128 = 1_000_000_000_000;
@@ -119,7 +137,7 @@ decl_module! {
 		fn deposit_event() = default;
 
 		/// Set reth rewarder.
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::set_reth_rewarder()]
 		pub fn set_reth_rewarder(origin, account: T::AccountId) -> DispatchResult {
 			ensure_root(origin)?;
 			<REthRewarder<T>>::put(account);
@@ -127,7 +145,7 @@ decl_module! {
 		}
 
 		/// set fund address
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::set_fund_address()]
 		fn set_fund_address(origin, address: T::AccountId) -> DispatchResult {
 			ensure_root(origin)?;
 			<FundAddress<T>>::put(address);
@@ -135,7 +153,7 @@ decl_module! {
 		}
 
 
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::add_rtoken_reward_act()]
 		pub fn add_rtoken_reward_act(
 			origin,
 			begin: BlockNumber,
@@ -182,7 +200,7 @@ decl_module! {
 			Ok(())
 		}
 
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::update_rtoken_reward_act()]
 		pub fn update_rtoken_reward_act(
 			origin,
 			cycle: u32,
@@ -225,7 +243,7 @@ decl_module! {
 			Ok(())
 		}
 
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::add_reth_reward_act()]
 		pub fn add_reth_reward_act(
 			origin,
 			begin: BlockNumber,
@@ -272,7 +290,7 @@ decl_module! {
 			Ok(())
 		}
 
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::update_reth_reward_act()]
 		pub fn update_reth_reward_act(
 			origin,
 			cycle: u32,
@@ -316,7 +334,7 @@ decl_module! {
 
 
 		/// Make a rtoken claim
-		#[weight = 10_000_000_000]
+		#[weight = T::WeightInfo::claim_rtoken_reward()]
 		pub fn claim_rtoken_reward(origin, symbol: RSymbol, cycle: u32, index: u64) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let mut claim_info = Self::claim_infos((&who, symbol, cycle, index)).ok_or(Error::<T>::HasNoClaimInfo)?;
@@ -348,7 +366,7 @@ decl_module! {
 		}
 
 		/// Make a reth claim
-		#[weight = 10_000_000_000]
+		#[weight = T::WeightInfo::claim_reth_reward()]
 		pub fn claim_reth_reward(origin, pubkey: Vec<u8>, sigs: Vec<u8>, cycle: u32, index: u64) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let use_who = who.using_encoded(to_ascii_hex);
@@ -383,7 +401,7 @@ decl_module! {
 			Ok(())
 		}
 
-		#[weight = 100_000]
+		#[weight = T::WeightInfo::update_reth_claim_info()]
 		pub fn update_reth_claim_info(origin, tx_hashs: Vec<Vec<u8>>, pubkeys: Vec<Vec<u8>>, mint_values: Vec<u128>, native_token_values: Vec<u128>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(Self::is_rewarder(&who), Error::<T>::InvalidREthRewarder);
@@ -8,16 +8,20 @@ use sp_core::{
     ed25519::{Public as Ed25519Public, Signature as Ed25519Signature},
     ecdsa::{Public as EcdsaPublic, Signature as EcdsaSignature},
 };
-use sp_io::{hashing::keccak_256, crypto::secp256k1_ecdsa_recover};
+use sp_io::{hashing::{keccak_256, sha2_256}, crypto::secp256k1_ecdsa_recover};
+use rcrypto::cosmos;
 
-use node_primitives::{RSymbol, ChainType, Sr25519AppCrypto, Ed25519AppCrypto, EcdsaAppCrypto};
+use node_primitives::{ChainType, Sr25519AppCrypto, Ed25519AppCrypto, EcdsaAppCrypto};
 use frame_system::offchain::AppCrypto;
 
 #[cfg(test)]
 mod tests;
 
-pub fn verify_signature(symbol: RSymbol, pubkey: &Vec<u8>, signature: &Vec<u8>, message: &Vec<u8>) -> SigVerifyResult {
-    match symbol.chain_type() {
+/// bech32 prefix of cosmos hub accounts, used to rebuild ADR-036 sign docs
+pub const COSMOS_ADDRESS_PREFIX: &str = "cosmos";
+
+pub fn verify_signature(chain_type: ChainType, pubkey: &Vec<u8>, signature: &Vec<u8>, message: &Vec<u8>) -> SigVerifyResult {
+    match chain_type {
         ChainType::Substrate => {
             let mut pre = b"<Bytes>".to_vec();
             let mut end = b"</Bytes>".to_vec();
@@ -36,8 +40,19 @@ pub fn verify_signature(symbol: RSymbol, pubkey: &Vec<u8>, signature: &Vec<u8>,
     }
 }
 
-pub fn verify_recipient(symbol: RSymbol, recipient: &Vec<u8>) -> bool {
-    match symbol.chain_type() {
+/// verifies the signature of a sub account over a multisig pool proposal. pools sign the raw proposal,
+/// so no wallet prefix is added, except that ethereum also accepts a `personal_sign` signature
+pub fn verify_proposal_signature(chain_type: ChainType, pubkey: &Vec<u8>, signature: &Vec<u8>, proposal: &Vec<u8>) -> SigVerifyResult {
+    match chain_type {
+        ChainType::Substrate => substrate_verify(&pubkey, &signature, &proposal),
+        ChainType::Tendermint => tendermint_verify(&pubkey, &signature, &proposal),
+        ChainType::Solana => ed25519_verify(&pubkey, &signature, &proposal),
+        ChainType::Ethereum => ethereum_hash_verify(&pubkey, &signature, &proposal),
+    }
+}
+
+pub fn verify_recipient(chain_type: ChainType, recipient: &Vec<u8>) -> bool {
+    match chain_type {
         ChainType::Substrate => {
             let re_public = <Sr25519Public as TryFrom<_>>::try_from(&recipient[..]);
             return re_public.is_ok();
@@ -101,12 +116,18 @@ pub fn substrate_verify(pubkey: &Vec<u8>, signature: &Vec<u8>, message: &Vec<u8>
     SigVerifyResult::Fail
 }
 
-pub fn tendermint_verify(pubkey: &Vec<u8>, _signature: &Vec<u8>, _message: &Vec<u8>) -> SigVerifyResult {
+/// Accepts either a plain secp256k1 signature over sha256(message), or an
+/// ADR-036 signature (eg: Keplr `signArbitrary`) with message as the data.
+pub fn tendermint_verify(pubkey: &Vec<u8>, signature: &Vec<u8>, message: &Vec<u8>) -> SigVerifyResult {
     if !check_tendermint_pubkey(&pubkey) {
         return SigVerifyResult::InvalidPubkey;
     }
-    
-    if true {
+
+    if cosmos::verify::verify(&sha2_256(&message), &signature, &pubkey) {
+        return SigVerifyResult::Pass;
+    }
+
+    if cosmos::adr036::verify_arbitrary(COSMOS_ADDRESS_PREFIX, &message, &signature, &pubkey) {
         return SigVerifyResult::Pass;
     }
 
@@ -150,6 +171,31 @@ pub fn ethereum_verify(pubkey: &Vec<u8>, signature: &Vec<u8>, msg: &[u8]) -> Sig
     SigVerifyResult::Fail
 }
 
+/// verifies an ethereum signature over a 32 bytes hash, signed either raw or by `personal_sign`
+pub fn ethereum_hash_verify(pubkey: &Vec<u8>, signature: &Vec<u8>, hash: &Vec<u8>) -> SigVerifyResult {
+    if pubkey.len() != 20 {
+        return SigVerifyResult::InvalidPubkey;
+    }
+    if signature.len() != 65 || hash.len() != 32 {
+        return SigVerifyResult::Fail;
+    }
+
+    let mut sig = [0u8; 65];
+    sig.copy_from_slice(&signature);
+    let mut msg = [0u8; 32];
+    msg.copy_from_slice(&hash);
+
+    let raw_signer = secp256k1_ecdsa_recover(&sig, &msg).ok().map(|public| keccak_256(&public[..])[12..].to_vec());
+    if raw_signer.as_ref() == Some(pubkey) {
+        return SigVerifyResult::Pass;
+    }
+    if eth_recover(&sig, &hash).map(|signer| signer.to_vec()).as_ref() == Some(pubkey) {
+        return SigVerifyResult::Pass;
+    }
+
+    SigVerifyResult::Fail
+}
+
 pub fn eth_recover(sig: &[u8; 65], msg: &[u8]) -> Option<[u8; 20]> {
     let mut res = [0u8; 20];
     let use_msg = keccak_256(&ethereum_signable_message(msg));
@@ -1,6 +1,8 @@
-use super::{ethereum_verify, SigVerifyResult};
+use super::{ethereum_verify, ethereum_hash_verify, tendermint_verify, verify_proposal_signature, verify_signature, SigVerifyResult};
+use node_primitives::ChainType;
 use hex_literal::hex;
 use sp_core::sr25519::{Pair as Sr25519Pair, Public, Signature};
+use sp_core::ed25519::Pair as Ed25519Pair;
 use sp_core::Pair as TraitPair;
 
 #[test]
@@ -48,3 +50,70 @@ fn ethereum_verify_should_not_work() {
 
     assert_eq!(result, SigVerifyResult::Fail);
 }
+
+#[test]
+fn tendermint_verify_should_work() {
+    // signed by a cosmos wallet over sha256("test1")
+    let msg = b"test1".to_vec();
+    let sig = hex!["83cb210aa033be60f44309962b3546bc0d869515a1ad1236644040ca74b35ff83c2479a3ac1373b8b566323d23faad1ac2a3f7dd70505f18b72fa7d598de19ac"].to_vec();
+    let pubkey = hex!["02253456b87dd51a5aadc8105e23ff74dc5e53607f206a02cdc1b4d9b6bf11101c"].to_vec();
+
+    let result = tendermint_verify(&pubkey, &sig, &msg);
+
+    assert_eq!(result, SigVerifyResult::Pass);
+
+    let der_sig = hex!["304502210083cb210aa033be60f44309962b3546bc0d869515a1ad1236644040ca74b35ff802203c2479a3ac1373b8b566323d23faad1ac2a3f7dd70505f18b72fa7d598de19ac"].to_vec();
+    assert_eq!(tendermint_verify(&pubkey, &der_sig, &msg), SigVerifyResult::Pass);
+}
+
+#[test]
+fn tendermint_adr036_verify_should_work() {
+    // signArbitrary of the bonder account by cosmos1lzggtcxe6mzcmv0h25p54eepvsdqwfpgxkcmd9
+    let msg = hex!["d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"].to_vec();
+    let sig = hex!["a5305f444b21de2e2fa13a147ed4528f48c3dd66cc3760f9ecff1f929f0ba6db69de073c62dd117eede2a52f8196c1fc7986c71135471cb1a55767db5a8424b9"].to_vec();
+    let pubkey = hex!["027d9bcf346cd3441ed7d43d927abe3812b735abf33a17ee57a91b413cd95a883f"].to_vec();
+
+    let result = tendermint_verify(&pubkey, &sig, &msg);
+
+    assert_eq!(result, SigVerifyResult::Pass);
+}
+
+#[test]
+fn tendermint_verify_should_not_work() {
+    let msg = b"test2".to_vec();
+    let sig = hex!["83cb210aa033be60f44309962b3546bc0d869515a1ad1236644040ca74b35ff83c2479a3ac1373b8b566323d23faad1ac2a3f7dd70505f18b72fa7d598de19ac"].to_vec();
+    let pubkey = hex!["02253456b87dd51a5aadc8105e23ff74dc5e53607f206a02cdc1b4d9b6bf11101c"].to_vec();
+
+    assert_eq!(tendermint_verify(&pubkey, &sig, &msg), SigVerifyResult::Fail);
+    assert_eq!(tendermint_verify(&pubkey, &sig[1..].to_vec(), &msg), SigVerifyResult::Fail);
+    assert_eq!(tendermint_verify(&pubkey[1..].to_vec(), &sig, &msg), SigVerifyResult::InvalidPubkey);
+}
+
+#[test]
+fn ethereum_hash_verify_should_work() {
+    // signed by personal_sign, accepted as well as raw signatures
+    let msg = hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").to_vec();
+    let sig = hex!["82dbd11468a4fe72682e656a03bcb5817f4470b9e41a25ed0e0a50f7fdb22c380070999361924984f66fb5d7772049539207c73d836f4578579638df3513ae6700"].to_vec();
+    let signer = hex!["Bca9567A9e8D5F6F58C419d32aF6190F74C880e6"].to_vec();
+    let other = hex!["aca9567A9e8D5F6F58C419d32aF6190F74C880e6"].to_vec();
+
+    assert_eq!(ethereum_hash_verify(&signer, &sig, &msg), SigVerifyResult::Pass);
+    assert_eq!(ethereum_hash_verify(&other, &sig, &msg), SigVerifyResult::Fail);
+    assert_eq!(ethereum_hash_verify(&signer, &sig[1..].to_vec(), &msg), SigVerifyResult::Fail);
+    assert_eq!(ethereum_hash_verify(&signer, &sig, &msg[1..].to_vec()), SigVerifyResult::Fail);
+    assert_eq!(ethereum_hash_verify(&signer[1..].to_vec(), &sig, &msg), SigVerifyResult::InvalidPubkey);
+}
+
+#[test]
+fn solana_proposal_signature_should_be_raw() {
+    let pair = <Ed25519Pair as TraitPair>::from_seed(&hex!(
+        "7caadbb21966e5e268945005488a3c9be64a37b949d4691a367e477ed9e57995"
+    ));
+    let pubkey = pair.public().0.to_vec();
+    let proposal = hex!("26db25c52b007221331a844e5335e59874e45b03e81c3d76ff007377c2c17965").to_vec();
+    let signature = pair.sign(&proposal[..]).0.to_vec();
+
+    assert_eq!(verify_proposal_signature(ChainType::Solana, &pubkey, &signature, &proposal), SigVerifyResult::Pass);
+    assert_eq!(verify_signature(ChainType::Solana, &pubkey, &signature, &proposal), SigVerifyResult::Fail);
+    assert_eq!(verify_proposal_signature(ChainType::Solana, &pubkey, &signature, &proposal[1..].to_vec()), SigVerifyResult::Fail);
+}
@@ -0,0 +1,128 @@
+// Copyright 2018 Stafi Protocol, Inc.
+// This file is part of Stafi.
+
+// Stafi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! ADR-036 arbitrary message signing, as produced by wallets such as Keplr
+//! through `signArbitrary`. The data is wrapped into an amino `StdSignDoc`
+//! holding a single `sign/MsgSignData` message, and the sorted JSON of that
+//! doc is what actually gets hashed and signed.
+
+extern crate bech32_no_std as bech32;
+extern crate crypto;
+
+use bech32::ToBase32;
+use bitcoin_hashes::{Hash, ripemd160};
+use crypto::{sha2::Sha256, digest::Digest};
+use sp_std::vec::Vec;
+
+use super::verify::{try_verify, VerifyError};
+
+const BASE64_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Verify an ADR-036 signature of `data`, where the signer address is derived
+/// from `pk` with the bech32 prefix `hrp`, eg: "cosmos".
+pub fn try_verify_arbitrary(hrp: &str, data: &[u8], sig: &[u8], pk: &[u8]) -> Result<(), VerifyError> {
+    let signer = address_from_pubkey(hrp, pk).ok_or(VerifyError::BadKeyLength)?;
+    let doc = sign_doc(&signer, data);
+
+    try_verify(&sha256(&doc), sig, pk)
+}
+
+pub fn verify_arbitrary(hrp: &str, data: &[u8], sig: &[u8], pk: &[u8]) -> bool {
+    try_verify_arbitrary(hrp, data, sig, pk).is_ok()
+}
+
+/// Bech32 account address of a compressed secp256k1 pubkey: ripemd160(sha256(pk)).
+pub fn address_from_pubkey(hrp: &str, pk: &[u8]) -> Option<Vec<u8>> {
+    if pk.len() != 33 {
+        return None;
+    }
+    let hash = ripemd160::Hash::hash(&sha256(pk)).into_inner();
+    bech32::encode(hrp, hash.to_base32()).ok().map(|addr| addr.into_bytes())
+}
+
+/// Sorted JSON of the amino sign doc Keplr builds for `signArbitrary`.
+pub fn sign_doc(signer: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut doc = b"{\"account_number\":\"0\",\"chain_id\":\"\",\"fee\":{\"amount\":[],\"gas\":\"0\"},\"memo\":\"\",\
+        \"msgs\":[{\"type\":\"sign/MsgSignData\",\"value\":{\"data\":\"".to_vec();
+    doc.extend(base64_encode(data));
+    doc.extend_from_slice(b"\",\"signer\":\"");
+    doc.extend_from_slice(signer);
+    doc.extend_from_slice(b"\"}}],\"sequence\":\"0\"}");
+    doc
+}
+
+/// Standard base64 with padding, the encoding amino JSON uses for bytes.
+pub fn base64_encode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
+        out.push(BASE64_TABLE[n >> 18 & 63]);
+        out.push(BASE64_TABLE[n >> 12 & 63]);
+        out.push(if chunk.len() > 1 { BASE64_TABLE[n >> 6 & 63] } else { b'=' });
+        out.push(if chunk.len() > 2 { BASE64_TABLE[n & 63] } else { b'=' });
+    }
+    out
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.input(data);
+    let mut out = [0; 32];
+    hasher.result(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), b"".to_vec());
+        assert_eq!(base64_encode(b"f"), b"Zg==".to_vec());
+        assert_eq!(base64_encode(b"fo"), b"Zm8=".to_vec());
+        assert_eq!(base64_encode(b"foo"), b"Zm9v".to_vec());
+        assert_eq!(base64_encode(b"foobar"), b"Zm9vYmFy".to_vec());
+    }
+
+    #[test]
+    fn test_address_from_pubkey() {
+        let pk = hex!("02253456b87dd51a5aadc8105e23ff74dc5e53607f206a02cdc1b4d9b6bf11101c");
+        assert_eq!(address_from_pubkey("cosmos", &pk), Some(b"cosmos1jd2rfsk4tzjzmj5ucl4t7ayam8p2ttefk8tvkd".to_vec()));
+        assert_eq!(address_from_pubkey("cosmos", &pk[1..]), None);
+    }
+
+    #[test]
+    fn test_sign_doc() {
+        let data = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+        let doc = sign_doc(b"cosmos1lzggtcxe6mzcmv0h25p54eepvsdqwfpgxkcmd9", &data);
+        assert_eq!(doc, br#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"1DWTxxX90xxhFBq9BKmf1oIshViFTM3jmlaE56Vton0=","signer":"cosmos1lzggtcxe6mzcmv0h25p54eepvsdqwfpgxkcmd9"}}],"sequence":"0"}"#.to_vec());
+    }
+
+    #[test]
+    fn test_verify_arbitrary() {
+        let data = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+        let pk = hex!("027d9bcf346cd3441ed7d43d927abe3812b735abf33a17ee57a91b413cd95a883f");
+        let sig = hex!("a5305f444b21de2e2fa13a147ed4528f48c3dd66cc3760f9ecff1f929f0ba6db69de073c62dd117eede2a52f8196c1fc7986c71135471cb1a55767db5a8424b9");
+        assert!(verify_arbitrary("cosmos", &data, &sig, &pk));
+        assert!(!verify_arbitrary("terra", &data, &sig, &pk));
+        assert!(!verify_arbitrary("cosmos", &data[1..], &sig, &pk));
+        assert_eq!(try_verify_arbitrary("cosmos", &data, &sig, &pk[1..]), Err(VerifyError::BadKeyLength));
+        assert_eq!(try_verify_arbitrary("cosmos", &data, &sig[1..], &pk), Err(VerifyError::BadSignature));
+    }
+}
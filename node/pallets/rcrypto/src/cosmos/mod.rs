@@ -15,3 +15,4 @@
 // along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
 
 pub mod verify;
+pub mod adr036;
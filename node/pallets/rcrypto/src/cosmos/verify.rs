@@ -25,90 +25,156 @@ use sp_std::{
 	convert::{TryInto},
 };
 
-pub fn verify_with_prefix(data: &[u8], sig_data: &[u8], pk: &[u8]) -> bool {
-    let data_str = match str::from_utf8(data) {
-        Ok(str) => str,
-        Err(_) => return false,
-    };
+/// bech32 prefix of cosmos hub pubkeys
+pub const COSMOS_PUBKEY_HRP: &str = "cosmospub";
+
+/// amino prefix of a secp256k1 pubkey wrapped in a bech32 pubkey
+const AMINO_SECP256K1_PREFIX: [u8; 5] = [0xeb, 0x5a, 0xe9, 0x87, 0x21];
+
+/// Reasons a cosmos signature can be refused
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum VerifyError {
+    /// pubkey is neither raw bytes nor a valid bech32 string
+    BadBech32,
+    /// bech32 pubkey has an unexpected prefix
+    WrongHrp,
+    /// pubkey is not a valid 33 bytes compressed key
+    BadKeyLength,
+    /// signature is neither 64 bytes compact nor DER encoded
+    BadSignature,
+    /// signature does not match the data and pubkey
+    VerifyFailed,
+}
+
+/// Verify a signature over sha256(data), with pk given either as 33 raw bytes
+/// or as a bech32 string with the prefix `hrp`.
+pub fn try_verify_with_prefix(data: &[u8], sig_data: &[u8], pk: &[u8], hrp: &str) -> Result<(), VerifyError> {
     let mut hasher = Sha256::new();
-    hasher.input_str(data_str);
+    hasher.input(data);
     let mut data_out = [0; 32];
     hasher.result(&mut data_out);
 
-    // let sig_str = match str::from_utf8(sig_data) {
-    //     Ok(str) => str,
-    //     Err(_) => return false,
-    // };
-    // let sig_bytes = base64::decode(&sig_str).unwrap();
-    // let sigature: &[u8] = &sig_bytes;
+    let pubkey = parse_pubkey(pk, hrp)?;
+    let signature = parse_signature(sig_data)?;
 
-    let sigature: &[u8] = sig_data;
+    check(&data_out, &signature, &pubkey)
+}
 
-    let pk_str = match str::from_utf8(pk) {
-        Ok(str) => str,
-        Err(_) => return false,
-    };
-    let (_hrp, pk_data) = bech32::decode(&pk_str).unwrap();
-    let prefixed_pubkey = Vec::<u8>::from_base32(&pk_data).unwrap();
-    let pubkey = &prefixed_pubkey[5..];
+/// Verify a signature over the prehashed data, pk given as in `try_verify_with_prefix`
+/// with the cosmos hub prefix.
+pub fn try_verify(data: &[u8; 32], sig: &[u8], pk: &[u8]) -> Result<(), VerifyError> {
+    let pubkey = parse_pubkey(pk, COSMOS_PUBKEY_HRP)?;
+    let signature = parse_signature(sig)?;
 
-    return verify(&data_out, &sigature, &pubkey);
+    check(data, &signature, &pubkey)
+}
+
+pub fn verify_with_prefix(data: &[u8], sig_data: &[u8], pk: &[u8]) -> bool {
+    try_verify_with_prefix(data, sig_data, pk, COSMOS_PUBKEY_HRP).is_ok()
 }
 
 pub fn verify(data: &[u8; 32], sig: &[u8], pk: &[u8]) -> bool {
-    secp256k1::verify(&secp256k1::Message::parse(&data), 
-        &secp256k1::Signature::parse(sig.try_into().unwrap()),
-        &secp256k1::PublicKey::parse_compressed(pk.try_into().unwrap()).unwrap()
-    )
+    try_verify(data, sig, pk).is_ok()
+}
+
+/// Parse a compressed pubkey from 33 raw bytes or from a bech32 string with the prefix `hrp`,
+/// whose data may carry the amino prefix.
+pub fn parse_pubkey(pk: &[u8], hrp: &str) -> Result<secp256k1::PublicKey, VerifyError> {
+    if pk.len() == 33 {
+        return parse_compressed(pk);
+    }
+
+    let pk_str = str::from_utf8(pk).map_err(|_| VerifyError::BadBech32)?;
+    let (pk_hrp, pk_data) = bech32::decode(&pk_str).map_err(|_| VerifyError::BadBech32)?;
+    if pk_hrp != hrp {
+        return Err(VerifyError::WrongHrp);
+    }
+    let prefixed_pubkey = Vec::<u8>::from_base32(&pk_data).map_err(|_| VerifyError::BadBech32)?;
+    if prefixed_pubkey.len() == 38 && prefixed_pubkey[..5] == AMINO_SECP256K1_PREFIX {
+        return parse_compressed(&prefixed_pubkey[5..]);
+    }
+
+    parse_compressed(&prefixed_pubkey)
+}
+
+/// Parse a signature either as 64 bytes compact (r || s) or DER encoded.
+pub fn parse_signature(sig: &[u8]) -> Result<secp256k1::Signature, VerifyError> {
+    match sig.try_into() {
+        Ok(compact) => Ok(secp256k1::Signature::parse(compact)),
+        Err(_) => secp256k1::Signature::parse_der(sig).map_err(|_| VerifyError::BadSignature),
+    }
+}
+
+fn parse_compressed(pk: &[u8]) -> Result<secp256k1::PublicKey, VerifyError> {
+    let compressed = pk.try_into().map_err(|_| VerifyError::BadKeyLength)?;
+    secp256k1::PublicKey::parse_compressed(compressed).map_err(|_| VerifyError::BadKeyLength)
+}
+
+fn check(data: &[u8; 32], sig: &secp256k1::Signature, pk: &secp256k1::PublicKey) -> Result<(), VerifyError> {
+    if secp256k1::verify(&secp256k1::Message::parse(data), sig, pk) {
+        Ok(())
+    } else {
+        Err(VerifyError::VerifyFailed)
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use secp256k1;
+    use hex_literal::hex;
+
+    const PK_STR: &str = "cosmospub1addwnpepqgjng44c0h235k4deqg9ugllwnw9u5mq0usx5qkdcx6dnd4lzygpcrhupw2";
+    const PK: [u8; 33] = hex!("02253456b87dd51a5aadc8105e23ff74dc5e53607f206a02cdc1b4d9b6bf11101c");
+    // signed over "test1" by a cosmos wallet
+    const SIG: [u8; 64] = hex!("83cb210aa033be60f44309962b3546bc0d869515a1ad1236644040ca74b35ff83c2479a3ac1373b8b566323d23faad1ac2a3f7dd70505f18b72fa7d598de19ac");
 
     #[test]
     fn test_cosmos_bech32_decode() {
-        let pk_str = "cosmospub1addwnpepqgjng44c0h235k4deqg9ugllwnw9u5mq0usx5qkdcx6dnd4lzygpcrhupw2";
-        let (hrp, data) = bech32::decode(&pk_str).unwrap();
+        let (hrp, data) = bech32::decode(&PK_STR).unwrap();
         let prefixed_pubkey = Vec::<u8>::from_base32(&data).unwrap();
         let pubkey = &prefixed_pubkey[5..];
         assert_eq!(hrp, "cosmospub");
         assert_eq!(prefixed_pubkey, vec![235, 90, 233, 135, 33, 2, 37, 52, 86, 184, 125, 213, 26, 90, 173, 200, 16, 94, 35, 255, 116, 220, 94, 83, 96, 127, 32, 106, 2, 205, 193, 180, 217, 182, 191, 17, 16, 28]);
-        assert_eq!(pubkey, vec![2, 37, 52, 86, 184, 125, 213, 26, 90, 173, 200, 16, 94, 35, 255, 116, 220, 94, 83, 96, 127, 32, 106, 2, 205, 193, 180, 217, 182, 191, 17, 16, 28]);
+        assert_eq!(pubkey, PK.to_vec());
     }
 
     #[test]
     fn test_cosmos_base64_decode() {
-        // let sig_str = "g8shCqAzvmD0QwmWKzVGvA2GlRWhrRI2ZEBAynSzX/g8JHmjrBNzuLVmMj0j+q0awqP33XBQXxi3L6fVmN4ZrA==";
-        // let sig_bytes = base64::decode(&sig_str).unwrap();
-        // assert_eq!(sig_bytes, vec![131, 203, 33, 10, 160, 51, 190, 96, 244, 67, 9, 150, 43, 53, 70, 188, 13, 134, 149, 21, 161, 173, 18, 54, 100, 64, 64, 202, 116, 179, 95, 248, 60, 36, 121, 163, 172, 19, 115, 184, 181, 102, 50, 61, 35, 250, 173, 26, 194, 163, 247, 221, 112, 80, 95, 24, 183, 47, 167, 213, 152, 222, 25, 172]);
+        let sig_str = "g8shCqAzvmD0QwmWKzVGvA2GlRWhrRI2ZEBAynSzX/g8JHmjrBNzuLVmMj0j+q0awqP33XBQXxi3L6fVmN4ZrA==";
+        assert_eq!(super::super::adr036::base64_encode(&SIG), sig_str.as_bytes().to_vec());
     }
 
     #[test]
-    fn test_cosmos_verify() {
-        // let message = "test1";
-        // let mut hasher = Sha256::new();
-        // hasher.input_str(message);
-        // let mut message_out = [0; 32];
-        // hasher.result(&mut message_out);
-
-        // let pk_str = "cosmospub1addwnpepqgjng44c0h235k4deqg9ugllwnw9u5mq0usx5qkdcx6dnd4lzygpcrhupw2";
-        // let (hrp, data) = bech32::decode(&pk_str).unwrap();
-        // let prefixed_pubkey = Vec::<u8>::from_base32(&data).unwrap();
-        // let pubkey = &prefixed_pubkey[5..];
-
-        // let sig_str = "g8shCqAzvmD0QwmWKzVGvA2GlRWhrRI2ZEBAynSzX/g8JHmjrBNzuLVmMj0j+q0awqP33XBQXxi3L6fVmN4ZrA==";
-        // let sig_bytes = base64::decode(&sig_str).unwrap();
-
-        // let sigature: &[u8] = &sig_bytes;
+    fn test_cosmos_parse_pubkey() {
+        let raw = parse_pubkey(&PK, COSMOS_PUBKEY_HRP).unwrap();
+        let bech = parse_pubkey(PK_STR.as_bytes(), COSMOS_PUBKEY_HRP).unwrap();
+        assert_eq!(raw.serialize_compressed(), bech.serialize_compressed());
+
+        assert_eq!(parse_pubkey(PK_STR.as_bytes(), "terrapub").err(), Some(VerifyError::WrongHrp));
+        assert_eq!(parse_pubkey(b"cosmospub1invalid", COSMOS_PUBKEY_HRP).err(), Some(VerifyError::BadBech32));
+        assert_eq!(parse_pubkey(&PK[1..], COSMOS_PUBKEY_HRP).err(), Some(VerifyError::BadBech32));
+        assert_eq!(parse_pubkey(&[0u8; 33], COSMOS_PUBKEY_HRP).err(), Some(VerifyError::BadKeyLength));
+    }
 
-        // let verify_res = secp256k1::verify(&secp256k1::Message::parse(&message_out), 
-        //     &secp256k1::Signature::parse(sigature.try_into().unwrap()),
-        //     &secp256k1::PublicKey::parse_compressed(pubkey.try_into().unwrap()).unwrap()
-        // );
+    #[test]
+    fn test_cosmos_parse_signature() {
+        let der = hex!("304502210083cb210aa033be60f44309962b3546bc0d869515a1ad1236644040ca74b35ff802203c2479a3ac1373b8b566323d23faad1ac2a3f7dd70505f18b72fa7d598de19ac");
+        assert_eq!(parse_signature(&der).unwrap().serialize(), SIG);
+        assert_eq!(parse_signature(&SIG).unwrap().serialize(), SIG);
+        assert_eq!(parse_signature(&SIG[1..]).err(), Some(VerifyError::BadSignature));
+    }
 
-        // assert_eq!(verify_res, true);
+    #[test]
+    fn test_cosmos_verify() {
+        assert_eq!(try_verify_with_prefix(b"test1", &SIG, PK_STR.as_bytes(), COSMOS_PUBKEY_HRP), Ok(()));
+        assert_eq!(try_verify_with_prefix(b"test1", &SIG, &PK, COSMOS_PUBKEY_HRP), Ok(()));
+        assert_eq!(try_verify_with_prefix(b"test2", &SIG, &PK, COSMOS_PUBKEY_HRP), Err(VerifyError::VerifyFailed));
+        assert!(verify_with_prefix(b"test1", &SIG, PK_STR.as_bytes()));
+
+        let hash = hex!("1b4f0e9851971998e732078544c96b36c3d01cedf7caa332359d6f1d83567014");
+        assert!(verify(&hash, &SIG, &PK));
+        assert!(!verify(&hash, &SIG[..63], &PK));
+        assert!(!verify(&hash, &SIG, &PK[..32]));
     }
-}
\ No newline at end of file
+}
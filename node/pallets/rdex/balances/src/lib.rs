@@ -12,6 +12,11 @@ use sp_std::prelude::*;
 
 pub mod traits;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 pub trait Trait: system::Trait {
 	/// The overarching event type.
 	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -27,6 +32,8 @@ decl_event!(
 		Minted(AccountId, RSymbol, u128),
 		/// Some balance was burned
 		Burned(AccountId, RSymbol, u128),
+		/// Allowance of a spender was set. \[owner, spender, symbol, value\]
+		Approval(AccountId, AccountId, RSymbol, u128),
 	}
 );
 
@@ -38,6 +45,8 @@ decl_error! {
 		Overflow,
 		/// Balance too low to send value
 		InsufficientBalance,
+		/// Allowance too low to spend value
+		InsufficientAllowance,
 	}
 }
 
@@ -56,6 +65,9 @@ decl_storage! {
 		/// NOTE: This is only used in the case that this module is used to store balances.
 		pub Account get(fn account):
 			double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::AccountId => Option<AccountLpData>;
+
+		/// Amount a spender may transfer out of the balance of an owner, by (owner, spender, symbol)
+		pub Allowances get(fn allowances): map hasher(blake2_128_concat) (T::AccountId, T::AccountId, RSymbol) => u128;
 	}
 }
 
@@ -75,6 +87,67 @@ decl_module! {
 			let dest = T::Lookup::lookup(dest)?;
 			<Self as traits::Currency<_>>::transfer(&transactor, &dest, symbol, value)?;
 		}
+
+		/// Set the amount `spender` may transfer out of the caller's free balance.
+		#[weight = 195_000_000]
+		pub fn approve(
+			origin,
+			spender: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			value: u128
+		) {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			Self::set_allowance(&owner, &spender, symbol, value);
+		}
+
+		/// Raise the amount `spender` may transfer out of the caller's free balance.
+		#[weight = 195_000_000]
+		pub fn increase_allowance(
+			origin,
+			spender: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			added_value: u128
+		) {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			let value = Self::allowances((&owner, &spender, symbol)).checked_add(added_value)
+				.ok_or(Error::<T>::Overflow)?;
+			Self::set_allowance(&owner, &spender, symbol, value);
+		}
+
+		/// Lower the amount `spender` may transfer out of the caller's free balance.
+		#[weight = 195_000_000]
+		pub fn decrease_allowance(
+			origin,
+			spender: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			subtracted_value: u128
+		) {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			let value = Self::allowances((&owner, &spender, symbol)).checked_sub(subtracted_value)
+				.ok_or(Error::<T>::InsufficientAllowance)?;
+			Self::set_allowance(&owner, &spender, symbol, value);
+		}
+
+		/// Transfer some liquid free balance of `owner` to another account, spending the caller's allowance.
+		#[weight = 195_000_000]
+		pub fn transfer_from(
+			origin,
+			owner: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			value: u128
+		) {
+			let spender = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let allowance = Self::allowances((&owner, &spender, symbol)).checked_sub(value)
+				.ok_or(Error::<T>::InsufficientAllowance)?;
+			<Self as traits::Currency<_>>::transfer(&owner, &dest, symbol, value)?;
+			Self::set_allowance(&owner, &spender, symbol, allowance);
+		}
 	}
 }
 
@@ -105,6 +178,16 @@ impl<T: Trait> Module<T> {
 		})
 		.map(|result| result)
 	}
+
+	/// Set the allowance of `spender` over the balance of `owner`, removing it once it is zero.
+	fn set_allowance(owner: &T::AccountId, spender: &T::AccountId, symbol: RSymbol, value: u128) {
+		if value.is_zero() {
+			<Allowances<T>>::remove((owner, spender, symbol));
+		} else {
+			<Allowances<T>>::insert((owner, spender, symbol), value);
+		}
+		Self::deposit_event(RawEvent::Approval(owner.clone(), spender.clone(), symbol, value));
+	}
 }
 
 impl<T: Trait> traits::Currency<T::AccountId> for Module<T> {
@@ -116,6 +199,10 @@ impl<T: Trait> traits::Currency<T::AccountId> for Module<T> {
 		}
 	}
 
+	fn allowance(owner: &T::AccountId, spender: &T::AccountId, symbol: RSymbol) -> u128 {
+		<Allowances<T>>::get((owner, spender, symbol))
+	}
+
 	fn total_issuance(symbol: RSymbol) -> u128 {
 		<TotalIssuance>::get(symbol)
 	}
@@ -0,0 +1,81 @@
+use super::*;
+use super::mock::*;
+use super::traits::Currency;
+use frame_support::{assert_ok, assert_noop};
+
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+#[test]
+fn approve_should_set_allowance_and_emit_approval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RDexBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+		assert_eq!(RDexBalances::allowances((1, 2, SYMBOL)), 100);
+		assert_eq!(last_event(), TestEvent::rdex_balances(RawEvent::Approval(1, 2, SYMBOL, 100)));
+
+		assert_ok!(RDexBalances::increase_allowance(Origin::signed(1), 2, SYMBOL, 50));
+		assert_eq!(last_event(), TestEvent::rdex_balances(RawEvent::Approval(1, 2, SYMBOL, 150)));
+		assert_ok!(RDexBalances::decrease_allowance(Origin::signed(1), 2, SYMBOL, 150));
+		assert_eq!(last_event(), TestEvent::rdex_balances(RawEvent::Approval(1, 2, SYMBOL, 0)));
+		assert!(!Allowances::<Test>::contains_key((1, 2, SYMBOL)));
+	});
+}
+
+#[test]
+fn allowance_should_not_underflow_or_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RDexBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+		assert_noop!(
+			RDexBalances::decrease_allowance(Origin::signed(1), 2, SYMBOL, 101),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			RDexBalances::increase_allowance(Origin::signed(1), 2, SYMBOL, u128::max_value()),
+			Error::<Test>::Overflow
+		);
+		assert_eq!(RDexBalances::allowance(&1, &2, SYMBOL), 100);
+	});
+}
+
+#[test]
+fn transfer_from_should_spend_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RDexBalances::mint(&1, SYMBOL, 1000));
+		assert_ok!(RDexBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+
+		assert_noop!(
+			RDexBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 101),
+			Error::<Test>::InsufficientAllowance
+		);
+		// the allowance is per symbol and per spender
+		assert_noop!(
+			RDexBalances::transfer_from(Origin::signed(3), 1, 3, SYMBOL, 1),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			RDexBalances::transfer_from(Origin::signed(2), 1, 3, RSymbol::RKSM, 1),
+			Error::<Test>::InsufficientAllowance
+		);
+
+		assert_ok!(RDexBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 60));
+		assert_eq!(RDexBalances::free_balance(&1, SYMBOL), 940);
+		assert_eq!(RDexBalances::free_balance(&3, SYMBOL), 60);
+		assert_eq!(RDexBalances::allowances((1, 2, SYMBOL)), 40);
+		assert_eq!(last_event(), TestEvent::rdex_balances(RawEvent::Approval(1, 2, SYMBOL, 40)));
+
+		assert_ok!(RDexBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 40));
+		assert!(!Allowances::<Test>::contains_key((1, 2, SYMBOL)));
+	});
+}
+
+#[test]
+fn transfer_from_should_keep_allowance_when_balance_is_short() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RDexBalances::mint(&1, SYMBOL, 10));
+		assert_ok!(RDexBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+		assert_noop!(
+			RDexBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 11),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(RDexBalances::allowances((1, 2, SYMBOL)), 100);
+	});
+}
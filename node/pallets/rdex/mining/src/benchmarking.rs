@@ -0,0 +1,172 @@
+//! Benchmarks for the RDexMining Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use rdex_swap::{FeeModel, SwapPool};
+
+const SEED: u32 = 0;
+const SYMBOL: RSymbol = RSymbol::RDOT;
+const POOL_INDEX: u32 = 0;
+const LP_AMOUNT: u128 = 1_000_000_000_000;
+const SWAP_POOL_AMOUNT: u128 = 1_000_000_000_000_000;
+const REWARD_PER_BLOCK: u128 = 1_000_000_000;
+const TOTAL_REWARD: u128 = 1_000_000_000_000_000;
+
+fn set_swap_pool(fis_balance: u128, rtoken_balance: u128) {
+    rdex_swap::SwapPools::insert(SYMBOL, SwapPool {
+        symbol: SYMBOL,
+        fis_balance,
+        rtoken_balance,
+        total_unit: SWAP_POOL_AMOUNT,
+        fee_model: FeeModel::Slip,
+    });
+}
+
+/// a stake pool guarding impermanent loss, with the module account funded for rewards and guard
+fn add_stake_pool<T: Trait>() {
+    let stake_pool = StakePool {
+        symbol: SYMBOL,
+        emergency_switch: false,
+        total_stake_lp: 0,
+        start_block: 0,
+        reward_per_block: REWARD_PER_BLOCK,
+        total_reward: TOTAL_REWARD,
+        left_reward: TOTAL_REWARD,
+        lp_locked_blocks: 0,
+        last_reward_block: 0,
+        reward_per_share: 0,
+        guard_impermanent_loss: true,
+    };
+    <StakePools>::insert((SYMBOL, POOL_INDEX), vec![stake_pool]);
+    <PoolCount>::insert(SYMBOL, POOL_INDEX + 1);
+    <GuardLine>::insert((SYMBOL, POOL_INDEX), 0);
+    <GuardReserve>::insert(SYMBOL, TOTAL_REWARD);
+    T::Currency::make_free_balance_be(&Module::<T>::account_id(), (TOTAL_REWARD * 2).saturated_into());
+    set_swap_pool(SWAP_POOL_AMOUNT, SWAP_POOL_AMOUNT);
+}
+
+/// the caller stakes in the pool, then blocks pass and the swap pool price moves
+fn staked_caller<T: Trait>() -> Result<T::AccountId, &'static str> {
+    let caller: T::AccountId = whitelisted_caller();
+    add_stake_pool::<T>();
+    LpSwitch::insert(SYMBOL, true);
+    T::LpCurrency::mint(&caller, SYMBOL, LP_AMOUNT)?;
+    Module::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), SYMBOL, POOL_INDEX, 0, LP_AMOUNT)?;
+    let now = system::Module::<T>::block_number();
+    system::Module::<T>::set_block_number(now + 100u32.into());
+    set_swap_pool(SWAP_POOL_AMOUNT * 2, SWAP_POOL_AMOUNT / 2);
+    Ok(caller)
+}
+
+benchmarks! {
+    _ { }
+
+    deposit {
+        let caller: T::AccountId = whitelisted_caller();
+        add_stake_pool::<T>();
+        LpSwitch::insert(SYMBOL, true);
+        T::LpCurrency::mint(&caller, SYMBOL, LP_AMOUNT)?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, POOL_INDEX, 0, LP_AMOUNT)
+    verify {
+        assert_eq!(Module::<T>::user_stake_count((SYMBOL, POOL_INDEX, &caller)), 1);
+    }
+
+    withdraw {
+        let caller = staked_caller::<T>()?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, POOL_INDEX, 0, LP_AMOUNT)
+    verify {
+        assert_eq!(T::LpCurrency::free_balance(&caller, SYMBOL), LP_AMOUNT);
+        assert!(Module::<T>::guard_reserve(SYMBOL) < TOTAL_REWARD);
+    }
+
+    claim_reward {
+        let caller = staked_caller::<T>()?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, POOL_INDEX, 0)
+    verify {
+        assert!(Module::<T>::stake_users((SYMBOL, POOL_INDEX, &caller, 0)).unwrap().claimed_reward > 0);
+    }
+
+    emergency_withdraw {
+        let caller = staked_caller::<T>()?;
+        Module::<T>::emergency_switch(RawOrigin::Root.into(), SYMBOL, POOL_INDEX, 0)?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, POOL_INDEX, 0)
+    verify {
+        assert_eq!(T::LpCurrency::free_balance(&caller, SYMBOL), LP_AMOUNT);
+    }
+
+    add_pool {
+        <StakePools>::insert((SYMBOL, POOL_INDEX), Vec::<StakePool>::new());
+    }: _(RawOrigin::Root, SYMBOL, POOL_INDEX, 0, 0, REWARD_PER_BLOCK, TOTAL_REWARD, true)
+    verify {
+        assert_eq!(Module::<T>::stake_pools((SYMBOL, POOL_INDEX)).unwrap().len(), 1);
+    }
+
+    update_stake_pool {
+        add_stake_pool::<T>();
+    }: _(RawOrigin::Root, SYMBOL, POOL_INDEX, 0, REWARD_PER_BLOCK * 2, TOTAL_REWARD * 2)
+    verify {
+        assert_eq!(Module::<T>::stake_pools((SYMBOL, POOL_INDEX)).unwrap()[0].left_reward, TOTAL_REWARD * 2);
+    }
+
+    rm_pool {
+        add_stake_pool::<T>();
+    }: _(RawOrigin::Root, SYMBOL, POOL_INDEX, 0)
+    verify {
+        assert!(Module::<T>::stake_pools((SYMBOL, POOL_INDEX)).unwrap().is_empty());
+    }
+
+    increase_pool_index {
+    }: _(RawOrigin::Root, SYMBOL)
+    verify {
+        assert_eq!(Module::<T>::pool_count(SYMBOL), 1);
+    }
+
+    emergency_switch {
+        add_stake_pool::<T>();
+    }: _(RawOrigin::Root, SYMBOL, POOL_INDEX, 0)
+    verify {
+        assert!(Module::<T>::stake_pools((SYMBOL, POOL_INDEX)).unwrap()[0].emergency_switch);
+    }
+
+    withdraw_guard_fund {
+        add_stake_pool::<T>();
+        let to: T::AccountId = account("to", 0, SEED);
+    }: _(RawOrigin::Root, SYMBOL, to, TOTAL_REWARD / 2)
+    verify {
+        assert_eq!(Module::<T>::guard_reserve(SYMBOL), TOTAL_REWARD / 2);
+    }
+
+    set_guard_line {
+    }: _(RawOrigin::Root, SYMBOL, POOL_INDEX, 100)
+    verify {
+        assert_eq!(Module::<T>::guard_line((SYMBOL, POOL_INDEX)), 100);
+    }
+
+    set_guard_reserve {
+    }: _(RawOrigin::Root, SYMBOL, TOTAL_REWARD)
+    verify {
+        assert_eq!(Module::<T>::guard_reserve(SYMBOL), TOTAL_REWARD);
+    }
+
+    add_lp_to_whitelist {
+        let who: T::AccountId = account("lp", 0, SEED);
+    }: _(RawOrigin::Root, SYMBOL, who.clone())
+    verify {
+        assert!(Module::<T>::lp_whitelist((SYMBOL, who)));
+    }
+
+    remove_lp_from_whitelist {
+        let who: T::AccountId = account("lp", 0, SEED);
+        <LpWhitelist<T>>::insert((SYMBOL, who.clone()), true);
+    }: _(RawOrigin::Root, SYMBOL, who.clone())
+    verify {
+        assert!(!Module::<T>::lp_whitelist((SYMBOL, who)));
+    }
+
+    toggle_lp_switch {
+    }: _(RawOrigin::Root, SYMBOL)
+    verify {
+        assert!(Module::<T>::lp_switch(SYMBOL));
+    }
+}
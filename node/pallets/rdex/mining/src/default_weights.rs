@@ -0,0 +1,54 @@
+//! Default weights for the RDexMining Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn deposit() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn withdraw() -> Weight {
+        20_000_000_000 as Weight
+    }
+    fn claim_reward() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn emergency_withdraw() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn add_pool() -> Weight {
+        10_000 as Weight
+    }
+    fn update_stake_pool() -> Weight {
+        10_000 as Weight
+    }
+    fn rm_pool() -> Weight {
+        10_000 as Weight
+    }
+    fn increase_pool_index() -> Weight {
+        10_000 as Weight
+    }
+    fn emergency_switch() -> Weight {
+        10_000 as Weight
+    }
+    fn withdraw_guard_fund() -> Weight {
+        100_000 as Weight
+    }
+    fn set_guard_line() -> Weight {
+        100_000 as Weight
+    }
+    fn set_guard_reserve() -> Weight {
+        100_000 as Weight
+    }
+    fn add_lp_to_whitelist() -> Weight {
+        10_000 as Weight
+    }
+    fn remove_lp_from_whitelist() -> Weight {
+        10_000 as Weight
+    }
+    fn toggle_lp_switch() -> Weight {
+        100_000 as Weight
+    }
+}
@@ -5,6 +5,7 @@ use frame_support::{
     dispatch::DispatchResult,
     ensure,
     traits::{Currency, ExistenceRequirement::KeepAlive},
+    weights::Weight,
 };
 use sp_std::prelude::*;
 
@@ -21,6 +22,8 @@ pub trait Trait: system::Trait {
     type Currency: Currency<Self::AccountId>;
     /// currency of lp
     type LpCurrency: LpCurrency<Self::AccountId>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 pub mod models;
@@ -31,6 +34,27 @@ use sp_core::U512;
 mod mock;
 #[cfg(test)]
 mod tests;
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub trait WeightInfo {
+    fn deposit() -> Weight;
+    fn withdraw() -> Weight;
+    fn claim_reward() -> Weight;
+    fn emergency_withdraw() -> Weight;
+    fn add_pool() -> Weight;
+    fn update_stake_pool() -> Weight;
+    fn rm_pool() -> Weight;
+    fn increase_pool_index() -> Weight;
+    fn emergency_switch() -> Weight;
+    fn withdraw_guard_fund() -> Weight;
+    fn set_guard_line() -> Weight;
+    fn set_guard_reserve() -> Weight;
+    fn add_lp_to_whitelist() -> Weight;
+    fn remove_lp_from_whitelist() -> Weight;
+    fn toggle_lp_switch() -> Weight;
+}
 
 const MODULE_ID: ModuleId = ModuleId(*b"rdx/mine");
 const REWARD_FACTOR: u128 = 1_000_000_000_000;
@@ -99,7 +123,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// deposit
-        #[weight = 10_000_000_000]
+        #[weight = T::WeightInfo::deposit()]
         pub fn deposit(origin, symbol: RSymbol, pool_index: u32, grade_index: u32, lp_amount: u128) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(Self::lp_whitelist((symbol, who.clone())) || Self::lp_switch(symbol), Error::<T>::DepositNotAllowed);
@@ -140,7 +164,7 @@ decl_module! {
         }
 
         /// withdraw
-        #[weight = 20_000_000_000]
+        #[weight = T::WeightInfo::withdraw()]
         pub fn withdraw(origin, symbol: RSymbol, pool_index: u32, stake_index: u32, lp_amount: u128) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let mut stake_user = Self::stake_users((symbol, pool_index, &who, stake_index)).ok_or(Error::<T>::StakeUserNotExist)?;
@@ -163,7 +187,23 @@ decl_module! {
                 saturating_sub(stake_user.reward_debt);
             let reserved_lp_total_reward = stake_user.reserved_lp_reward.saturating_add(withdraw_reward);
 
-            let guard_amount: u128 = 0;
+            let guard_amount = if stake_pool.guard_impermanent_loss &&
+                now_block >= stake_user.deposit_height.saturating_add(Self::guard_line((symbol, pool_index))) {
+                let swap_pool = rdex_swap::SwapPools::get(symbol).ok_or(Error::<T>::SwapPoolNotExist)?;
+                let loss = Self::cal_guard_amount(
+                    Self::cal_share_amount(stake_user.lp_amount, lp_amount, stake_user.total_fis_value),
+                    Self::cal_share_amount(stake_user.lp_amount, lp_amount, stake_user.total_rtoken_value),
+                    Self::cal_share_amount(swap_pool.total_unit, lp_amount, swap_pool.fis_balance),
+                    Self::cal_share_amount(swap_pool.total_unit, lp_amount, swap_pool.rtoken_balance),
+                    swap_pool.fis_balance,
+                    swap_pool.rtoken_balance,
+                );
+                let module_free_balance = T::Currency::free_balance(&Self::account_id()).saturated_into::<u128>();
+                loss.min(Self::guard_reserve(symbol))
+                    .min(module_free_balance.saturating_sub(withdraw_reward))
+            } else {
+                0
+            };
 
             let reserved_lp = stake_user.lp_amount.saturating_sub(lp_amount);
             stake_user.total_fis_value = Self::cal_share_amount(stake_user.lp_amount, reserved_lp, stake_user.total_fis_value);
@@ -182,6 +222,9 @@ decl_module! {
             }
 
             T::LpCurrency::transfer(&Self::account_id(), &who, symbol, lp_amount)?;
+            if guard_amount > 0 {
+                <GuardReserve>::mutate(symbol, |reserve| *reserve = reserve.saturating_sub(guard_amount));
+            }
             <StakeUsers<T>>::insert((symbol, pool_index, &who, stake_index), stake_user.clone());
             <StakePools>::insert((symbol, pool_index), stake_pool_vec);
             Self::deposit_event(RawEvent::Withdraw(who, symbol, pool_index, stake_user.grade_index, stake_index, lp_amount, withdraw_reward, guard_amount));
@@ -189,7 +232,7 @@ decl_module! {
         }
 
         /// claim reward
-        #[weight = 10_000_000_000]
+        #[weight = T::WeightInfo::claim_reward()]
         pub fn claim_reward(origin, symbol: RSymbol, pool_index: u32, stake_index: u32) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let mut stake_user = Self::stake_users((symbol, pool_index, &who, stake_index)).ok_or(Error::<T>::StakeUserNotExist)?;
@@ -220,7 +263,7 @@ decl_module! {
         }
 
          /// emergency withdraw
-         #[weight = 10_000_000_000]
+         #[weight = T::WeightInfo::emergency_withdraw()]
          pub fn emergency_withdraw(origin, symbol: RSymbol, pool_index: u32, stake_index: u32) -> DispatchResult {
              let who = ensure_signed(origin)?;
              let mut stake_user = Self::stake_users((symbol, pool_index, &who, stake_index)).ok_or(Error::<T>::StakeUserNotExist)?;
@@ -248,7 +291,7 @@ decl_module! {
          }
 
         /// create pool
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::add_pool()]
         pub fn add_pool(origin, symbol: RSymbol, pool_index: u32, start_block: u32, lp_locked_blocks: u32, reward_per_block: u128, total_reward: u128, guard_impermanent_loss: bool) -> DispatchResult {
             ensure_root(origin.clone())?;
             let mut stake_pool_vec = Self::stake_pools((symbol, pool_index)).ok_or(Error::<T>::StakePoolNotExist)?;
@@ -280,7 +323,7 @@ decl_module! {
         }
 
         /// update pool params
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::update_stake_pool()]
         pub fn update_stake_pool(origin, symbol: RSymbol, pool_index: u32, grade_index: u32, reward_per_block: u128, total_reward: u128) -> DispatchResult {
             ensure_root(origin.clone())?;
             let mut stake_pool_vec = Self::stake_pools((symbol, pool_index)).ok_or(Error::<T>::StakePoolNotExist)?;
@@ -301,7 +344,7 @@ decl_module! {
         }
 
         /// remove pool
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::rm_pool()]
         pub fn rm_pool(origin, symbol: RSymbol, pool_index: u32, grade_index: u32) -> DispatchResult {
             ensure_root(origin.clone())?;
             let mut stake_pool_vec = Self::stake_pools((symbol, pool_index)).ok_or(Error::<T>::StakePoolNotExist)?;
@@ -315,7 +358,7 @@ decl_module! {
         }
 
         /// increase pool index
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::increase_pool_index()]
         pub fn increase_pool_index(origin, symbol: RSymbol) -> DispatchResult {
             ensure_root(origin.clone())?;
             let pool_count = Self::pool_count(symbol);
@@ -327,7 +370,7 @@ decl_module! {
         }
 
         /// emergency switch
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::emergency_switch()]
         pub fn emergency_switch(origin, symbol: RSymbol, pool_index: u32, grade_index: u32) -> DispatchResult {
             ensure_root(origin.clone())?;
 
@@ -343,7 +386,7 @@ decl_module! {
         }
 
         /// withdraw guard fund
-        #[weight = 100_000]
+        #[weight = T::WeightInfo::withdraw_guard_fund()]
         fn withdraw_guard_fund(origin, symbol: RSymbol, to_address: T::AccountId, amount: u128) -> DispatchResult {
             ensure_root(origin)?;
             let mut withdraw_amount = amount;
@@ -363,35 +406,35 @@ decl_module! {
         }
 
         /// set guard line
-        #[weight = 100_000]
+        #[weight = T::WeightInfo::set_guard_line()]
         fn set_guard_line(origin, symbol: RSymbol, pool_index: u32, line: u32) -> DispatchResult {
             ensure_root(origin)?;
             <GuardLine>::insert((symbol, pool_index), line);
             Ok(())
         }
         /// set guard reserve
-        #[weight = 100_000]
+        #[weight = T::WeightInfo::set_guard_reserve()]
         fn set_guard_reserve(origin, symbol: RSymbol, amount: u128) -> DispatchResult {
             ensure_root(origin)?;
             <GuardReserve>::insert(symbol, amount);
             Ok(())
         }
          /// add lp to whitelist
-         #[weight = 10_000]
+         #[weight = T::WeightInfo::add_lp_to_whitelist()]
          pub fn add_lp_to_whitelist(origin, symbol: RSymbol, who: T::AccountId) -> DispatchResult {
              ensure_root(origin.clone())?;
              <LpWhitelist<T>>::insert((symbol, who), true);
              Ok(())
          }
          /// remove lp from whitelist
-         #[weight = 10_000]
+         #[weight = T::WeightInfo::remove_lp_from_whitelist()]
          pub fn remove_lp_from_whitelist(origin, symbol: RSymbol, who: T::AccountId) -> DispatchResult {
              ensure_root(origin.clone())?;
              <LpWhitelist<T>>::remove((symbol, who));
              Ok(())
          }
          /// turn on/off lp switch, default closed
-         #[weight = 100_000]
+         #[weight = T::WeightInfo::toggle_lp_switch()]
          fn toggle_lp_switch(origin, symbol: RSymbol) -> DispatchResult {
              ensure_root(origin)?;
              let state = Self::lp_switch(symbol);
@@ -466,6 +509,40 @@ impl<T: Trait> Module<T> {
         Self::safe_to_u128(share_amount)
     }
 
+    // F = pool fis balance, R = pool rToken balance
+    // deposit value = deposit fis + deposit rToken * F / R
+    // share value = share fis + share rToken * F / R
+    // guard = deposit value - share value, valued in fis at the current pool price
+    pub fn cal_guard_amount(
+        deposit_fis: u128,
+        deposit_rtoken: u128,
+        share_fis: u128,
+        share_rtoken: u128,
+        pool_fis: u128,
+        pool_rtoken: u128,
+    ) -> u128 {
+        if pool_fis == 0 || pool_rtoken == 0 {
+            return 0;
+        }
+        let f_capital = U512::from(pool_fis);
+        let r_capital = U512::from(pool_rtoken);
+        let deposit_value = U512::from(deposit_fis)
+            .saturating_mul(r_capital)
+            .saturating_add(U512::from(deposit_rtoken).saturating_mul(f_capital));
+        let share_value = U512::from(share_fis)
+            .saturating_mul(r_capital)
+            .saturating_add(U512::from(share_rtoken).saturating_mul(f_capital));
+        if deposit_value <= share_value {
+            return 0;
+        }
+        let loss = deposit_value
+            .saturating_sub(share_value)
+            .checked_div(r_capital)
+            .unwrap_or(U512::zero());
+
+        Self::safe_to_u128(loss)
+    }
+
     pub fn safe_to_u128(number: U512) -> u128 {
         if number > U512::from(u128::max_value()) {
             u128::max_value()
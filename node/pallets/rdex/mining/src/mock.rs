@@ -81,6 +81,7 @@ impl Trait for Test {
     type Event = ();
     type Currency = Balances;
     type LpCurrency = LpBalances;
+    type WeightInfo = ();
 }
 
 impl rdex_swap::Trait for Test {
@@ -88,6 +89,7 @@ impl rdex_swap::Trait for Test {
     type RCurrency = RBalances;
     type Currency = Balances;
     type LpCurrency = LpBalances;
+    type WeightInfo = ();
 }
 
 impl pallet_balances::Trait for Test {
@@ -104,6 +106,12 @@ impl rdex_balances::Trait for Test {
     type Event = ();
 }
 
+impl rtoken_registry::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
 impl rtoken_balances::Trait for Test {
     type Event = ();
 }
@@ -126,6 +134,12 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut t)
     .unwrap();
 
+    rtoken_registry::GenesisConfig {
+        assets: rtoken_registry::builtin_assets(),
+    }
+    .assimilate_storage::<Test>(&mut t)
+    .unwrap();
+
     t.into()
 }
 
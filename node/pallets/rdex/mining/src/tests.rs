@@ -214,7 +214,9 @@ fn deposit_should_work() {
             Origin::signed(U256::from(1)),
             symbol,
             20,
-            10
+            10,
+            0,
+            None
         ));
         assert_eq!(LpBalances::free_balance(&U256::from(1), symbol), 10);
 
@@ -302,7 +304,9 @@ fn deposit_should_fail() {
             Origin::signed(U256::from(1)),
             symbol,
             20,
-            10
+            10,
+            0,
+            None
         ));
         assert_eq!(LpBalances::free_balance(&U256::from(1), symbol), 10);
 
@@ -380,7 +384,9 @@ fn withdraw_should_work_before_start() {
             Origin::signed(U256::from(1)),
             symbol,
             20,
-            10
+            10,
+            0,
+            None
         ));
         assert_eq!(LpBalances::free_balance(&U256::from(1), symbol), 10);
 
@@ -488,7 +494,9 @@ fn withdraw_should_work_after_start() {
             Origin::signed(U256::from(1)),
             symbol,
             20,
-            10
+            10,
+            0,
+            None
         ));
         assert_eq!(LpBalances::free_balance(&U256::from(1), symbol), 10);
 
@@ -598,13 +606,17 @@ fn withdraw_should_work_with_multi() {
             Origin::signed(U256::from(1)),
             symbol,
             20,
-            10
+            10,
+            0,
+            None
         ));
         assert_ok!(RDexSwap::add_liquidity(
             Origin::signed(U256::from(3)),
             symbol,
             40,
-            20
+            20,
+            0,
+            None
         ));
 
         assert_eq!(LpBalances::free_balance(&U256::from(1), symbol), 10);
@@ -748,13 +760,17 @@ fn claim_reward_should_work_with_multi() {
             Origin::signed(U256::from(1)),
             symbol,
             20,
-            10
+            10,
+            0,
+            None
         ));
         assert_ok!(RDexSwap::add_liquidity(
             Origin::signed(U256::from(3)),
             symbol,
             40,
-            20
+            20,
+            0,
+            None
         ));
 
         assert_eq!(LpBalances::free_balance(&U256::from(1), symbol), 10);
@@ -873,3 +889,193 @@ fn claim_reward_should_work_with_multi() {
         );
     });
 }
+
+#[test]
+fn cal_guard_amount_should_work() {
+    new_test_ext().execute_with(|| {
+        // pool price unchanged
+        assert_eq!(RDexMining::cal_guard_amount(10, 20, 10, 20, 20, 40), 0);
+        // share worth more than deposit
+        assert_eq!(RDexMining::cal_guard_amount(10, 20, 60, 17, 120, 35), 0);
+        // (10 * 16 + 20 * 50 - 25 * 16 - 8 * 50) / 16
+        assert_eq!(RDexMining::cal_guard_amount(10, 20, 25, 8, 50, 16), 22);
+        // empty pool side
+        assert_eq!(RDexMining::cal_guard_amount(10, 20, 0, 0, 0, 16), 0);
+        assert_eq!(RDexMining::cal_guard_amount(10, 20, 0, 0, 50, 0), 0);
+    });
+}
+
+#[test]
+fn withdraw_should_pay_guard() {
+    new_test_ext().execute_with(|| {
+        let symbol = RSymbol::RATOM;
+        assert_ok!(RBalances::mint(&U256::from(42), symbol, 100));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            U256::from(42),
+            symbol,
+            20,
+            10
+        ));
+        // no swap fee, so the price move is pure impermanent loss
+        assert_ok!(RDexSwap::set_fee_model(
+            Origin::root(),
+            symbol,
+            rdex_swap::FeeModel::ConstantProduct(0)
+        ));
+        assert_ok!(RDexSwap::toggle_lp_switch(Origin::root(), symbol));
+        assert_ok!(RDexMining::toggle_lp_switch(Origin::root(), symbol));
+
+        // add liquidity
+        assert_ok!(RBalances::mint(&U256::from(1), symbol, 100));
+        assert_ok!(RDexSwap::add_liquidity(
+            Origin::signed(U256::from(1)),
+            symbol,
+            20,
+            10,
+            0,
+            None
+        ));
+
+        // increase pool index
+        assert_ok!(RDexMining::increase_pool_index(Origin::root(), symbol));
+        assert_ok!(RDexMining::add_pool(
+            Origin::root(),
+            symbol,
+            0,
+            2,
+            10,
+            10,
+            200,
+            true
+        ));
+        assert_ok!(Balances::transfer(
+            Origin::signed(U256::from(2)),
+            RDexMining::account_id(),
+            200
+        ));
+        assert_ok!(Balances::transfer(
+            Origin::signed(U256::from(3)),
+            RDexMining::account_id(),
+            50
+        ));
+        assert_ok!(RDexMining::set_guard_line(Origin::root(), symbol, 0, 5));
+        assert_ok!(RDexMining::set_guard_reserve(Origin::root(), symbol, 50));
+
+        // deposit
+        assert_ok!(RDexMining::deposit(
+            Origin::signed(U256::from(1)),
+            symbol,
+            0,
+            0,
+            10
+        ));
+        let stake_user = RDexMining::stake_users((symbol, 0, &U256::from(1), 0)).unwrap();
+        assert_eq!(stake_user.total_fis_value, 10);
+        assert_eq!(stake_user.total_rtoken_value, 20);
+
+        // move the price
+        assert_ok!(RDexSwap::swap(
+            Origin::signed(U256::from(4)),
+            symbol,
+            30,
+            24,
+            true,
+            None
+        ));
+        let swap_pool = RDexSwap::swap_pools(symbol).unwrap();
+        assert_eq!(swap_pool.fis_balance, 50);
+        assert_eq!(swap_pool.rtoken_balance, 16);
+
+        run_to_block(12);
+
+        assert_ok!(RDexMining::withdraw(
+            Origin::signed(U256::from(1)),
+            symbol,
+            0,
+            0,
+            10
+        ));
+        // 90 left after adding liquidity, 100 reward and 22 guard
+        assert_eq!(Balances::free_balance(&U256::from(1)), 90 + 100 + 22);
+        assert_eq!(RDexMining::guard_reserve(symbol), 28);
+    });
+}
+
+#[test]
+fn withdraw_should_cap_guard_by_reserve() {
+    new_test_ext().execute_with(|| {
+        let symbol = RSymbol::RATOM;
+        assert_ok!(RBalances::mint(&U256::from(42), symbol, 100));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            U256::from(42),
+            symbol,
+            20,
+            10
+        ));
+        assert_ok!(RDexSwap::set_fee_model(
+            Origin::root(),
+            symbol,
+            rdex_swap::FeeModel::ConstantProduct(0)
+        ));
+        assert_ok!(RDexSwap::toggle_lp_switch(Origin::root(), symbol));
+        assert_ok!(RDexMining::toggle_lp_switch(Origin::root(), symbol));
+        assert_ok!(RBalances::mint(&U256::from(1), symbol, 100));
+        assert_ok!(RDexSwap::add_liquidity(
+            Origin::signed(U256::from(1)),
+            symbol,
+            20,
+            10,
+            0,
+            None
+        ));
+
+        assert_ok!(RDexMining::increase_pool_index(Origin::root(), symbol));
+        assert_ok!(RDexMining::add_pool(
+            Origin::root(),
+            symbol,
+            0,
+            2,
+            10,
+            10,
+            200,
+            true
+        ));
+        assert_ok!(Balances::transfer(
+            Origin::signed(U256::from(2)),
+            RDexMining::account_id(),
+            210
+        ));
+        assert_ok!(RDexMining::set_guard_line(Origin::root(), symbol, 0, 5));
+        assert_ok!(RDexMining::set_guard_reserve(Origin::root(), symbol, 10));
+
+        assert_ok!(RDexMining::deposit(
+            Origin::signed(U256::from(1)),
+            symbol,
+            0,
+            0,
+            10
+        ));
+        assert_ok!(RDexSwap::swap(
+            Origin::signed(U256::from(4)),
+            symbol,
+            30,
+            24,
+            true,
+            None
+        ));
+
+        run_to_block(12);
+
+        assert_ok!(RDexMining::withdraw(
+            Origin::signed(U256::from(1)),
+            symbol,
+            0,
+            0,
+            10
+        ));
+        assert_eq!(Balances::free_balance(&U256::from(1)), 90 + 100 + 10);
+        assert_eq!(RDexMining::guard_reserve(symbol), 0);
+    });
+}
@@ -0,0 +1,29 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for quoting the rdex swap pools.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use node_primitives::RSymbol;
+
+sp_api::decl_runtime_apis! {
+    pub trait RDexSwapApi {
+        /// (output amount, fee amount) of a swap, none if the pool rejects it
+        fn quote_swap(symbol: RSymbol, input_amount: u128, input_is_fis: bool) -> Option<(u128, u128)>;
+        /// (new total unit, add lp unit) of adding liquidity
+        fn quote_add_liquidity(symbol: RSymbol, rtoken_amount: u128, fis_amount: u128) -> Option<(u128, u128)>;
+        /// (fis amount, rToken amount) of removing liquidity
+        fn quote_remove_liquidity(symbol: RSymbol, rm_unit: u128, swap_unit: u128, input_is_fis: bool) -> Option<(u128, u128)>;
+        /// (fis amount, output amount, input pool fee, output pool fee) of a rToken to rToken swap through fis
+        fn quote_swap_via_fis(input_symbol: RSymbol, output_symbol: RSymbol, input_amount: u128) -> Option<(u128, u128, u128, u128)>;
+    }
+}
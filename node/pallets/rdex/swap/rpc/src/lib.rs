@@ -0,0 +1,153 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for quoting the rdex swap pools.
+
+use std::sync::Arc;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use serde::{Serialize, Deserialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use node_primitives::RSymbol;
+use rtoken_ledger_rpc::{to_number_or_hex, to_u128, runtime_error_into_rpc_err};
+pub use rdex_swap_rpc_runtime_api::RDexSwapApi as RDexSwapRuntimeApi;
+
+/// Quote of a swap
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapQuote {
+    pub out_amount: NumberOrHex,
+    pub fee: NumberOrHex,
+}
+
+/// Quote of adding liquidity
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddLiquidityQuote {
+    pub new_total_unit: NumberOrHex,
+    pub add_unit: NumberOrHex,
+}
+
+/// Quote of removing liquidity
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveLiquidityQuote {
+    pub fis_amount: NumberOrHex,
+    pub rtoken_amount: NumberOrHex,
+}
+
+/// Quote of a rToken to rToken swap through fis
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapViaFisQuote {
+    pub fis_amount: NumberOrHex,
+    pub out_amount: NumberOrHex,
+    pub input_pool_fee: NumberOrHex,
+    pub output_pool_fee: NumberOrHex,
+}
+
+#[rpc]
+pub trait RDexSwapApi<BlockHash> {
+    /// quote a swap, null if the pool does not exist or rejects it
+    #[rpc(name = "rdex_quoteSwap")]
+    fn quote_swap(&self, symbol: RSymbol, input_amount: NumberOrHex, input_is_fis: bool, at: Option<BlockHash>) -> Result<Option<SwapQuote>>;
+
+    /// quote the lp unit of adding liquidity
+    #[rpc(name = "rdex_quoteAddLiquidity")]
+    fn quote_add_liquidity(&self, symbol: RSymbol, rtoken_amount: NumberOrHex, fis_amount: NumberOrHex, at: Option<BlockHash>) -> Result<Option<AddLiquidityQuote>>;
+
+    /// quote the output of removing liquidity
+    #[rpc(name = "rdex_quoteRemoveLiquidity")]
+    fn quote_remove_liquidity(&self, symbol: RSymbol, rm_unit: NumberOrHex, swap_unit: NumberOrHex, input_is_fis: bool, at: Option<BlockHash>) -> Result<Option<RemoveLiquidityQuote>>;
+
+    /// quote a rToken to rToken swap through fis
+    #[rpc(name = "rdex_quoteSwapViaFis")]
+    fn quote_swap_via_fis(&self, input_symbol: RSymbol, output_symbol: RSymbol, input_amount: NumberOrHex, at: Option<BlockHash>) -> Result<Option<SwapViaFisQuote>>;
+}
+
+/// An implementation of rdex swap specific RPC methods.
+pub struct RDexSwap<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> RDexSwap<C, B> {
+    /// Create new `RDexSwap` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block> RDexSwapApi<<Block as BlockT>::Hash> for RDexSwap<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: RDexSwapRuntimeApi<Block>,
+{
+    fn quote_swap(&self, symbol: RSymbol, input_amount: NumberOrHex, input_is_fis: bool, at: Option<<Block as BlockT>::Hash>) -> Result<Option<SwapQuote>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let input_amount = to_u128(input_amount)?;
+
+        api.quote_swap(&at, symbol, input_amount, input_is_fis)
+            .map(|quote| quote.map(|(out_amount, fee)| SwapQuote {
+                out_amount: to_number_or_hex(out_amount),
+                fee: to_number_or_hex(fee),
+            }))
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn quote_add_liquidity(&self, symbol: RSymbol, rtoken_amount: NumberOrHex, fis_amount: NumberOrHex, at: Option<<Block as BlockT>::Hash>) -> Result<Option<AddLiquidityQuote>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let rtoken_amount = to_u128(rtoken_amount)?;
+        let fis_amount = to_u128(fis_amount)?;
+
+        api.quote_add_liquidity(&at, symbol, rtoken_amount, fis_amount)
+            .map(|quote| quote.map(|(new_total_unit, add_unit)| AddLiquidityQuote {
+                new_total_unit: to_number_or_hex(new_total_unit),
+                add_unit: to_number_or_hex(add_unit),
+            }))
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn quote_remove_liquidity(&self, symbol: RSymbol, rm_unit: NumberOrHex, swap_unit: NumberOrHex, input_is_fis: bool, at: Option<<Block as BlockT>::Hash>) -> Result<Option<RemoveLiquidityQuote>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let rm_unit = to_u128(rm_unit)?;
+        let swap_unit = to_u128(swap_unit)?;
+
+        api.quote_remove_liquidity(&at, symbol, rm_unit, swap_unit, input_is_fis)
+            .map(|quote| quote.map(|(fis_amount, rtoken_amount)| RemoveLiquidityQuote {
+                fis_amount: to_number_or_hex(fis_amount),
+                rtoken_amount: to_number_or_hex(rtoken_amount),
+            }))
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn quote_swap_via_fis(&self, input_symbol: RSymbol, output_symbol: RSymbol, input_amount: NumberOrHex, at: Option<<Block as BlockT>::Hash>) -> Result<Option<SwapViaFisQuote>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let input_amount = to_u128(input_amount)?;
+
+        api.quote_swap_via_fis(&at, input_symbol, output_symbol, input_amount)
+            .map(|quote| quote.map(|(fis_amount, out_amount, input_pool_fee, output_pool_fee)| SwapViaFisQuote {
+                fis_amount: to_number_or_hex(fis_amount),
+                out_amount: to_number_or_hex(out_amount),
+                input_pool_fee: to_number_or_hex(input_pool_fee),
+                output_pool_fee: to_number_or_hex(output_pool_fee),
+            }))
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
@@ -0,0 +1,127 @@
+//! Benchmarks for the RDexSwap Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+
+const SEED: u32 = 0;
+const SYMBOL: RSymbol = RSymbol::RDOT;
+const OTHER_SYMBOL: RSymbol = RSymbol::RKSM;
+const POOL_AMOUNT: u128 = 1_000_000_000_000_000;
+const AMOUNT: u128 = 1_000_000_000_000;
+
+fn funded_account<T: Trait>(who: T::AccountId) -> Result<T::AccountId, &'static str> {
+    T::Currency::make_free_balance_be(&who, (POOL_AMOUNT * 10).saturated_into());
+    T::RCurrency::mint(&who, SYMBOL, POOL_AMOUNT * 10)?;
+    T::RCurrency::mint(&who, OTHER_SYMBOL, POOL_AMOUNT * 10)?;
+    Ok(who)
+}
+
+/// creates the pool of `symbol` with the hybrid fee model, the most expensive one to compute
+fn create_pool_of<T: Trait>(creator: &T::AccountId, symbol: RSymbol) -> DispatchResult {
+    Module::<T>::create_pool(RawOrigin::Root.into(), creator.clone(), symbol, POOL_AMOUNT, POOL_AMOUNT)?;
+    Module::<T>::set_fee_model(RawOrigin::Root.into(), symbol, FeeModel::Hybrid(30))
+}
+
+/// turns the protocol fee on, so that every swap also pays the receiver
+fn enable_protocol_fee<T: Trait>() -> Result<(), &'static str> {
+    let receiver = funded_account::<T>(account("receiver", 0, SEED))?;
+    <ProtocolFeeReceiver<T>>::put(receiver);
+    ProtocolFeeShare::put(Perbill::from_percent(10));
+    Ok(())
+}
+
+benchmarks! {
+    _ { }
+
+    swap {
+        let creator = funded_account::<T>(account("creator", 0, SEED))?;
+        create_pool_of::<T>(&creator, SYMBOL)?;
+        enable_protocol_fee::<T>()?;
+        let caller = funded_account::<T>(whitelisted_caller())?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, AMOUNT, 1, false, None)
+    verify {
+        assert_eq!(T::RCurrency::free_balance(&caller, SYMBOL), POOL_AMOUNT * 10 - AMOUNT);
+    }
+
+    swap_via_fis {
+        let creator = funded_account::<T>(account("creator", 0, SEED))?;
+        create_pool_of::<T>(&creator, SYMBOL)?;
+        create_pool_of::<T>(&creator, OTHER_SYMBOL)?;
+        enable_protocol_fee::<T>()?;
+        let caller = funded_account::<T>(whitelisted_caller())?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, OTHER_SYMBOL, AMOUNT, 1, None)
+    verify {
+        assert!(T::RCurrency::free_balance(&caller, OTHER_SYMBOL) > POOL_AMOUNT * 10);
+    }
+
+    add_liquidity {
+        let creator = funded_account::<T>(account("creator", 0, SEED))?;
+        create_pool_of::<T>(&creator, SYMBOL)?;
+        LpSwitch::insert(SYMBOL, true);
+        let caller = funded_account::<T>(whitelisted_caller())?;
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, AMOUNT, AMOUNT, 1, None)
+    verify {
+        assert!(T::LpCurrency::free_balance(&caller, SYMBOL) > 0);
+    }
+
+    remove_liquidity {
+        let caller = funded_account::<T>(whitelisted_caller())?;
+        create_pool_of::<T>(&caller, SYMBOL)?;
+        enable_protocol_fee::<T>()?;
+        let lp_unit = T::LpCurrency::free_balance(&caller, SYMBOL);
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, lp_unit / 2, lp_unit / 4, 0, 0, true, None)
+    verify {
+        assert_eq!(T::LpCurrency::free_balance(&caller, SYMBOL), lp_unit - lp_unit / 2);
+    }
+
+    create_pool {
+        let creator = funded_account::<T>(account("creator", 0, SEED))?;
+    }: _(RawOrigin::Root, creator, SYMBOL, POOL_AMOUNT, POOL_AMOUNT)
+    verify {
+        assert!(Module::<T>::swap_pools(SYMBOL).is_some());
+    }
+
+    add_lp_to_whitelist {
+        let who: T::AccountId = account("lp", 0, SEED);
+    }: _(RawOrigin::Root, SYMBOL, who.clone())
+    verify {
+        assert!(Module::<T>::lp_whitelist((SYMBOL, who)));
+    }
+
+    remove_lp_from_whitelist {
+        let who: T::AccountId = account("lp", 0, SEED);
+        <LpWhitelist<T>>::insert((SYMBOL, who.clone()), true);
+    }: _(RawOrigin::Root, SYMBOL, who.clone())
+    verify {
+        assert!(!Module::<T>::lp_whitelist((SYMBOL, who)));
+    }
+
+    set_fee_model {
+        let creator = funded_account::<T>(account("creator", 0, SEED))?;
+        Module::<T>::create_pool(RawOrigin::Root.into(), creator, SYMBOL, POOL_AMOUNT, POOL_AMOUNT)?;
+    }: _(RawOrigin::Root, SYMBOL, FeeModel::ConstantProduct(30))
+    verify {
+        assert_eq!(Module::<T>::swap_pools(SYMBOL).unwrap().fee_model, FeeModel::ConstantProduct(30));
+    }
+
+    set_protocol_fee_share {
+        <ProtocolFeeReceiver<T>>::put(account::<T::AccountId>("receiver", 0, SEED));
+    }: _(RawOrigin::Root, Perbill::from_percent(10))
+    verify {
+        assert_eq!(Module::<T>::protocol_fee_share(), Perbill::from_percent(10));
+    }
+
+    set_protocol_fee_receiver {
+        let receiver: T::AccountId = account("receiver", 0, SEED);
+    }: _(RawOrigin::Root, receiver.clone())
+    verify {
+        assert_eq!(Module::<T>::protocol_fee_receiver(), Some(receiver));
+    }
+
+    toggle_lp_switch {
+    }: _(RawOrigin::Root, SYMBOL)
+    verify {
+        assert!(Module::<T>::lp_switch(SYMBOL));
+    }
+}
@@ -0,0 +1,42 @@
+//! Default weights for the RDexSwap Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn swap() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn swap_via_fis() -> Weight {
+        20_000_000_000 as Weight
+    }
+    fn add_liquidity() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn remove_liquidity() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn create_pool() -> Weight {
+        10_000 as Weight
+    }
+    fn add_lp_to_whitelist() -> Weight {
+        10_000 as Weight
+    }
+    fn remove_lp_from_whitelist() -> Weight {
+        10_000 as Weight
+    }
+    fn set_fee_model() -> Weight {
+        100_000 as Weight
+    }
+    fn set_protocol_fee_share() -> Weight {
+        100_000 as Weight
+    }
+    fn set_protocol_fee_receiver() -> Weight {
+        100_000 as Weight
+    }
+    fn toggle_lp_switch() -> Weight {
+        100_000 as Weight
+    }
+}
@@ -4,7 +4,9 @@ use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::DispatchResult,
     ensure,
-    traits::{Currency, ExistenceRequirement::KeepAlive},
+    traits::{Currency, Get, ExistenceRequirement::KeepAlive},
+    storage::IterableStorageMap,
+    weights::Weight,
 };
 use sp_std::prelude::*;
 
@@ -14,7 +16,7 @@ use rdex_balances::traits::Currency as LpCurrency;
 use rtoken_balances::traits::Currency as RCurrency;
 use sp_runtime::{
     traits::{AccountIdConversion, SaturatedConversion},
-    ModuleId,
+    ModuleId, Perbill, DispatchError,
 };
 pub trait Trait: system::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
@@ -24,6 +26,8 @@ pub trait Trait: system::Trait {
     type Currency: Currency<Self::AccountId>;
     /// currency of lp
     type LpCurrency: LpCurrency<Self::AccountId>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 pub mod models;
@@ -34,6 +38,23 @@ use sp_core::U512;
 mod mock;
 #[cfg(test)]
 mod tests;
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub trait WeightInfo {
+    fn swap() -> Weight;
+    fn swap_via_fis() -> Weight;
+    fn add_liquidity() -> Weight;
+    fn remove_liquidity() -> Weight;
+    fn create_pool() -> Weight;
+    fn add_lp_to_whitelist() -> Weight;
+    fn remove_lp_from_whitelist() -> Weight;
+    fn set_fee_model() -> Weight;
+    fn set_protocol_fee_share() -> Weight;
+    fn set_protocol_fee_receiver() -> Weight;
+    fn toggle_lp_switch() -> Weight;
+}
 
 const MODULE_ID: ModuleId = ModuleId(*b"rdx/swap");
 
@@ -49,6 +70,16 @@ decl_event! {
         AddLiquidity(AccountId, RSymbol, u128, u128, u128, u128, u128, u128),
         /// RemoveLiquidity: (account, symbol, rm unit, swap unit, rm fis amount, rm rToken amount, input is fis, fis balance, rtoken balance)
         RemoveLiquidity(AccountId, RSymbol, u128, u128, u128, u128, bool, u128, u128),
+        /// SwapViaFis: (account, input symbol, output symbol, input amount, fis amount, output amount, input pool fee, output pool fee)
+        SwapViaFis(AccountId, RSymbol, RSymbol, u128, u128, u128, u128, u128),
+        /// SwapFee: (symbol, fee is fis, fee amount, lp fee, protocol fee)
+        SwapFee(RSymbol, bool, u128, u128, u128),
+        /// FeeModelSet: (symbol, fee model)
+        FeeModelSet(RSymbol, FeeModel),
+        /// ProtocolFeeShareSet: (old share, new share)
+        ProtocolFeeShareSet(Perbill, Perbill),
+        /// ProtocolFeeReceiverSet: (receiver)
+        ProtocolFeeReceiverSet(AccountId),
     }
 }
 
@@ -69,6 +100,11 @@ decl_error! {
         AddLpUnitIsZero,
         PoolOneSideZero,
         AddLpNotAllowed,
+        SameSymbol,
+        LessThanMinAddUnit,
+        Expired,
+        InvalidFeeModel,
+        NoProtocolFeeReceiver,
     }
 }
 
@@ -80,6 +116,12 @@ decl_storage! {
         pub LpWhitelist get(fn lp_whitelist): map hasher(blake2_128_concat) (RSymbol, T::AccountId) => bool = false;
         /// lp switch
         pub LpSwitch get(fn lp_switch): map hasher(blake2_128_concat)  RSymbol => bool = false;
+        /// share of the swap fee paid to the protocol
+        pub ProtocolFeeShare get(fn protocol_fee_share): Perbill;
+        /// receiver of the protocol fee, eg: treasury
+        pub ProtocolFeeReceiver get(fn protocol_fee_receiver): Option<T::AccountId>;
+        /// storage version, genesis pools need no migration
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig| Releases::V2_0_0): Releases;
     }
 }
 
@@ -87,51 +129,58 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get() == Releases::V2_0_0 {
+                return 0;
+            }
+
+            SwapPools::translate::<SwapPoolV1, _>(|_, old| Some(old.into()));
+            StorageVersion::put(Releases::V2_0_0);
+            let count = SwapPools::iter().count() as Weight;
+            T::DbWeight::get().reads_writes(count + 1, count + 1)
+        }
+
         /// swap
-        #[weight = 10_000_000_000]
-        pub fn swap(origin, symbol: RSymbol, input_amount: u128, min_out_amount: u128, input_is_fis: bool) -> DispatchResult {
+        #[weight = T::WeightInfo::swap()]
+        pub fn swap(origin, symbol: RSymbol, input_amount: u128, min_out_amount: u128, input_is_fis: bool, deadline: Option<T::BlockNumber>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let mut pool = Self::swap_pools(symbol).ok_or(Error::<T>::PoolNotExist)?;
+            Self::ensure_not_expired(deadline)?;
+            Self::do_swap(&who, symbol, input_amount, min_out_amount, input_is_fis)?;
+            Ok(())
+        }
+
+        /// swap rToken for another rToken, through the fis side of both pools
+        #[weight = T::WeightInfo::swap_via_fis()]
+        pub fn swap_via_fis(origin, input_symbol: RSymbol, output_symbol: RSymbol, input_amount: u128, min_out_amount: u128, deadline: Option<T::BlockNumber>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_expired(deadline)?;
+            ensure!(input_symbol != output_symbol, Error::<T>::SameSymbol);
             ensure!(input_amount > 0 && min_out_amount > 0, Error::<T>::AmountZero);
+            let old_input_pool = Self::swap_pools(input_symbol).ok_or(Error::<T>::PoolNotExist)?;
+            let old_output_pool = Self::swap_pools(output_symbol).ok_or(Error::<T>::PoolNotExist)?;
 
-            let (result, fee) = Self::cal_swap_result(pool.fis_balance, pool.rtoken_balance, input_amount, input_is_fis);
-            ensure!(result > 0, Error::<T>::SwapAmountTooFew);
+            let (input_pool, fis_amount, input_fee, input_protocol_fee) = Self::cal_pool_after_swap(&old_input_pool, input_amount, false)?;
+            let (output_pool, result, output_fee, output_protocol_fee) = Self::cal_pool_after_swap(&old_output_pool, fis_amount, true)?;
             ensure!(result >= min_out_amount, Error::<T>::LessThanMinOutAmount);
+            ensure!(T::RCurrency::free_balance(&who, input_symbol) >= input_amount, Error::<T>::UserRTokenAmountNotEnough);
 
-            if input_is_fis {
-                ensure!(T::Currency::free_balance(&who).saturated_into::<u128>() > input_amount, Error::<T>::UserFisAmountNotEnough);
-                ensure!(result < pool.rtoken_balance, Error::<T>::PoolRTokenBalanceNotEnough);
-
-                // transfer
-                T::Currency::transfer(&who, &Self::account_id(), input_amount.saturated_into(), KeepAlive)?;
-                T::RCurrency::transfer(&Self::account_id(), &who, symbol, result)?;
-
-                // update pool
-                pool.fis_balance = pool.fis_balance.saturating_add(input_amount);
-                pool.rtoken_balance = pool.rtoken_balance.saturating_sub(result);
-            } else {
-                ensure!(T::RCurrency::free_balance(&who, symbol) >= input_amount, Error::<T>::UserRTokenAmountNotEnough);
-                ensure!(result < pool.fis_balance, Error::<T>::PoolFisBalanceNotEnough);
-
-                // transfer
-                T::Currency::transfer(&Self::account_id(), &who, result.saturated_into(), KeepAlive)?;
-                T::RCurrency::transfer(&who, &Self::account_id(), symbol, input_amount)?;
+            // fis stays in the module account, only moving between pools
+            T::RCurrency::transfer(&who, &Self::account_id(), input_symbol, input_amount)?;
+            T::RCurrency::transfer(&Self::account_id(), &who, output_symbol, result)?;
+            Self::pay_protocol_fee(input_symbol, input_fee, input_protocol_fee, true)?;
+            Self::pay_protocol_fee(output_symbol, output_fee, output_protocol_fee, false)?;
 
-                // update pool
-                pool.rtoken_balance = pool.rtoken_balance.saturating_add(input_amount);
-                pool.fis_balance = pool.fis_balance.saturating_sub(result);
-            }
-
-            // update pool storage
-            <SwapPools>::insert(symbol, pool.clone());
-            Self::deposit_event(RawEvent::Swap(who, symbol, input_amount, result, fee, input_is_fis, pool.fis_balance, pool.rtoken_balance));
+            <SwapPools>::insert(input_symbol, input_pool);
+            <SwapPools>::insert(output_symbol, output_pool);
+            Self::deposit_event(RawEvent::SwapViaFis(who, input_symbol, output_symbol, input_amount, fis_amount, result, input_fee, output_fee));
             Ok(())
         }
 
         /// add liquidity
-        #[weight = 10_000_000_000]
-        pub fn add_liquidity(origin, symbol: RSymbol, rtoken_amount: u128, fis_amount: u128) -> DispatchResult {
+        #[weight = T::WeightInfo::add_liquidity()]
+        pub fn add_liquidity(origin, symbol: RSymbol, rtoken_amount: u128, fis_amount: u128, min_add_unit: u128, deadline: Option<T::BlockNumber>) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_expired(deadline)?;
             let mut pool = Self::swap_pools(symbol).ok_or(Error::<T>::PoolNotExist)?;
 
             ensure!(Self::lp_whitelist((symbol, who.clone())) || Self::lp_switch(symbol), Error::<T>::AddLpNotAllowed);
@@ -141,6 +190,7 @@ decl_module! {
 
             let (new_total_pool_unit, add_lp_unit) = Self::cal_pool_unit(pool.total_unit, pool.fis_balance, pool.rtoken_balance, fis_amount, rtoken_amount);
             ensure!(add_lp_unit > 0, Error::<T>::AddLpUnitIsZero);
+            ensure!(add_lp_unit >= min_add_unit, Error::<T>::LessThanMinAddUnit);
 
             // transfer token to module account
             if fis_amount > 0 {
@@ -163,43 +213,18 @@ decl_module! {
         }
 
         /// remove liquidity
-        #[weight = 10_000_000_000]
-        pub fn remove_liquidity(origin, symbol: RSymbol, rm_unit: u128, swap_unit: u128, min_fis_out_amount: u128, min_rtoken_out_amount: u128, input_is_fis: bool) -> DispatchResult {
+        #[weight = T::WeightInfo::remove_liquidity()]
+        pub fn remove_liquidity(origin, symbol: RSymbol, rm_unit: u128, swap_unit: u128, min_fis_out_amount: u128, min_rtoken_out_amount: u128, input_is_fis: bool, deadline: Option<T::BlockNumber>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let mut pool = Self::swap_pools(symbol).ok_or(Error::<T>::PoolNotExist)?;
+            Self::ensure_not_expired(deadline)?;
+            let old_pool = Self::swap_pools(symbol).ok_or(Error::<T>::PoolNotExist)?;
             let lp_unit = T::LpCurrency::free_balance(&who, symbol);
             let pool_fis_balance = T::Currency::free_balance(&Self::account_id()).saturated_into::<u128>();
             let pool_rtoken_balance = T::RCurrency::free_balance(&Self::account_id(), symbol);
 
             ensure!(rm_unit > 0 && rm_unit <= lp_unit && rm_unit >= swap_unit, Error::<T>::UnitAmountImproper);
 
-            let (mut rm_fis_amount, mut rm_rtoken_amount, swap_input_amount) = Self::cal_remove_result(pool.total_unit, rm_unit, swap_unit, pool.fis_balance, pool.rtoken_balance, input_is_fis);
-            //update pool/lp
-            pool.total_unit = pool.total_unit.saturating_sub(rm_unit);
-            pool.fis_balance =  pool.fis_balance.saturating_sub(rm_fis_amount);
-            pool.rtoken_balance = pool.rtoken_balance.saturating_sub(rm_rtoken_amount);
-            if swap_input_amount > 0 {
-                let (swap_result, _) = Self::cal_swap_result(pool.fis_balance, pool.rtoken_balance, swap_input_amount, input_is_fis);
-                ensure!(swap_result > 0, Error::<T>::SwapAmountTooFew);
-
-                if input_is_fis {
-                    ensure!(swap_result < pool.rtoken_balance, Error::<T>::PoolRTokenBalanceNotEnough);
-
-                    pool.fis_balance = pool.fis_balance.saturating_add(swap_input_amount);
-                    pool.rtoken_balance = pool.rtoken_balance.saturating_sub(swap_result);
-
-                    rm_fis_amount = rm_fis_amount.saturating_sub(swap_input_amount);
-                    rm_rtoken_amount = rm_rtoken_amount.saturating_add(swap_result);
-                } else {
-                    ensure!(swap_result < pool.fis_balance, Error::<T>::PoolFisBalanceNotEnough);
-
-                    pool.rtoken_balance = pool.rtoken_balance.saturating_add(swap_input_amount);
-                    pool.fis_balance = pool.fis_balance.saturating_sub(swap_result);
-
-                    rm_rtoken_amount = rm_rtoken_amount.saturating_sub(swap_input_amount);
-                    rm_fis_amount = rm_fis_amount.saturating_add(swap_result);
-                }
-            }
+            let (pool, rm_fis_amount, rm_rtoken_amount, fee, protocol_fee) = Self::cal_pool_after_remove(&old_pool, rm_unit, swap_unit, input_is_fis)?;
 
             ensure!(rm_fis_amount >= min_fis_out_amount && rm_rtoken_amount >= min_rtoken_out_amount, Error::<T>::LessThanMinOutAmount);
             ensure!(!((pool.fis_balance == 0 && pool.rtoken_balance != 0) || (pool.fis_balance != 0 && pool.rtoken_balance == 0)), Error::<T>::PoolOneSideZero);
@@ -213,6 +238,7 @@ decl_module! {
             if rm_rtoken_amount > 0 {
                 T::RCurrency::transfer(&Self::account_id(), &who, symbol, rm_rtoken_amount)?;
             }
+            Self::pay_protocol_fee(symbol, fee, protocol_fee, !input_is_fis)?;
             // burn unit
             T::LpCurrency::burn(&who, symbol, rm_unit)?;
             // update pool
@@ -222,7 +248,7 @@ decl_module! {
         }
 
         /// create pool
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::create_pool()]
         pub fn create_pool(origin, who: T::AccountId, symbol: RSymbol, rtoken_amount: u128, fis_amount: u128) -> DispatchResult {
             ensure_root(origin.clone())?;
             ensure!(Self::swap_pools(symbol).is_none(), Error::<T>::PoolAlreadyExist);
@@ -237,6 +263,7 @@ decl_module! {
                 fis_balance: fis_amount,
                 rtoken_balance: rtoken_amount,
                 total_unit: pool_unit,
+                fee_model: FeeModel::Slip,
             };
 
             // transfer token to module account
@@ -251,21 +278,53 @@ decl_module! {
         }
 
         /// add lp to whitelist
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::add_lp_to_whitelist()]
         pub fn add_lp_to_whitelist(origin, symbol: RSymbol, who: T::AccountId) -> DispatchResult {
             ensure_root(origin.clone())?;
             <LpWhitelist<T>>::insert((symbol, who), true);
             Ok(())
         }
         /// remove lp from whitelist
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::remove_lp_from_whitelist()]
         pub fn remove_lp_from_whitelist(origin, symbol: RSymbol, who: T::AccountId) -> DispatchResult {
             ensure_root(origin.clone())?;
             <LpWhitelist<T>>::remove((symbol, who));
             Ok(())
         }
+        /// set fee model of a pool
+        #[weight = T::WeightInfo::set_fee_model()]
+        pub fn set_fee_model(origin, symbol: RSymbol, fee_model: FeeModel) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(fee_model.is_valid(), Error::<T>::InvalidFeeModel);
+            let mut pool = Self::swap_pools(symbol).ok_or(Error::<T>::PoolNotExist)?;
+            pool.fee_model = fee_model;
+            <SwapPools>::insert(symbol, pool);
+            Self::deposit_event(RawEvent::FeeModelSet(symbol, fee_model));
+            Ok(())
+        }
+
+        /// set share of the swap fee paid to the protocol
+        #[weight = T::WeightInfo::set_protocol_fee_share()]
+        pub fn set_protocol_fee_share(origin, new_share: Perbill) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(new_share == Perbill::from_parts(0) || Self::protocol_fee_receiver().is_some(), Error::<T>::NoProtocolFeeReceiver);
+            let old_share = Self::protocol_fee_share();
+            ProtocolFeeShare::put(new_share);
+            Self::deposit_event(RawEvent::ProtocolFeeShareSet(old_share, new_share));
+            Ok(())
+        }
+
+        /// set receiver of the protocol fee
+        #[weight = T::WeightInfo::set_protocol_fee_receiver()]
+        pub fn set_protocol_fee_receiver(origin, new_receiver: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            <ProtocolFeeReceiver<T>>::put(&new_receiver);
+            Self::deposit_event(RawEvent::ProtocolFeeReceiverSet(new_receiver));
+            Ok(())
+        }
+
         /// turn on/off lp switch, default closed
-        #[weight = 100_000]
+        #[weight = T::WeightInfo::toggle_lp_switch()]
         fn toggle_lp_switch(origin, symbol: RSymbol) -> DispatchResult {
             ensure_root(origin)?;
             let state = Self::lp_switch(symbol);
@@ -281,6 +340,148 @@ impl<T: Trait> Module<T> {
         MODULE_ID.into_account()
     }
 
+    /// swap for `who`, also used by pallets which fill from the pool: output amount
+    pub fn do_swap(who: &T::AccountId, symbol: RSymbol, input_amount: u128, min_out_amount: u128, input_is_fis: bool) -> Result<u128, DispatchError> {
+        let old_pool = Self::swap_pools(symbol).ok_or(Error::<T>::PoolNotExist)?;
+        ensure!(input_amount > 0 && min_out_amount > 0, Error::<T>::AmountZero);
+
+        let (pool, result, fee, protocol_fee) = Self::cal_pool_after_swap(&old_pool, input_amount, input_is_fis)?;
+        ensure!(result >= min_out_amount, Error::<T>::LessThanMinOutAmount);
+
+        if input_is_fis {
+            ensure!(T::Currency::free_balance(who).saturated_into::<u128>() > input_amount, Error::<T>::UserFisAmountNotEnough);
+
+            // transfer
+            T::Currency::transfer(who, &Self::account_id(), input_amount.saturated_into(), KeepAlive)?;
+            T::RCurrency::transfer(&Self::account_id(), who, symbol, result)?;
+        } else {
+            ensure!(T::RCurrency::free_balance(who, symbol) >= input_amount, Error::<T>::UserRTokenAmountNotEnough);
+
+            // transfer
+            T::Currency::transfer(&Self::account_id(), who, result.saturated_into(), KeepAlive)?;
+            T::RCurrency::transfer(who, &Self::account_id(), symbol, input_amount)?;
+        }
+        Self::pay_protocol_fee(symbol, fee, protocol_fee, !input_is_fis)?;
+
+        // update pool storage
+        <SwapPools>::insert(symbol, pool.clone());
+        Self::deposit_event(RawEvent::Swap(who.clone(), symbol, input_amount, result, fee, input_is_fis, pool.fis_balance, pool.rtoken_balance));
+        Ok(result)
+    }
+
+    /// calls with a deadline are rejected once the block number is past it
+    fn ensure_not_expired(deadline: Option<T::BlockNumber>) -> DispatchResult {
+        if let Some(deadline) = deadline {
+            ensure!(system::Module::<T>::block_number() <= deadline, Error::<T>::Expired);
+        }
+        Ok(())
+    }
+
+    /// pool after a swap, with the output, fee and protocol fee amount
+    pub fn cal_pool_after_swap(pool: &SwapPool, input_amount: u128, input_is_fis: bool) -> Result<(SwapPool, u128, u128, u128), Error<T>> {
+        let (result, fee) = Self::cal_swap_result_by_model(pool.fee_model, pool.fis_balance, pool.rtoken_balance, input_amount, input_is_fis);
+        ensure!(result > 0, Error::<T>::SwapAmountTooFew);
+        let protocol_fee = Self::cal_protocol_fee(fee);
+        let out_amount = result.saturating_add(protocol_fee);
+
+        let mut new_pool = pool.clone();
+        if input_is_fis {
+            ensure!(out_amount < pool.rtoken_balance, Error::<T>::PoolRTokenBalanceNotEnough);
+            new_pool.fis_balance = pool.fis_balance.saturating_add(input_amount);
+            new_pool.rtoken_balance = pool.rtoken_balance.saturating_sub(out_amount);
+        } else {
+            ensure!(out_amount < pool.fis_balance, Error::<T>::PoolFisBalanceNotEnough);
+            new_pool.rtoken_balance = pool.rtoken_balance.saturating_add(input_amount);
+            new_pool.fis_balance = pool.fis_balance.saturating_sub(out_amount);
+        }
+
+        Ok((new_pool, result, fee, protocol_fee))
+    }
+
+    /// pool after removing `rm_unit`, of which `swap_unit` is swapped to the other side,
+    /// with the fis and rToken amount removed and the fee and protocol fee of the swap
+    pub fn cal_pool_after_remove(pool: &SwapPool, rm_unit: u128, swap_unit: u128, input_is_fis: bool) -> Result<(SwapPool, u128, u128, u128, u128), Error<T>> {
+        let (mut rm_fis_amount, mut rm_rtoken_amount, swap_input_amount) = Self::cal_remove_result(pool.total_unit, rm_unit, swap_unit, pool.fis_balance, pool.rtoken_balance, input_is_fis);
+        let mut new_pool = pool.clone();
+        new_pool.total_unit = pool.total_unit.saturating_sub(rm_unit);
+        new_pool.fis_balance = pool.fis_balance.saturating_sub(rm_fis_amount);
+        new_pool.rtoken_balance = pool.rtoken_balance.saturating_sub(rm_rtoken_amount);
+        let mut fee = 0;
+        let mut protocol_fee = 0;
+        if swap_input_amount > 0 {
+            let (swapped_pool, swap_result, swap_fee, swap_protocol_fee) = Self::cal_pool_after_swap(&new_pool, swap_input_amount, input_is_fis)?;
+            new_pool = swapped_pool;
+            fee = swap_fee;
+            protocol_fee = swap_protocol_fee;
+            if input_is_fis {
+                rm_fis_amount = rm_fis_amount.saturating_sub(swap_input_amount);
+                rm_rtoken_amount = rm_rtoken_amount.saturating_add(swap_result);
+            } else {
+                rm_rtoken_amount = rm_rtoken_amount.saturating_sub(swap_input_amount);
+                rm_fis_amount = rm_fis_amount.saturating_add(swap_result);
+            }
+        }
+
+        Ok((new_pool, rm_fis_amount, rm_rtoken_amount, fee, protocol_fee))
+    }
+
+    /// protocol share of a swap fee, nothing if no receiver is set
+    pub fn cal_protocol_fee(fee: u128) -> u128 {
+        if Self::protocol_fee_receiver().is_none() {
+            return 0;
+        }
+        Self::protocol_fee_share() * fee
+    }
+
+    /// pay the protocol fee out of the module account and report the fee split
+    fn pay_protocol_fee(symbol: RSymbol, fee: u128, protocol_fee: u128, fee_is_fis: bool) -> DispatchResult {
+        if protocol_fee > 0 {
+            let receiver = Self::protocol_fee_receiver().ok_or(Error::<T>::NoProtocolFeeReceiver)?;
+            if fee_is_fis {
+                T::Currency::transfer(&Self::account_id(), &receiver, protocol_fee.saturated_into(), KeepAlive)?;
+            } else {
+                T::RCurrency::transfer(&Self::account_id(), &receiver, symbol, protocol_fee)?;
+            }
+        }
+        if fee > 0 {
+            Self::deposit_event(RawEvent::SwapFee(symbol, fee_is_fis, fee, fee.saturating_sub(protocol_fee), protocol_fee));
+        }
+        Ok(())
+    }
+
+    /// quote a swap against the live pool: (output amount, fee amount)
+    pub fn quote_swap(symbol: RSymbol, input_amount: u128, input_is_fis: bool) -> Option<(u128, u128)> {
+        let pool = Self::swap_pools(symbol)?;
+        Self::cal_pool_after_swap(&pool, input_amount, input_is_fis).ok()
+            .map(|(_, result, fee, _)| (result, fee))
+    }
+
+    /// quote adding liquidity against the live pool: (new total unit, add lp unit)
+    pub fn quote_add_liquidity(symbol: RSymbol, rtoken_amount: u128, fis_amount: u128) -> Option<(u128, u128)> {
+        let pool = Self::swap_pools(symbol)?;
+        Some(Self::cal_pool_unit(pool.total_unit, pool.fis_balance, pool.rtoken_balance, fis_amount, rtoken_amount))
+    }
+
+    /// quote removing liquidity against the live pool: (fis amount, rToken amount)
+    pub fn quote_remove_liquidity(symbol: RSymbol, rm_unit: u128, swap_unit: u128, input_is_fis: bool) -> Option<(u128, u128)> {
+        let pool = Self::swap_pools(symbol)?;
+        Self::cal_pool_after_remove(&pool, rm_unit, swap_unit, input_is_fis).ok()
+            .map(|(_, rm_fis_amount, rm_rtoken_amount, _, _)| (rm_fis_amount, rm_rtoken_amount))
+    }
+
+    /// quote a swap of rToken to another rToken through fis: (fis amount, output amount, input pool fee, output pool fee)
+    pub fn quote_swap_via_fis(input_symbol: RSymbol, output_symbol: RSymbol, input_amount: u128) -> Option<(u128, u128, u128, u128)> {
+        if input_symbol == output_symbol {
+            return None;
+        }
+        let input_pool = Self::swap_pools(input_symbol)?;
+        let output_pool = Self::swap_pools(output_symbol)?;
+        let (_, fis_amount, input_fee, _) = Self::cal_pool_after_swap(&input_pool, input_amount, false).ok()?;
+        let (_, result, output_fee, _) = Self::cal_pool_after_swap(&output_pool, fis_amount, true).ok()?;
+
+        Some((fis_amount, result, input_fee, output_fee))
+    }
+
     // F = fis Balance (before)
     // R = rToken Balance (before)
     // f = fis added;
@@ -392,6 +593,63 @@ impl<T: Trait> Module<T> {
         (Self::safe_to_u128(y), Self::safe_to_u128(fee))
     }
 
+    pub fn cal_swap_result_by_model(
+        fee_model: FeeModel,
+        fis_balance: u128,
+        rtoken_balance: u128,
+        input_amount: u128,
+        input_is_fis: bool,
+    ) -> (u128, u128) {
+        match fee_model {
+            FeeModel::Slip => Self::cal_swap_result(fis_balance, rtoken_balance, input_amount, input_is_fis),
+            FeeModel::ConstantProduct(fee_bps) => Self::cal_constant_product_result(fis_balance, rtoken_balance, input_amount, input_is_fis, fee_bps),
+            FeeModel::Hybrid(fee_bps) => {
+                let (result, fee) = Self::cal_swap_result(fis_balance, rtoken_balance, input_amount, input_is_fis);
+                let bps_fee = Self::safe_to_u128(
+                    U512::from(result)
+                        .saturating_mul(U512::from(fee_bps))
+                        .checked_div(U512::from(FEE_BPS_BASE))
+                        .unwrap_or(U512::zero()),
+                );
+                (result.saturating_sub(bps_fee), fee.saturating_add(bps_fee))
+            }
+        }
+    }
+
+    // b = fee bps, B = bps base
+    // y = (x * (B - b) * Y) / (X * B + x * (B - b))
+    // fee = (x * Y) / (x + X) - y
+    pub fn cal_constant_product_result(
+        fis_balance: u128,
+        rtoken_balance: u128,
+        input_amount: u128,
+        input_is_fis: bool,
+        fee_bps: u32,
+    ) -> (u128, u128) {
+        if fis_balance == 0 || rtoken_balance == 0 || input_amount == 0 || fee_bps >= FEE_BPS_BASE {
+            return (0, 0);
+        }
+        let x = U512::from(input_amount);
+        let mut x_capital = U512::from(rtoken_balance);
+        let mut y_capital = U512::from(fis_balance);
+        if input_is_fis {
+            x_capital = U512::from(fis_balance);
+            y_capital = U512::from(rtoken_balance);
+        }
+        let base = U512::from(FEE_BPS_BASE);
+        let x_with_fee = x.saturating_mul(base.saturating_sub(U512::from(fee_bps)));
+        let y = x_with_fee
+            .saturating_mul(y_capital)
+            .checked_div(x_capital.saturating_mul(base).saturating_add(x_with_fee))
+            .unwrap_or(U512::zero());
+        let y_without_fee = x
+            .saturating_mul(y_capital)
+            .checked_div(x.saturating_add(x_capital))
+            .unwrap_or(U512::zero());
+
+        (Self::safe_to_u128(y), Self::safe_to_u128(y_without_fee.saturating_sub(y)))
+    }
+
     pub fn cal_remove_result(
         pool_unit: u128,
         rm_unit: u128,
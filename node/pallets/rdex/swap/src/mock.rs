@@ -80,6 +80,7 @@ impl Trait for Test {
     type Currency = Balances;
     type RCurrency = RBalances;
     type LpCurrency = LpBalances;
+    type WeightInfo = ();
 }
 
 impl pallet_balances::Trait for Test {
@@ -96,6 +97,12 @@ impl rdex_balances::Trait for Test {
     type Event = ();
 }
 
+impl rtoken_registry::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
 impl rtoken_balances::Trait for Test {
     type Event = ();
 }
@@ -111,6 +118,12 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut t)
     .unwrap();
 
+    rtoken_registry::GenesisConfig {
+        assets: rtoken_registry::builtin_assets(),
+    }
+    .assimilate_storage::<Test>(&mut t)
+    .unwrap();
+
     t.into()
 }
 
@@ -3,6 +3,35 @@ use node_primitives::RSymbol;
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
+/// fee bps base
+pub const FEE_BPS_BASE: u32 = 10_000;
+
+/// How a pool charges the swap fee, which stays with lp except the protocol share
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum FeeModel {
+    /// slip fee x^2*Y/(x+X)^2, growing with the trade size
+    Slip,
+    /// constant product with a fixed fee in bps of the input
+    ConstantProduct(u32),
+    /// slip fee plus a fixed fee in bps of the output
+    Hybrid(u32),
+}
+
+impl Default for FeeModel {
+    fn default() -> Self {
+        FeeModel::Slip
+    }
+}
+
+impl FeeModel {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            FeeModel::Slip => true,
+            FeeModel::ConstantProduct(bps) | FeeModel::Hybrid(bps) => *bps < FEE_BPS_BASE,
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 pub struct SwapPool {
     /// rToken symbol
@@ -13,5 +42,41 @@ pub struct SwapPool {
     pub rtoken_balance: u128,
     /// total lp unit
     pub total_unit: u128,
+    /// fee model
+    pub fee_model: FeeModel,
+}
+
+/// swap pool before fee models, kept for the storage migration
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct SwapPoolV1 {
+    pub symbol: RSymbol,
+    pub fis_balance: u128,
+    pub rtoken_balance: u128,
+    pub total_unit: u128,
+}
+
+impl From<SwapPoolV1> for SwapPool {
+    fn from(old: SwapPoolV1) -> Self {
+        Self {
+            symbol: old.symbol,
+            fis_balance: old.fis_balance,
+            rtoken_balance: old.rtoken_balance,
+            total_unit: old.total_unit,
+            fee_model: FeeModel::Slip,
+        }
+    }
+}
+
+/// storage releases of this pallet
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum Releases {
+    V1_0_0,
+    V2_0_0,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
 }
 
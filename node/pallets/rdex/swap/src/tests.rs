@@ -1,6 +1,6 @@
 use super::mock::*;
 use super::*;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, storage::unhashed, traits::OnRuntimeUpgrade};
 use node_primitives::RSymbol;
 pub struct CalPoolUnitData {
     pub old_pool_unit: u128,
@@ -180,6 +180,29 @@ fn cal_swap_result_should_work() {
     });
 }
 
+#[test]
+fn cal_swap_result_by_model_should_work() {
+    new_test_ext().execute_with(|| {
+        let (fis_balance, rtoken_balance, input_amount) = (1000000, 10000000, 100000);
+        assert_eq!(
+            RDexSwap::cal_swap_result_by_model(FeeModel::Slip, fis_balance, rtoken_balance, input_amount, true),
+            RDexSwap::cal_swap_result(fis_balance, rtoken_balance, input_amount, true)
+        );
+        assert_eq!(
+            RDexSwap::cal_swap_result_by_model(FeeModel::ConstantProduct(30), fis_balance, rtoken_balance, input_amount, true),
+            (906610, 2480)
+        );
+        assert_eq!(
+            RDexSwap::cal_swap_result_by_model(FeeModel::Hybrid(30), fis_balance, rtoken_balance, input_amount, true),
+            (823967, 85123)
+        );
+        assert_eq!(RDexSwap::cal_constant_product_result(100, 1000, 10, true, 0), (90, 0));
+        assert_eq!(RDexSwap::cal_constant_product_result(1000, 100, 10, false, 30), (90, 0));
+        assert_eq!(RDexSwap::cal_constant_product_result(1000, 100, 10, false, 10000), (0, 0));
+        assert_eq!(RDexSwap::cal_constant_product_result(0, 100, 10, false, 30), (0, 0));
+    });
+}
+
 pub struct CalRemoveResultData {
     pub pool_unit: u128,
     pub rm_unit: u128,
@@ -352,7 +375,7 @@ fn add_liquidity_should_work() {
 
         // add liquidity
         assert_ok!(RBalances::mint(&(1 as u64), symbol, 100));
-        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10));
+        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10, 0, None));
 
         let pool = RDexSwap::swap_pools(symbol).unwrap();
         assert_eq!(RBalances::free_balance(&(1 as u64), symbol), 80);
@@ -392,7 +415,7 @@ fn rm_liquidity_should_work() {
 
         // add liquidity
         assert_ok!(RBalances::mint(&(1 as u64), symbol, 100));
-        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10));
+        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10, 0, None));
 
         let pool = RDexSwap::swap_pools(symbol).unwrap();
         assert_eq!(RBalances::free_balance(&(1 as u64), symbol), 80);
@@ -411,7 +434,8 @@ fn rm_liquidity_should_work() {
             0,
             0,
             0,
-            true
+            true,
+            None
         ));
         let pool = RDexSwap::swap_pools(symbol).unwrap();
         assert_eq!(RBalances::free_balance(&(1 as u64), symbol), 82);
@@ -452,7 +476,7 @@ fn swap_should_work() {
 
         // add liquidity
         assert_ok!(RBalances::mint(&(1 as u64), symbol, 1000));
-        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 980, 90));
+        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 980, 90, 0, None));
 
         let pool = RDexSwap::swap_pools(symbol).unwrap();
         assert_eq!(RBalances::free_balance(&(1 as u64), symbol), 20);
@@ -467,7 +491,7 @@ fn swap_should_work() {
         // assert_eq!(pool.total_unit, 20);
 
         // swap
-        assert_ok!(RDexSwap::swap(Origin::signed(2), symbol, 10, 82, true));
+        assert_ok!(RDexSwap::swap(Origin::signed(2), symbol, 10, 82, true, None));
         assert_eq!(RBalances::free_balance(&(2 as u64), symbol), 82);
     });
 }
@@ -498,7 +522,7 @@ fn swap_should_fail() {
 
         // add liquidity
         assert_ok!(RBalances::mint(&(1 as u64), symbol, 1000));
-        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 980, 90));
+        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 980, 90, 0, None));
 
         let pool = RDexSwap::swap_pools(symbol).unwrap();
         assert_eq!(RBalances::free_balance(&(1 as u64), symbol), 20);
@@ -512,26 +536,245 @@ fn swap_should_fail() {
 
         // swap
         assert_err!(
-            RDexSwap::swap(Origin::signed(2), RSymbol::RETH, 10, 83, true),
+            RDexSwap::swap(Origin::signed(2), RSymbol::RETH, 10, 83, true, None),
             Error::<Test>::PoolNotExist
         );
         assert_err!(
-            RDexSwap::swap(Origin::signed(2), symbol, 0, 83, true),
+            RDexSwap::swap(Origin::signed(2), symbol, 0, 83, true, None),
             Error::<Test>::AmountZero
         );
         assert_err!(
-            RDexSwap::swap(Origin::signed(2), symbol, 10, 0, true),
+            RDexSwap::swap(Origin::signed(2), symbol, 10, 0, true, None),
             Error::<Test>::AmountZero
         );
 
         assert_err!(
-            RDexSwap::swap(Origin::signed(2), symbol, 10, 83, true),
+            RDexSwap::swap(Origin::signed(2), symbol, 10, 83, true, None),
             Error::<Test>::LessThanMinOutAmount
         );
 
         assert_err!(
-            RDexSwap::swap(Origin::signed(2), symbol, 200, 83, true),
+            RDexSwap::swap(Origin::signed(2), symbol, 200, 83, true, None),
             Error::<Test>::UserFisAmountNotEnough
         );
     });
 }
+
+// swap_via_fis(origin, input_symbol: RSymbol, output_symbol: RSymbol, input_amount: u128, min_out_amount: u128)
+#[test]
+fn swap_via_fis_should_work() {
+    new_test_ext().execute_with(|| {
+        let input_symbol = RSymbol::RATOM;
+        let output_symbol = RSymbol::RDOT;
+
+        // create pools
+        assert_ok!(RBalances::mint(&(42 as u64), input_symbol, 1000));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            42 as u64,
+            input_symbol,
+            1000,
+            40
+        ));
+        assert_ok!(RBalances::mint(&(1 as u64), output_symbol, 500));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            1 as u64,
+            output_symbol,
+            500,
+            40
+        ));
+        assert_eq!(Balances::free_balance(RDexSwap::account_id()), 80);
+
+        // quote
+        assert_eq!(RDexSwap::quote_swap(input_symbol, 100, false), Some((3, 0)));
+        assert_eq!(RDexSwap::quote_swap(output_symbol, 3, true), Some((32, 2)));
+        assert_eq!(
+            RDexSwap::quote_swap_via_fis(input_symbol, output_symbol, 100),
+            Some((3, 32, 0, 2))
+        );
+        assert_eq!(RDexSwap::quote_swap_via_fis(input_symbol, input_symbol, 100), None);
+        assert_eq!(RDexSwap::quote_swap_via_fis(input_symbol, RSymbol::RETH, 100), None);
+
+        // swap
+        assert_ok!(RBalances::mint(&(2 as u64), input_symbol, 100));
+        assert_err!(
+            RDexSwap::swap_via_fis(Origin::signed(2), input_symbol, input_symbol, 100, 32, None),
+            Error::<Test>::SameSymbol
+        );
+        assert_err!(
+            RDexSwap::swap_via_fis(Origin::signed(2), input_symbol, RSymbol::RETH, 100, 32, None),
+            Error::<Test>::PoolNotExist
+        );
+        assert_err!(
+            RDexSwap::swap_via_fis(Origin::signed(2), input_symbol, output_symbol, 100, 33, None),
+            Error::<Test>::LessThanMinOutAmount
+        );
+        assert_err!(
+            RDexSwap::swap_via_fis(Origin::signed(2), input_symbol, output_symbol, 101, 1, None),
+            Error::<Test>::UserRTokenAmountNotEnough
+        );
+        assert_ok!(RDexSwap::swap_via_fis(Origin::signed(2), input_symbol, output_symbol, 100, 32, None));
+
+        assert_eq!(RBalances::free_balance(&(2 as u64), input_symbol), 0);
+        assert_eq!(RBalances::free_balance(&(2 as u64), output_symbol), 32);
+        assert_eq!(Balances::free_balance(RDexSwap::account_id()), 80);
+        let input_pool = RDexSwap::swap_pools(input_symbol).unwrap();
+        assert_eq!(input_pool.fis_balance, 37);
+        assert_eq!(input_pool.rtoken_balance, 1100);
+        let output_pool = RDexSwap::swap_pools(output_symbol).unwrap();
+        assert_eq!(output_pool.fis_balance, 43);
+        assert_eq!(output_pool.rtoken_balance, 468);
+    });
+}
+
+#[test]
+fn quote_liquidity_should_work() {
+    new_test_ext().execute_with(|| {
+        let symbol = RSymbol::RATOM;
+        assert_eq!(RDexSwap::quote_add_liquidity(symbol, 20, 10), None);
+
+        assert_ok!(RBalances::mint(&(42 as u64), symbol, 100));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            42 as u64,
+            symbol,
+            20,
+            10
+        ));
+
+        assert_eq!(RDexSwap::quote_add_liquidity(symbol, 20, 10), Some((20, 10)));
+        assert_eq!(RDexSwap::quote_remove_liquidity(symbol, 1, 0, true), Some((1, 2)));
+
+        assert_ok!(RDexSwap::remove_liquidity(
+            Origin::signed(42),
+            symbol,
+            1,
+            0,
+            0,
+            0,
+            true,
+            None
+        ));
+        assert_eq!(RBalances::free_balance(&(42 as u64), symbol), 82);
+    });
+}
+
+#[test]
+fn deadline_and_min_add_unit_should_work() {
+    new_test_ext().execute_with(|| {
+        let symbol = RSymbol::RATOM;
+        System::set_block_number(10);
+
+        // create pool
+        assert_ok!(RBalances::mint(&(42 as u64), symbol, 100));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            42 as u64,
+            symbol,
+            20,
+            10
+        ));
+        assert_ok!(RBalances::mint(&(1 as u64), symbol, 1000));
+        assert_ok!(RDexSwap::toggle_lp_switch(Origin::root(), symbol));
+
+        // add liquidity
+        assert_err!(
+            RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10, 0, Some(9)),
+            Error::<Test>::Expired
+        );
+        assert_err!(
+            RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10, 11, Some(10)),
+            Error::<Test>::LessThanMinAddUnit
+        );
+        assert_ok!(RDexSwap::add_liquidity(Origin::signed(1), symbol, 20, 10, 10, Some(10)));
+        assert_eq!(LpBalances::free_balance(&(1 as u64), symbol), 10);
+
+        // swap
+        assert_err!(
+            RDexSwap::swap(Origin::signed(1), symbol, 10, 1, false, Some(9)),
+            Error::<Test>::Expired
+        );
+        assert_ok!(RDexSwap::swap(Origin::signed(1), symbol, 10, 1, false, Some(11)));
+
+        // remove liquidity
+        assert_err!(
+            RDexSwap::remove_liquidity(Origin::signed(1), symbol, 1, 0, 0, 0, true, Some(9)),
+            Error::<Test>::Expired
+        );
+        assert_ok!(RDexSwap::remove_liquidity(Origin::signed(1), symbol, 1, 0, 0, 0, true, None));
+    });
+}
+
+#[test]
+fn protocol_fee_should_work() {
+    new_test_ext().execute_with(|| {
+        let symbol = RSymbol::RATOM;
+
+        // create pool
+        assert_ok!(RBalances::mint(&(42 as u64), symbol, 1000));
+        assert_ok!(RDexSwap::create_pool(
+            Origin::root(),
+            42 as u64,
+            symbol,
+            1000,
+            40
+        ));
+        assert_err!(
+            RDexSwap::set_fee_model(Origin::root(), symbol, FeeModel::ConstantProduct(10000)),
+            Error::<Test>::InvalidFeeModel
+        );
+        assert_err!(
+            RDexSwap::set_fee_model(Origin::root(), RSymbol::RETH, FeeModel::Slip),
+            Error::<Test>::PoolNotExist
+        );
+        assert_err!(
+            RDexSwap::set_protocol_fee_share(Origin::root(), Perbill::from_percent(50)),
+            Error::<Test>::NoProtocolFeeReceiver
+        );
+        assert_ok!(RDexSwap::set_protocol_fee_receiver(Origin::root(), 3));
+        assert_ok!(RDexSwap::set_protocol_fee_share(Origin::root(), Perbill::from_percent(50)));
+
+        // swap, fee 40 of which 20 to the protocol
+        assert_eq!(RDexSwap::quote_swap(symbol, 10, true), Some((160, 40)));
+        assert_ok!(RDexSwap::swap(Origin::signed(2), symbol, 10, 160, true, None));
+        assert_eq!(RBalances::free_balance(&(2 as u64), symbol), 160);
+        assert_eq!(RBalances::free_balance(&(3 as u64), symbol), 20);
+        assert_eq!(RBalances::free_balance(&RDexSwap::account_id(), symbol), 820);
+        let pool = RDexSwap::swap_pools(symbol).unwrap();
+        assert_eq!(pool.fis_balance, 50);
+        assert_eq!(pool.rtoken_balance, 820);
+
+        // constant product
+        assert_ok!(RDexSwap::set_fee_model(Origin::root(), symbol, FeeModel::ConstantProduct(30)));
+        assert_eq!(RDexSwap::swap_pools(symbol).unwrap().fee_model, FeeModel::ConstantProduct(30));
+    });
+}
+
+#[test]
+fn genesis_should_need_no_migration() {
+    let t = GenesisConfig::default().build_storage::<Test>().unwrap();
+    sp_io::TestExternalities::new(t).execute_with(|| {
+        assert_eq!(RDexSwap::storage_version(), Releases::V2_0_0);
+    });
+}
+
+#[test]
+fn swap_pools_migration_should_work() {
+    new_test_ext().execute_with(|| {
+        let symbol = RSymbol::RATOM;
+        let old_pool = SwapPoolV1 {
+            symbol,
+            fis_balance: 10,
+            rtoken_balance: 20,
+            total_unit: 10,
+        };
+        unhashed::put(&SwapPools::hashed_key_for(symbol), &old_pool);
+        assert_eq!(RDexSwap::storage_version(), Releases::V1_0_0);
+
+        <RDexSwap as OnRuntimeUpgrade>::on_runtime_upgrade();
+        assert_eq!(RDexSwap::storage_version(), Releases::V2_0_0);
+        assert_eq!(RDexSwap::swap_pools(symbol), Some(old_pool.into()));
+        assert_eq!(RDexSwap::swap_pools(symbol).unwrap().fee_model, FeeModel::Slip);
+    });
+}
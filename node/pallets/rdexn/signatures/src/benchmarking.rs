@@ -0,0 +1,44 @@
+//! Benchmarks for the RDexnSignatures Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use codec::Encode;
+use general_signature::to_ascii_hex;
+use hex_literal::hex;
+
+const SEED: u32 = 0;
+const MAX_SUB_ACCOUNTS: u32 = 16;
+/// solana proposals are signed by ed25519 keys
+const SYMBOL: RSymbol = RSymbol::RSOL;
+/// ed25519 pubkey and its signature of the ascii hex of the encoded `whitelisted_caller`
+const PUBKEY: [u8; 32] = hex!("96dbc2747898cab3fd9b8cde80efc58cec3fe3d35ab37d89821cae9781da1194");
+const SIGNATURE: [u8; 64] = hex!("51443d59119eeab759c102797e0120c2cc399111ac2cb4d1827f1b23fa59fbc114a53a269d18bee48b73f1d2923b1ae4965a3deb66ba154c967fa8ee09ea820d");
+
+benchmarks! {
+    _ { }
+
+    set_payer_pool {
+        let pool = account::<T::AccountId>("pool", 0, SEED).encode();
+    }: _(RawOrigin::Root, SYMBOL, pool.clone())
+    verify {
+        assert_eq!(PayerPools::get(SYMBOL), Some(pool));
+    }
+
+    submit_signatures {
+        let s in 1 .. MAX_SUB_ACCOUNTS;
+        let caller: T::AccountId = whitelisted_caller();
+        payers::Payers::<T>::insert(SYMBOL, &caller, true);
+        let pool = account::<T::AccountId>("pool", 0, SEED).encode();
+        PayerPools::insert(SYMBOL, &pool);
+        ledger::MultiThresholds::insert(SYMBOL, &pool, 1);
+        // the signer is the last sub account, so that all of them are tried
+        let mut sub_accounts: Vec<Vec<u8>> = (1..s).map(|i| [i as u8; 32].to_vec()).collect();
+        sub_accounts.push(PUBKEY.to_vec());
+        ledger::SubAccounts::insert(SYMBOL, &pool, sub_accounts);
+        let proposal_id = to_ascii_hex(&caller.encode());
+    }: _(RawOrigin::Signed(caller), SYMBOL, 1, proposal_id.clone(), SIGNATURE.to_vec())
+    verify {
+        assert_eq!(SignatureSigners::get(SYMBOL, (1, &proposal_id)), vec![PUBKEY.to_vec()]);
+    }
+}
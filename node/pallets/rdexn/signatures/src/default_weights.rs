@@ -0,0 +1,15 @@
+//! Default weights for the RDexnSignatures Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn set_payer_pool() -> Weight {
+        10_000_000 as Weight
+    }
+    fn submit_signatures(s: u32) -> Weight {
+        (10_000_000 as Weight).saturating_add((100_000_000 as Weight).saturating_mul(s as Weight))
+    }
+}
@@ -5,18 +5,33 @@ use sp_std::prelude::*;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchResult}, ensure,
-    traits::{Currency}
+    traits::{Currency},
+    weights::Weight,
 };
 
-use frame_system::{self as system, ensure_signed};
+use frame_system::{self as system, ensure_signed, ensure_root};
 use node_primitives::{RSymbol, ChainType};
 use rdexn_payers as payers;
+use rtoken_registry as registry;
+use rtoken_ledger as ledger;
+use general_signature::{verify_proposal_signature, SigVerifyResult};
 
 
-pub trait Trait: system::Trait + rtoken_rate::Trait + payers::Trait {
+pub trait Trait: system::Trait + rtoken_rate::Trait + payers::Trait + registry::Trait + ledger::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
     /// The currency mechanism.
     type Currency: Currency<Self::AccountId>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
+}
+
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub trait WeightInfo {
+    fn set_payer_pool() -> Weight;
+    fn submit_signatures(s: u32) -> Weight;
 }
 
 decl_event! {
@@ -25,6 +40,10 @@ decl_event! {
     {
         /// submit signatures: account, symbol, block, proposalId, signature
         SubmitSignatures(AccountId, RSymbol, u64, Vec<u8>, Vec<u8>),
+        /// signatures enough: symbol, block, proposalId
+        SignaturesEnough(RSymbol, u64, Vec<u8>),
+        /// multisig pool whose sub accounts sign the proposals of symbol set
+        PayerPoolSet(RSymbol, Vec<u8>),
     }
 }
 
@@ -34,6 +53,8 @@ decl_error! {
         SignatureRepeated,
         /// invalid symbol
         InvalidRSymbol,
+        /// signature not of a sub account of the payer pool
+        InvalidSignature,
     }
 }
 
@@ -41,6 +62,11 @@ decl_storage! {
     trait Store for Module<T: Trait> as RDexnSignatures {
         pub Signatures get(fn signatures): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) (u64, Vec<u8>) => Option<Vec<Vec<u8>>>;
         pub AccountSignature get(fn account_signature): map hasher(blake2_128_concat) (T::AccountId, RSymbol, u64, Vec<u8>) => Option<Vec<u8>>;
+        /// sub accounts of the payer pool which signed, in the order of Signatures
+        pub SignatureSigners get(fn signature_signers): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) (u64, Vec<u8>) => Vec<Vec<u8>>;
+        /// multisig pool whose ledger sub accounts and threshold sign the proposals of a symbol,
+        /// signatures of a symbol without one are kept unverified as before
+        pub PayerPools get(fn payer_pool): map hasher(blake2_128_concat) RSymbol => Option<Vec<u8>>;
     }
 }
 
@@ -48,22 +74,58 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        /// Submit tx signatures
-        #[weight = 10_000_000]
+        /// set the multisig pool whose sub accounts sign the proposals of symbol
+        #[weight = <T as Trait>::WeightInfo::set_payer_pool()]
+        pub fn set_payer_pool(origin, symbol: RSymbol, pool: Vec<u8>) -> DispatchResult {
+            ensure_root(origin)?;
+            <PayerPools>::insert(symbol, &pool);
+
+            Self::deposit_event(RawEvent::PayerPoolSet(symbol, pool));
+            Ok(())
+        }
+
+        /// Submit tx signatures, the signature must be of a sub account of the payer pool over the proposal_id.
+        /// until the payer pool of the symbol is set, signatures are not verified, those of a proposal are dropped
+        /// by its first verified one
+        #[weight = <T as Trait>::WeightInfo::submit_signatures(Module::<T>::payer_sub_account_count(*symbol))]
         pub fn submit_signatures(origin, symbol: RSymbol, block: u64, proposal_id: Vec<u8>, signature: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(symbol.chain_type() != ChainType::Substrate, Error::<T>::InvalidRSymbol);
+            let chain_type = registry::Module::<T>::chain_type(symbol)?;
+            ensure!(chain_type != ChainType::Substrate, Error::<T>::InvalidRSymbol);
             ensure!(payers::Module::<T>::is_payer(symbol, &who), payers::Error::<T>::MustBePayer);
-            ensure!(Self::account_signature((&who, symbol, block, &proposal_id)).is_none(), Error::<T>::SignatureRepeated);
 
             let mut signatures = Signatures::get(symbol, (block, &proposal_id)).unwrap_or(vec![]);
+            let pool = Self::payer_pool(symbol);
+            let mut signers = SignatureSigners::get(symbol, (block, &proposal_id));
+            if pool.is_some() && signers.len() < signatures.len() {
+                // unverified signatures submitted before the payer pool was set are dropped, their payers may sign again
+                signatures.drain(..signatures.len() - signers.len());
+            }
+            let submitted = Self::account_signature((&who, symbol, block, &proposal_id));
+            ensure!(submitted.map_or(true, |submitted| !signatures.contains(&submitted)), Error::<T>::SignatureRepeated);
             ensure!(!signatures.contains(&signature), Error::<T>::SignatureRepeated);
 
+            if let Some(pool) = &pool {
+                let signer = ledger::SubAccounts::get(symbol, pool).into_iter()
+                    .find(|pubkey| verify_proposal_signature(chain_type, pubkey, &signature, &proposal_id) == SigVerifyResult::Pass)
+                    .ok_or(Error::<T>::InvalidSignature)?;
+                ensure!(!signers.contains(&signer), Error::<T>::SignatureRepeated);
+                signers.push(signer);
+            }
+
             //update state
             signatures.push(signature.clone());
             Signatures::insert(symbol, (block, &proposal_id), &signatures);
             <AccountSignature<T>>::insert((&who, symbol, block, &proposal_id), &signature);
 
+            if let Some(pool) = pool {
+                let enough = signers.len() == ledger::MultiThresholds::get(symbol, &pool).unwrap_or(0) as usize;
+                SignatureSigners::insert(symbol, (block, &proposal_id), signers);
+                if enough {
+                    Self::deposit_event(RawEvent::SignaturesEnough(symbol, block, proposal_id.clone()));
+                }
+            }
+
             Self::deposit_event(RawEvent::SubmitSignatures(who.clone(), symbol, block, proposal_id, signature));
             Ok(())
         }
@@ -71,4 +133,8 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    /// sub accounts of the payer pool of symbol, each of which a signature may be verified against
+    pub fn payer_sub_account_count(symbol: RSymbol) -> u32 {
+        Self::payer_pool(symbol).map_or(0, |pool| ledger::SubAccounts::get(symbol, &pool).len() as u32)
+    }
 }
\ No newline at end of file
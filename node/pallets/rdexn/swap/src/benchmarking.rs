@@ -0,0 +1,150 @@
+//! Benchmarks for the RDexnSwap Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+
+const SEED: u32 = 0;
+const SYMBOL: RSymbol = RSymbol::RDOT;
+const GRADE: u8 = 0;
+const LOCK_NUMBER: u64 = 10;
+const AMOUNT: u128 = 1_000_000_000_000;
+const FUNDS: u128 = 1_000_000_000_000_000;
+
+fn funded_account<T: Trait>(who: T::AccountId, symbol: RSymbol) -> Result<T::AccountId, &'static str> {
+    T::Currency::make_free_balance_be(&who, FUNDS.saturated_into());
+    T::RCurrency::mint(&who, symbol, FUNDS)?;
+    Ok(who)
+}
+
+fn prepare_swap<T: Trait>(symbol: RSymbol) -> Result<(), &'static str> {
+    SwapTotalSwitch::put(true);
+    <FundAddress<T>>::put(funded_account::<T>(account("fund", 0, SEED), symbol)?);
+    RTokenRate::Module::<T>::set_rate(symbol, 0, 0);
+    SwapRates::insert((symbol, GRADE), SwapRate {lock_number: LOCK_NUMBER, rate: RATEBASE});
+    Ok(())
+}
+
+/// fills `block` with `count` undealt transactions
+fn fill_trans_infos<T: Trait>(block: u64, count: u32) {
+    let trans_infos: Vec<SwapTransactionInfo<T::AccountId>> = (0..count).map(|i| SwapTransactionInfo {
+        account: account("user", i, SEED),
+        receiver: vec![1u8; 32],
+        value: AMOUNT,
+        is_deal: false,
+    }).collect();
+    <TransInfos<T>>::insert((SYMBOL, block), trans_infos);
+}
+
+/// a payer of `SYMBOL` whose vote reaches the threshold
+fn last_payer<T: Trait>() -> T::AccountId {
+    let payer: T::AccountId = whitelisted_caller();
+    <RDexnPayers::Payers<T>>::insert(SYMBOL, &payer, true);
+    RDexnPayers::PayerThreshold::insert(SYMBOL, 1);
+    payer
+}
+
+benchmarks! {
+    _ { }
+
+    swap_rtoken_for_native_token {
+        prepare_swap::<T>(SYMBOL)?;
+        NativeTokenReserves::insert(SYMBOL, FUNDS);
+        let caller = funded_account::<T>(whitelisted_caller(), SYMBOL)?;
+        let trans_block = system::Module::<T>::block_number().saturated_into::<u64>() + LOCK_NUMBER;
+        fill_trans_infos::<T>(trans_block, Module::<T>::swap_limit_per_block() - 1);
+    }: _(RawOrigin::Signed(caller), vec![1u8; 32], SYMBOL, AMOUNT, 1, GRADE)
+    verify {
+        assert_eq!(Module::<T>::trans_infos((SYMBOL, trans_block)).unwrap().len(), Module::<T>::swap_limit_per_block() as usize);
+    }
+
+    swap_rfis_for_fis_token {
+        prepare_swap::<T>(RSymbol::RFIS)?;
+        let native_pool = funded_account::<T>(account("native_pool", 0, SEED), RSymbol::RFIS)?;
+        <NativePoolAddress<T>>::put(native_pool);
+        let caller = funded_account::<T>(whitelisted_caller(), RSymbol::RFIS)?;
+        let receiver: T::AccountId = account("receiver", 0, SEED);
+    }: _(RawOrigin::Signed(caller.clone()), receiver, AMOUNT, 1, GRADE)
+    verify {
+        assert_eq!(T::RCurrency::free_balance(&caller, RSymbol::RFIS), FUNDS - AMOUNT);
+    }
+
+    report_transfer_result_with_block {
+        let payer = last_payer::<T>();
+        fill_trans_infos::<T>(1, Module::<T>::swap_limit_per_block());
+    }: _(RawOrigin::Signed(payer), SYMBOL, 1)
+    verify {
+        assert_eq!(Module::<T>::latest_deal_block(SYMBOL), 1);
+    }
+
+    report_transfer_result_with_index {
+        let payer = last_payer::<T>();
+        let limit = Module::<T>::swap_limit_per_block();
+        fill_trans_infos::<T>(1, limit);
+        let mut trans_infos = Module::<T>::trans_infos((SYMBOL, 1)).unwrap();
+        for trans_info in trans_infos.iter_mut().take(limit as usize - 1) {
+            trans_info.is_deal = true;
+        }
+        <TransInfos<T>>::insert((SYMBOL, 1), trans_infos);
+    }: _(RawOrigin::Signed(payer), SYMBOL, 1, limit - 1)
+    verify {
+        assert_eq!(Module::<T>::latest_deal_block(SYMBOL), 1);
+    }
+
+    toggle_swap_total_switch {
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(Module::<T>::swap_total_switch());
+    }
+
+    toggle_swap_rtoken_switch {
+    }: _(RawOrigin::Root, SYMBOL)
+    verify {
+        assert!(!Module::<T>::swap_rtoken_switch(SYMBOL));
+    }
+
+    set_fund_address {
+        let address: T::AccountId = account("fund", 0, SEED);
+    }: _(RawOrigin::Root, address.clone())
+    verify {
+        assert_eq!(Module::<T>::fund_address(), Some(address));
+    }
+
+    set_native_pool_address {
+        let address: T::AccountId = account("native_pool", 0, SEED);
+    }: _(RawOrigin::Root, address.clone())
+    verify {
+        assert_eq!(Module::<T>::native_pool_address(), Some(address));
+    }
+
+    set_native_token_reserve {
+    }: _(RawOrigin::Root, SYMBOL, FUNDS)
+    verify {
+        assert_eq!(Module::<T>::native_token_reserves(SYMBOL), FUNDS);
+    }
+
+    set_swap_fee {
+    }: _(RawOrigin::Root, SYMBOL, AMOUNT)
+    verify {
+        assert_eq!(Module::<T>::swap_fees(SYMBOL), AMOUNT);
+    }
+
+    set_swap_rate {
+    }: _(RawOrigin::Root, SYMBOL, GRADE, LOCK_NUMBER, RATEBASE)
+    verify {
+        assert!(Module::<T>::swap_rates((SYMBOL, GRADE)).is_some());
+    }
+
+    set_swap_limit_per_block {
+    }: _(RawOrigin::Root, 100)
+    verify {
+        assert_eq!(Module::<T>::swap_limit_per_block(), 100);
+    }
+
+    set_latest_deal_block {
+        fill_trans_infos::<T>(1, Module::<T>::swap_limit_per_block());
+    }: _(RawOrigin::Root, SYMBOL, 1)
+    verify {
+        assert!(Module::<T>::trans_infos((SYMBOL, 1)).unwrap().iter().all(|trans_info| trans_info.is_deal));
+    }
+}
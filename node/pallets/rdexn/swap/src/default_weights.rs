@@ -0,0 +1,48 @@
+//! Default weights for the RDexnSwap Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn swap_rtoken_for_native_token() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn swap_rfis_for_fis_token() -> Weight {
+        3_000_000_000 as Weight
+    }
+    fn report_transfer_result_with_block() -> Weight {
+        100_000 as Weight
+    }
+    fn report_transfer_result_with_index() -> Weight {
+        100_000 as Weight
+    }
+    fn toggle_swap_total_switch() -> Weight {
+        100_000 as Weight
+    }
+    fn toggle_swap_rtoken_switch() -> Weight {
+        100_000 as Weight
+    }
+    fn set_fund_address() -> Weight {
+        100_000 as Weight
+    }
+    fn set_native_pool_address() -> Weight {
+        100_000 as Weight
+    }
+    fn set_native_token_reserve() -> Weight {
+        100_000 as Weight
+    }
+    fn set_swap_fee() -> Weight {
+        100_000 as Weight
+    }
+    fn set_swap_rate() -> Weight {
+        100_000 as Weight
+    }
+    fn set_swap_limit_per_block() -> Weight {
+        100_000 as Weight
+    }
+    fn set_latest_deal_block() -> Weight {
+        100_000 as Weight
+    }
+}
@@ -5,6 +5,7 @@ use frame_support::{
     dispatch::DispatchResult,
     ensure,
     traits::{Currency, ExistenceRequirement::{KeepAlive}},
+    weights::Weight,
 };
 use sp_std::prelude::*;
 
@@ -14,18 +15,41 @@ use rtoken_balances::traits::Currency as RCurrency;
 use general_signature::verify_recipient;
 use rtoken_rate as RTokenRate;
 use rdexn_payers as RDexnPayers;
+use rtoken_registry as RTokenRegistry;
 use sp_arithmetic::helpers_128bit::multiply_by_rational;
 use sp_runtime::traits::{SaturatedConversion};
-pub trait Trait: system::Trait + RTokenRate::Trait + RDexnPayers::Trait{
+pub trait Trait: system::Trait + RTokenRate::Trait + RDexnPayers::Trait + RTokenRegistry::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
     /// currency of rtoken
     type RCurrency: RCurrency<Self::AccountId>;
     /// The currency mechanism.
     type Currency: Currency<Self::AccountId>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 pub mod models;
 pub use models::*;
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub trait WeightInfo {
+    fn swap_rtoken_for_native_token() -> Weight;
+    fn swap_rfis_for_fis_token() -> Weight;
+    fn report_transfer_result_with_block() -> Weight;
+    fn report_transfer_result_with_index() -> Weight;
+    fn toggle_swap_total_switch() -> Weight;
+    fn toggle_swap_rtoken_switch() -> Weight;
+    fn set_fund_address() -> Weight;
+    fn set_native_pool_address() -> Weight;
+    fn set_native_token_reserve() -> Weight;
+    fn set_swap_fee() -> Weight;
+    fn set_swap_rate() -> Weight;
+    fn set_swap_limit_per_block() -> Weight;
+    fn set_latest_deal_block() -> Weight;
+}
+
 pub const RATEBASE: u128 = 1_000_000_000_000;
 decl_event! {
     pub enum Event<T> where
@@ -116,7 +140,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// swap rtoken for native token
-        #[weight = 10_000_000_000]
+        #[weight = <T as Trait>::WeightInfo::swap_rtoken_for_native_token()]
         pub fn swap_rtoken_for_native_token(origin, receiver: Vec<u8>, symbol: RSymbol, rtoken_amount: u128, min_out_amount: u128, grade: u8) -> DispatchResult {
             let who = ensure_signed(origin)?;   
             let now_block = system::Module::<T>::block_number().saturated_into::<u64>();
@@ -137,7 +161,8 @@ decl_module! {
             ensure!(T::RCurrency::free_balance(&who, symbol) >= rtoken_amount, Error::<T>::RTokenAmountNotEnough);
 
             // check receiver
-            match verify_recipient(symbol, &receiver) {
+            let chain_type = RTokenRegistry::Module::<T>::chain_type(symbol)?;
+            match verify_recipient(chain_type, &receiver) {
                 false => Err(Error::<T>::ReceiverInvalid)?,
                 _ => (),
             }
@@ -166,7 +191,7 @@ decl_module! {
         }
 
         /// swap rFIS for FIS token
-        #[weight = 3_000_000_000]
+        #[weight = <T as Trait>::WeightInfo::swap_rfis_for_fis_token()]
         pub fn swap_rfis_for_fis_token(origin, receiver: T::AccountId, rtoken_amount: u128, min_out_amount: u128, grade: u8) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -201,7 +226,7 @@ decl_module! {
 
 
         /// report transfer result with block
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::report_transfer_result_with_block()]
         pub fn report_transfer_result_with_block(origin, symbol: RSymbol, block: u64) -> DispatchResult {
             let who = ensure_signed(origin)?;
             // check
@@ -226,7 +251,7 @@ decl_module! {
         }
 
         /// report transfer result with index
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::report_transfer_result_with_index()]
         pub fn report_transfer_result_with_index(origin, symbol: RSymbol, block: u64, index: u32) -> DispatchResult {
             let who = ensure_signed(origin)?;
             // check
@@ -261,7 +286,7 @@ decl_module! {
         }
 
         /// turn on/off swap total switch, default closed
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::toggle_swap_total_switch()]
         fn toggle_swap_total_switch(origin) -> DispatchResult {
             ensure_root(origin)?;
             let state = Self::swap_total_switch();
@@ -270,7 +295,7 @@ decl_module! {
         }
 
         /// turn on/off swap rtoken switch, default opened
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::toggle_swap_rtoken_switch()]
         fn toggle_swap_rtoken_switch(origin, symbol: RSymbol) -> DispatchResult {
             ensure_root(origin)?;
             let state = Self::swap_rtoken_switch(symbol);
@@ -279,7 +304,7 @@ decl_module! {
         }
 
         /// set fund address
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_fund_address()]
         fn set_fund_address(origin, address: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             <FundAddress<T>>::put(address);
@@ -287,7 +312,7 @@ decl_module! {
         }
 
         /// set native pool address
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_native_pool_address()]
         fn set_native_pool_address(origin, address: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             <NativePoolAddress<T>>::put(address);
@@ -295,7 +320,7 @@ decl_module! {
         }
 
         /// set native reserve
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_native_token_reserve()]
         fn set_native_token_reserve(origin, symbol: RSymbol, reserve: u128) -> DispatchResult {
             ensure_root(origin)?;
             NativeTokenReserves::insert(symbol, reserve);
@@ -303,7 +328,7 @@ decl_module! {
         }
 
         /// set swap fee
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_swap_fee()]
         fn set_swap_fee(origin, symbol: RSymbol, fee: Balance) -> DispatchResult {
             ensure_root(origin)?;
             SwapFees::insert(symbol, fee);
@@ -311,21 +336,21 @@ decl_module! {
         }
 
         /// set swap rate
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_swap_rate()]
         fn set_swap_rate(origin, symbol: RSymbol, grade: u8, lock_number: u64, rate: u128) -> DispatchResult {
             ensure_root(origin)?;
             SwapRates::insert((symbol, grade), SwapRate{lock_number, rate});
             Ok(())
         }
 
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_swap_limit_per_block()]
         fn set_swap_limit_per_block(origin, limit: u32) -> DispatchResult {
             ensure_root(origin)?;
             SwapLimitPerBlock::put(limit);
             Ok(())
         }
 
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::set_latest_deal_block()]
         fn set_latest_deal_block(origin, symbol: RSymbol, block: u64) -> DispatchResult {
             ensure_root(origin)?;
             LatestDealBlock::insert(symbol, block);
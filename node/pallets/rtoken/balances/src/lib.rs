@@ -4,6 +4,7 @@ use sp_std::{convert::Infallible};
 use codec::{Encode, Decode};
 use frame_support::{
 	decl_event, decl_storage, decl_module, decl_error, ensure,
+	traits::LockIdentifier,
 };
 use sp_runtime::{
 	RuntimeDebug, DispatchResult,
@@ -13,10 +14,16 @@ use sp_runtime::{
 };
 use frame_system::{self as system, ensure_signed};
 use node_primitives::RSymbol;
+use rtoken_registry as registry;
 
 pub mod traits;
 
-pub trait Trait: system::Trait {
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub trait Trait: system::Trait + registry::Trait {
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 }
@@ -31,6 +38,12 @@ decl_event!(
 		Minted(AccountId, RSymbol, u128),
 		/// Some balance was burned
 		Burned(AccountId, RSymbol, u128),
+		/// Allowance of a spender was set. \[owner, spender, symbol, value\]
+		Approval(AccountId, AccountId, RSymbol, u128),
+		/// Some balance was reserved (moved from free to reserved). \[who, symbol, value\]
+		Reserved(AccountId, RSymbol, u128),
+		/// Some balance was unreserved (moved from reserved to free). \[who, symbol, value\]
+		Unreserved(AccountId, RSymbol, u128),
 	}
 );
 
@@ -42,6 +55,8 @@ decl_error! {
 		Overflow,
 		/// Balance too low to send value
 		InsufficientBalance,
+		/// Allowance too low to spend value
+		InsufficientAllowance,
 	}
 }
 
@@ -52,6 +67,16 @@ pub struct AccountRData {
 	pub free: u128,
 }
 
+/// A single lock on the free balance of an account. The free balance can not go below the
+/// largest lock of the account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BalanceLock {
+	/// An identifier for this lock. Only one lock may be in existence for each identifier.
+	pub id: LockIdentifier,
+	/// The amount which the free balance may not drop below when this lock is in effect.
+	pub amount: u128,
+}
+
 decl_storage! {
 	trait Store for Module<T: Trait> as RBalances {
 		/// The total units issued in the system.
@@ -60,6 +85,18 @@ decl_storage! {
 		/// NOTE: This is only used in the case that this module is used to store balances.
 		pub Account get(fn account):
 			double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::AccountId => Option<AccountRData>;
+
+		/// Amount a spender may transfer out of the balance of an owner, by (owner, spender, symbol)
+		pub Allowances get(fn allowances): map hasher(blake2_128_concat) (T::AccountId, T::AccountId, RSymbol) => u128;
+
+		/// Reserved part of the balance, which is held in place and can not be spent.
+		/// Kept apart from `Account` so that existing account data needs no migration.
+		pub Reserved get(fn reserved):
+			double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::AccountId => u128;
+
+		/// Any liquidity locks on the free balance of an account.
+		pub Locks get(fn locks):
+			double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::AccountId => Vec<BalanceLock>;
 	}
 }
 
@@ -79,6 +116,67 @@ decl_module! {
 			let dest = T::Lookup::lookup(dest)?;
             <Self as traits::Currency<_>>::transfer(&transactor, &dest, symbol, value)?;
         }
+
+		/// Set the amount `spender` may transfer out of the caller's free balance.
+		#[weight = 195_000_000]
+		pub fn approve(
+			origin,
+			spender: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			value: u128
+		) {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			Self::set_allowance(&owner, &spender, symbol, value);
+		}
+
+		/// Raise the amount `spender` may transfer out of the caller's free balance.
+		#[weight = 195_000_000]
+		pub fn increase_allowance(
+			origin,
+			spender: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			added_value: u128
+		) {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			let value = Self::allowances((&owner, &spender, symbol)).checked_add(added_value)
+				.ok_or(Error::<T>::Overflow)?;
+			Self::set_allowance(&owner, &spender, symbol, value);
+		}
+
+		/// Lower the amount `spender` may transfer out of the caller's free balance.
+		#[weight = 195_000_000]
+		pub fn decrease_allowance(
+			origin,
+			spender: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			subtracted_value: u128
+		) {
+			let owner = ensure_signed(origin)?;
+			let spender = T::Lookup::lookup(spender)?;
+			let value = Self::allowances((&owner, &spender, symbol)).checked_sub(subtracted_value)
+				.ok_or(Error::<T>::InsufficientAllowance)?;
+			Self::set_allowance(&owner, &spender, symbol, value);
+		}
+
+		/// Transfer some liquid free balance of `owner` to another account, spending the caller's allowance.
+		#[weight = 195_000_000]
+		pub fn transfer_from(
+			origin,
+			owner: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			symbol: RSymbol,
+			value: u128
+		) {
+			let spender = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let allowance = Self::allowances((&owner, &spender, symbol)).checked_sub(value)
+				.ok_or(Error::<T>::InsufficientAllowance)?;
+			<Self as traits::Currency<_>>::transfer(&owner, &dest, symbol, value)?;
+			Self::set_allowance(&owner, &spender, symbol, allowance);
+		}
     }
 }
 
@@ -110,6 +208,34 @@ impl<T: Trait> Module<T> {
             result
         })
 	}
+
+	/// Set the allowance of `spender` over the balance of `owner`, removing it once it is zero.
+	fn set_allowance(owner: &T::AccountId, spender: &T::AccountId, symbol: RSymbol, value: u128) {
+		if value.is_zero() {
+			<Allowances<T>>::remove((owner, spender, symbol));
+		} else {
+			<Allowances<T>>::insert((owner, spender, symbol), value);
+		}
+		Self::deposit_event(RawEvent::Approval(owner.clone(), spender.clone(), symbol, value));
+	}
+
+	/// Set the reserved balance of `who`, removing it once it is zero.
+	fn set_reserved(who: &T::AccountId, symbol: RSymbol, value: u128) {
+		if value.is_zero() {
+			<Reserved<T>>::remove(symbol, who);
+		} else {
+			<Reserved<T>>::insert(symbol, who, value);
+		}
+	}
+
+	/// Update the locks of `who`, removing them once there is none left.
+	fn update_locks(who: &T::AccountId, symbol: RSymbol, locks: Vec<BalanceLock>) {
+		if locks.is_empty() {
+			<Locks<T>>::remove(symbol, who);
+		} else {
+			<Locks<T>>::insert(symbol, who, locks);
+		}
+	}
 }
 
 impl<T: Trait> traits::Currency<T::AccountId> for Module<T>
@@ -122,6 +248,10 @@ impl<T: Trait> traits::Currency<T::AccountId> for Module<T>
 		}
 	}
 
+	fn allowance(owner: &T::AccountId, spender: &T::AccountId, symbol: RSymbol) -> u128 {
+		<Allowances<T>>::get((owner, spender, symbol))
+	}
+
 	fn total_issuance(symbol: RSymbol) -> u128 {
 		<TotalIssuance>::get(symbol)
 	}
@@ -130,14 +260,14 @@ impl<T: Trait> traits::Currency<T::AccountId> for Module<T>
 	// restrictions like locks and vesting balance.
 	// Is a no-op if amount to be withdrawn is zero.
 	fn ensure_can_withdraw(
-		_who: &T::AccountId,
-		_symbol: RSymbol,
+		who: &T::AccountId,
+		symbol: RSymbol,
 		amount: u128,
 		new_balance: u128,
 	) -> DispatchResult {
 		if amount.is_zero() { return Ok(()) }
-		// let min_balance = <Account<T>>::get(symbol, &who).unwrap_or_default().frozen;
-		ensure!(new_balance >= Zero::zero(), Error::<T>::LiquidityRestrictions);
+		let frozen = Self::locks(symbol, who).iter().map(|lock| lock.amount).max().unwrap_or_default();
+		ensure!(new_balance >= frozen, Error::<T>::LiquidityRestrictions);
 		Ok(())
 	}
 
@@ -179,6 +309,7 @@ impl<T: Trait> traits::Currency<T::AccountId> for Module<T>
 		value: u128
 	) -> DispatchResult {
 		if value.is_zero() { return Ok(()) }
+		registry::Module::<T>::ensure_enabled(symbol)?;
 
 		Self::try_mutate_account(who, symbol, |account_rdata| -> DispatchResult {
 			account_rdata.free = account_rdata.free.checked_add(value).ok_or(Error::<T>::Overflow)?;
@@ -223,4 +354,104 @@ impl<T: Trait> traits::Currency<T::AccountId> for Module<T>
 		Self::deposit_event(RawEvent::Burned(who.clone(), symbol.clone(), value));
 		Ok(())
 	}
-}
\ No newline at end of file
+
+	fn reserved_balance(who: &T::AccountId, symbol: RSymbol) -> u128 {
+		Self::reserved(symbol, who)
+	}
+
+	/// Move `value` from the free balance of `who` to its reserved balance.
+	///
+	/// Is a no-op if the `value` to be reserved is zero.
+	fn reserve(
+		who: &T::AccountId,
+		symbol: RSymbol,
+		value: u128
+	) -> DispatchResult {
+		if value.is_zero() { return Ok(()) }
+
+		Self::try_mutate_account(who, symbol, |account_rdata| -> DispatchResult {
+			account_rdata.free = account_rdata.free.checked_sub(value).ok_or(Error::<T>::InsufficientBalance)?;
+			Self::ensure_can_withdraw(who, symbol, value, account_rdata.free)?;
+
+			Ok(())
+		})?;
+		let reserved = Self::reserved(symbol, who).checked_add(value).ok_or(Error::<T>::Overflow)?;
+		Self::set_reserved(who, symbol, reserved);
+
+		Self::deposit_event(RawEvent::Reserved(who.clone(), symbol, value));
+		Ok(())
+	}
+
+	/// Move up to `value` from the reserved balance of `who` back to its free balance.
+	///
+	/// Is a no-op if the `value` to be unreserved is zero.
+	fn unreserve(
+		who: &T::AccountId,
+		symbol: RSymbol,
+		value: u128
+	) -> u128 {
+		if value.is_zero() { return 0 }
+
+		let reserved = Self::reserved(symbol, who);
+		let actual = reserved.min(value);
+		if actual.is_zero() { return value }
+
+		Self::mutate_account(who, symbol, |account_rdata| {
+			account_rdata.free = account_rdata.free.saturating_add(actual);
+		});
+		Self::set_reserved(who, symbol, reserved - actual);
+
+		Self::deposit_event(RawEvent::Unreserved(who.clone(), symbol, actual));
+		value - actual
+	}
+
+	fn set_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		symbol: RSymbol,
+		amount: u128,
+	) {
+		if amount.is_zero() { return }
+
+		let mut new_lock = Some(BalanceLock { id, amount });
+		let mut locks = Self::locks(symbol, who).into_iter()
+			.filter_map(|lock| if lock.id == id { new_lock.take() } else { Some(lock) })
+			.collect::<Vec<_>>();
+		if let Some(lock) = new_lock {
+			locks.push(lock);
+		}
+		Self::update_locks(who, symbol, locks);
+	}
+
+	fn extend_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		symbol: RSymbol,
+		amount: u128,
+	) {
+		if amount.is_zero() { return }
+
+		let mut new_lock = Some(BalanceLock { id, amount });
+		let mut locks = Self::locks(symbol, who).into_iter()
+			.filter_map(|lock| if lock.id == id {
+				new_lock.take().map(|new_lock| BalanceLock { id, amount: lock.amount.max(new_lock.amount) })
+			} else {
+				Some(lock)
+			})
+			.collect::<Vec<_>>();
+		if let Some(lock) = new_lock {
+			locks.push(lock);
+		}
+		Self::update_locks(who, symbol, locks);
+	}
+
+	fn remove_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		symbol: RSymbol,
+	) {
+		let mut locks = Self::locks(symbol, who);
+		locks.retain(|lock| lock.id != id);
+		Self::update_locks(who, symbol, locks);
+	}
+}
@@ -0,0 +1,142 @@
+use super::*;
+use super::mock::*;
+use super::traits::Currency;
+use frame_support::{assert_ok, assert_noop};
+
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+#[test]
+fn approve_should_set_allowance_and_emit_approval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+		assert_eq!(RBalances::allowances((1, 2, SYMBOL)), 100);
+		assert_eq!(last_event(), TestEvent::rtoken_balances(RawEvent::Approval(1, 2, SYMBOL, 100)));
+
+		assert_ok!(RBalances::increase_allowance(Origin::signed(1), 2, SYMBOL, 50));
+		assert_eq!(last_event(), TestEvent::rtoken_balances(RawEvent::Approval(1, 2, SYMBOL, 150)));
+		assert_ok!(RBalances::decrease_allowance(Origin::signed(1), 2, SYMBOL, 150));
+		assert_eq!(last_event(), TestEvent::rtoken_balances(RawEvent::Approval(1, 2, SYMBOL, 0)));
+		assert!(!Allowances::<Test>::contains_key((1, 2, SYMBOL)));
+	});
+}
+
+#[test]
+fn allowance_should_not_underflow_or_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+		assert_noop!(
+			RBalances::decrease_allowance(Origin::signed(1), 2, SYMBOL, 101),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			RBalances::increase_allowance(Origin::signed(1), 2, SYMBOL, u128::max_value()),
+			Error::<Test>::Overflow
+		);
+		assert_eq!(RBalances::allowance(&1, &2, SYMBOL), 100);
+	});
+}
+
+#[test]
+fn transfer_from_should_spend_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RBalances::mint(&1, SYMBOL, 1000));
+		assert_ok!(RBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+
+		assert_noop!(
+			RBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 101),
+			Error::<Test>::InsufficientAllowance
+		);
+		// the allowance is per symbol and per spender
+		assert_noop!(
+			RBalances::transfer_from(Origin::signed(3), 1, 3, SYMBOL, 1),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			RBalances::transfer_from(Origin::signed(2), 1, 3, RSymbol::RKSM, 1),
+			Error::<Test>::InsufficientAllowance
+		);
+
+		assert_ok!(RBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 60));
+		assert_eq!(RBalances::free_balance(&1, SYMBOL), 940);
+		assert_eq!(RBalances::free_balance(&3, SYMBOL), 60);
+		assert_eq!(RBalances::allowances((1, 2, SYMBOL)), 40);
+		assert_eq!(last_event(), TestEvent::rtoken_balances(RawEvent::Approval(1, 2, SYMBOL, 40)));
+
+		assert_ok!(RBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 40));
+		assert!(!Allowances::<Test>::contains_key((1, 2, SYMBOL)));
+	});
+}
+
+#[test]
+fn transfer_from_should_keep_allowance_when_balance_is_short() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RBalances::mint(&1, SYMBOL, 10));
+		assert_ok!(RBalances::approve(Origin::signed(1), 2, SYMBOL, 100));
+		assert_noop!(
+			RBalances::transfer_from(Origin::signed(2), 1, 3, SYMBOL, 11),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(RBalances::allowances((1, 2, SYMBOL)), 100);
+	});
+}
+
+#[test]
+fn largest_lock_should_bound_transfer_and_burn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RBalances::mint(&1, SYMBOL, 100));
+		RBalances::set_lock(*b"lock____", &1, SYMBOL, 30);
+		RBalances::set_lock(*b"stake___", &1, SYMBOL, 60);
+
+		assert_noop!(RBalances::transfer(Origin::signed(1), 2, SYMBOL, 41), Error::<Test>::LiquidityRestrictions);
+		assert_noop!(RBalances::burn(&1, SYMBOL, 41), Error::<Test>::LiquidityRestrictions);
+		assert_noop!(RBalances::reserve(&1, SYMBOL, 41), Error::<Test>::LiquidityRestrictions);
+
+		assert_ok!(RBalances::transfer(Origin::signed(1), 2, SYMBOL, 30));
+		assert_ok!(RBalances::burn(&1, SYMBOL, 10));
+		assert_eq!(RBalances::free_balance(&1, SYMBOL), 60);
+		assert_noop!(RBalances::burn(&1, SYMBOL, 1), Error::<Test>::LiquidityRestrictions);
+
+		// once the largest lock is gone the next one applies
+		RBalances::remove_lock(*b"stake___", &1, SYMBOL);
+		assert_noop!(RBalances::burn(&1, SYMBOL, 31), Error::<Test>::LiquidityRestrictions);
+		assert_ok!(RBalances::burn(&1, SYMBOL, 30));
+		RBalances::remove_lock(*b"lock____", &1, SYMBOL);
+		assert!(!Locks::<Test>::contains_key(SYMBOL, 1));
+		assert_ok!(RBalances::transfer(Origin::signed(1), 2, SYMBOL, 30));
+	});
+}
+
+#[test]
+fn set_and_extend_lock_should_replace_lock_of_same_id() {
+	new_test_ext().execute_with(|| {
+		RBalances::set_lock(*b"stake___", &1, SYMBOL, 60);
+		RBalances::set_lock(*b"stake___", &1, SYMBOL, 20);
+		assert_eq!(RBalances::locks(SYMBOL, 1), vec![BalanceLock { id: *b"stake___", amount: 20 }]);
+
+		RBalances::extend_lock(*b"stake___", &1, SYMBOL, 10);
+		assert_eq!(RBalances::locks(SYMBOL, 1), vec![BalanceLock { id: *b"stake___", amount: 20 }]);
+		RBalances::extend_lock(*b"stake___", &1, SYMBOL, 50);
+		assert_eq!(RBalances::locks(SYMBOL, 1), vec![BalanceLock { id: *b"stake___", amount: 50 }]);
+	});
+}
+
+#[test]
+fn unreserve_should_return_shortfall() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RBalances::mint(&1, SYMBOL, 100));
+		assert_ok!(RBalances::reserve(&1, SYMBOL, 30));
+		assert_eq!(RBalances::free_balance(&1, SYMBOL), 70);
+		assert_eq!(RBalances::reserved_balance(&1, SYMBOL), 30);
+		assert_noop!(RBalances::reserve(&1, SYMBOL, 71), Error::<Test>::InsufficientBalance);
+
+		assert_eq!(RBalances::unreserve(&1, SYMBOL, 10), 0);
+		assert_eq!(RBalances::unreserve(&1, SYMBOL, 50), 30);
+		assert_eq!(last_event(), TestEvent::rtoken_balances(RawEvent::Unreserved(1, SYMBOL, 20)));
+		assert_eq!(RBalances::free_balance(&1, SYMBOL), 100);
+		assert!(!Reserved::<Test>::contains_key(SYMBOL, 1));
+
+		// nothing reserved, all of it falls short
+		assert_eq!(RBalances::unreserve(&1, SYMBOL, 5), 5);
+		assert_eq!(RBalances::total_issuance(SYMBOL), 100);
+	});
+}
@@ -1,9 +1,13 @@
 use sp_runtime::{DispatchResult};
+use frame_support::traits::LockIdentifier;
 use node_primitives::RSymbol;
 
 pub trait Currency<AccountId> {
 	/// The 'free' balance of a given account.
 	fn free_balance(who: &AccountId, symbol: RSymbol) -> u128;
+
+	/// The amount `spender` may still transfer out of the free balance of `owner`.
+	fn allowance(owner: &AccountId, spender: &AccountId, symbol: RSymbol) -> u128;
 	
 	/// Returns `Ok` iff the account is able to make a withdrawal of the given amount
 	/// for the given reason. Basically, it's just a dry-run of `withdraw`.
@@ -49,4 +53,53 @@ pub trait Currency<AccountId> {
 		symbol: RSymbol,
 		value: u128,
 	) -> DispatchResult;
-}
\ No newline at end of file
+
+	/// The amount of the balance of a given account that is reserved and can not be spent.
+	fn reserved_balance(who: &AccountId, symbol: RSymbol) -> u128;
+
+	/// Moves `value` from the free balance to the reserved balance of `who`.
+	///
+	/// Fails if the free balance is too low, or if the locks of `who` prevent the withdrawal.
+	fn reserve(
+		who: &AccountId,
+		symbol: RSymbol,
+		value: u128,
+	) -> DispatchResult;
+
+	/// Moves up to `value` from the reserved balance back to the free balance of `who`.
+	///
+	/// Returns the part of `value` that could not be unreserved.
+	fn unreserve(
+		who: &AccountId,
+		symbol: RSymbol,
+		value: u128,
+	) -> u128;
+
+	/// Creates a new lock `id` on the free balance of `who`, or replaces the existing one.
+	///
+	/// Is a no-op if `amount` is zero.
+	fn set_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		symbol: RSymbol,
+		amount: u128,
+	);
+
+	/// Raises the lock `id` on the free balance of `who` to `amount` if it is lower,
+	/// creating the lock if there is none.
+	///
+	/// Is a no-op if `amount` is zero.
+	fn extend_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		symbol: RSymbol,
+		amount: u128,
+	);
+
+	/// Removes the lock `id` from the free balance of `who`.
+	fn remove_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		symbol: RSymbol,
+	);
+}
@@ -0,0 +1,42 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the rtoken ledger and rate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use codec::Codec;
+use node_primitives::RSymbol;
+use rtoken_ledger::{LinkChunk, BondSnapshot};
+use rtoken_rate::RateType;
+
+sp_api::decl_runtime_apis! {
+    pub trait RTokenLedgerApi<AccountId, Hash> where
+        AccountId: Codec,
+        Hash: Codec,
+    {
+        /// current era of the symbol's original chain
+        fn chain_era(symbol: RSymbol) -> Option<u32>;
+        /// bond pipeline of each bonded pool
+        fn bond_pipelines(symbol: RSymbol) -> Vec<(Vec<u8>, LinkChunk)>;
+        /// snapshots of the current era still being processed, with their ids
+        fn pending_snapshots(symbol: RSymbol) -> Vec<(Hash, BondSnapshot<AccountId>)>;
+        /// current rate of rtoken to token
+        fn rate(symbol: RSymbol) -> Option<RateType>;
+        /// rate set at an era
+        fn era_rate(symbol: RSymbol, era: u32) -> Option<RateType>;
+        /// rtoken minted for a bond of `amount` at the current rate
+        fn token_to_rtoken(symbol: RSymbol, amount: u128) -> u128;
+        /// token redeemed for an unbond of `amount` at the current rate
+        fn rtoken_to_token(symbol: RSymbol, amount: u128) -> u128;
+    }
+}
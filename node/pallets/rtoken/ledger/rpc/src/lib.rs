@@ -0,0 +1,237 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the rtoken ledger and rate, served as `rtoken_*` methods.
+
+use std::sync::Arc;
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Serialize, Deserialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, U256};
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use node_primitives::RSymbol;
+use rtoken_ledger::{LinkChunk, BondSnapshot, PoolBondState};
+pub use rtoken_ledger_rpc_runtime_api::RTokenLedgerApi as RTokenLedgerRuntimeApi;
+
+/// Bond pipeline of a pool
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolPipeline {
+    pub pool: Bytes,
+    pub bond: NumberOrHex,
+    pub unbond: NumberOrHex,
+    pub active: NumberOrHex,
+}
+
+impl From<(Vec<u8>, LinkChunk)> for PoolPipeline {
+    fn from((pool, pipe): (Vec<u8>, LinkChunk)) -> Self {
+        Self {
+            pool: pool.into(),
+            bond: to_number_or_hex(pipe.bond),
+            unbond: to_number_or_hex(pipe.unbond),
+            active: to_number_or_hex(pipe.active),
+        }
+    }
+}
+
+/// Snapshot of a pool in the current era
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSnapshot<AccountId, Hash> {
+    pub shot_id: Hash,
+    pub era: u32,
+    pub pool: Bytes,
+    pub bond: NumberOrHex,
+    pub unbond: NumberOrHex,
+    pub active: NumberOrHex,
+    pub last_voter: AccountId,
+    pub bond_state: PoolBondState,
+}
+
+impl<AccountId, Hash> From<(Hash, BondSnapshot<AccountId>)> for PoolSnapshot<AccountId, Hash> {
+    fn from((shot_id, snap): (Hash, BondSnapshot<AccountId>)) -> Self {
+        Self {
+            shot_id,
+            era: snap.era,
+            pool: snap.pool.into(),
+            bond: to_number_or_hex(snap.bond),
+            unbond: to_number_or_hex(snap.unbond),
+            active: to_number_or_hex(snap.active),
+            last_voter: snap.last_voter,
+            bond_state: snap.bond_state,
+        }
+    }
+}
+
+#[rpc]
+pub trait RTokenLedgerApi<BlockHash, AccountId, Hash> {
+    /// current era of the symbol's original chain
+    #[rpc(name = "rtoken_chainEra")]
+    fn chain_era(&self, symbol: RSymbol, at: Option<BlockHash>) -> Result<Option<u32>>;
+
+    /// bond pipeline of each bonded pool
+    #[rpc(name = "rtoken_bondPipelines")]
+    fn bond_pipelines(&self, symbol: RSymbol, at: Option<BlockHash>) -> Result<Vec<PoolPipeline>>;
+
+    /// snapshots of the current era still being processed
+    #[rpc(name = "rtoken_pendingSnapshots")]
+    fn pending_snapshots(&self, symbol: RSymbol, at: Option<BlockHash>) -> Result<Vec<PoolSnapshot<AccountId, Hash>>>;
+
+    /// current rate, based on RATEBASE
+    #[rpc(name = "rtoken_rate")]
+    fn rate(&self, symbol: RSymbol, at: Option<BlockHash>) -> Result<Option<u64>>;
+
+    /// rate set at an era, based on RATEBASE
+    #[rpc(name = "rtoken_eraRate")]
+    fn era_rate(&self, symbol: RSymbol, era: u32, at: Option<BlockHash>) -> Result<Option<u64>>;
+
+    /// quote rtoken for bonding `amount` of token
+    #[rpc(name = "rtoken_tokenToRtoken")]
+    fn token_to_rtoken(&self, symbol: RSymbol, amount: NumberOrHex, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+    /// quote token for unbonding `amount` of rtoken, before commission
+    #[rpc(name = "rtoken_rtokenToToken")]
+    fn rtoken_to_token(&self, symbol: RSymbol, amount: NumberOrHex, at: Option<BlockHash>) -> Result<NumberOrHex>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+    /// The amount does not fit in u128.
+    AmountOverflow,
+}
+
+impl From<Error> for i64 {
+    fn from(e: Error) -> i64 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::AmountOverflow => 2,
+        }
+    }
+}
+
+/// An implementation of rtoken ledger specific RPC methods.
+pub struct RTokenLedger<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> RTokenLedger<C, B> {
+    /// Create new `RTokenLedger` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, AccountId, Hash> RTokenLedgerApi<<Block as BlockT>::Hash, AccountId, Hash> for RTokenLedger<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: RTokenLedgerRuntimeApi<Block, AccountId, Hash>,
+    AccountId: Codec,
+    Hash: Codec,
+{
+    fn chain_era(&self, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Option<u32>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.chain_era(&at, symbol).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn bond_pipelines(&self, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<PoolPipeline>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.bond_pipelines(&at, symbol)
+            .map(|pipes| pipes.into_iter().map(Into::into).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn pending_snapshots(&self, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<PoolSnapshot<AccountId, Hash>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.pending_snapshots(&at, symbol)
+            .map(|shots| shots.into_iter().map(Into::into).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn rate(&self, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.rate(&at, symbol).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn era_rate(&self, symbol: RSymbol, era: u32, at: Option<<Block as BlockT>::Hash>) -> Result<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.era_rate(&at, symbol, era).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn token_to_rtoken(&self, symbol: RSymbol, amount: NumberOrHex, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let amount = to_u128(amount)?;
+
+        api.token_to_rtoken(&at, symbol, amount)
+            .map(to_number_or_hex)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn rtoken_to_token(&self, symbol: RSymbol, amount: NumberOrHex, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let amount = to_u128(amount)?;
+
+        api.rtoken_to_token(&at, symbol, amount)
+            .map(to_number_or_hex)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+/// Balances bigger than u64 are returned as hex, as json numbers can't hold them.
+pub fn to_number_or_hex(value: u128) -> NumberOrHex {
+    if value <= u64::max_value() as u128 {
+        NumberOrHex::Number(value as u64)
+    } else {
+        NumberOrHex::Hex(U256::from(value))
+    }
+}
+
+/// Converts an rpc amount into a balance, refusing anything above u128.
+pub fn to_u128(value: NumberOrHex) -> Result<u128> {
+    let value = value.into_u256();
+    if value > U256::from(u128::max_value()) {
+        return Err(RpcError {
+            code: ErrorCode::ServerError(Error::AmountOverflow.into()),
+            message: "Amount does not fit into u128.".into(),
+            data: Some(format!("{}", value).into()),
+        });
+    }
+
+    Ok(value.low_u128())
+}
+
+/// Converts a runtime trap into an RPC error.
+pub fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(Error::RuntimeError.into()),
+        message: "Runtime trapped".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
@@ -0,0 +1,243 @@
+//! Benchmarks for the RTokenLedger Pallet
+
+use super::*;
+use codec::Encode;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account};
+
+const SEED: u32 = 0;
+/// number of bonded pools used for the benchmarks which iterate over pools
+const MAX_POOLS: u32 = 10;
+const ACTIVE: u128 = 1_000_000_000_000;
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+fn pool_of<T: Trait>(index: u32) -> Vec<u8> {
+    account::<T::AccountId>("pool", index, SEED).encode()
+}
+
+fn add_bonded_pool<T: Trait>(index: u32, active: u128) -> Vec<u8> {
+    let pool = pool_of::<T>(index);
+    <Pools>::append(SYMBOL, &pool);
+    <BondedPools>::append(SYMBOL, &pool);
+    <BondPipelines>::insert(SYMBOL, &pool, LinkChunk {bond: 0, unbond: 0, active});
+    pool
+}
+
+fn add_snapshot<T: Trait>(bond_state: PoolBondState) -> T::Hash {
+    let pool = add_bonded_pool::<T>(0, ACTIVE);
+    let era = 1;
+    let snapshot = BondSnapshot {symbol: SYMBOL, era, pool, bond: 1_000_000, unbond: 500_000,
+        last_voter: account("voter", 0, SEED), active: ACTIVE, bond_state};
+    let shot_id = <T::Hashing as Hash>::hash_of(&snapshot);
+    <Snapshots<T>>::insert(SYMBOL, &shot_id, snapshot);
+    <EraSnapShots<T>>::insert(SYMBOL, era, vec![shot_id.clone()]);
+    <CurrentEraSnapShots<T>>::insert(SYMBOL, vec![shot_id.clone()]);
+    <ChainEras>::insert(SYMBOL, era);
+    shot_id
+}
+
+fn prepare_active_report<T: Trait>() {
+    rtoken_rate::Module::<T>::set_rate(SYMBOL, 0, 0);
+    <Receiver<T>>::put(account::<T::AccountId>("receiver", 0, SEED));
+}
+
+benchmarks! {
+    _ { }
+
+    set_commission {
+    }: _(RawOrigin::Root, 100_000_000)
+    verify {
+        assert_eq!(Commission::get(), Perbill::from_parts(100_000_000));
+    }
+
+    add_new_pool {
+        let pool = pool_of::<T>(0);
+    }: _(RawOrigin::Root, SYMBOL, pool.clone())
+    verify {
+        assert!(Pools::get(SYMBOL).contains(&pool));
+    }
+
+    remove_pool {
+        let pool = add_bonded_pool::<T>(0, 0);
+    }: _(RawOrigin::Root, SYMBOL, pool.clone())
+    verify {
+        assert!(!BondedPools::get(SYMBOL).contains(&pool));
+    }
+
+    set_receiver {
+        let receiver: T::AccountId = account("receiver", 0, SEED);
+    }: _(RawOrigin::Root, receiver.clone())
+    verify {
+        assert_eq!(<Receiver<T>>::get(), Some(receiver));
+    }
+
+    set_era_unbond_limit {
+    }: _(RawOrigin::Root, SYMBOL, 200)
+    verify {
+        assert_eq!(EraUnbondLimit::get(SYMBOL), 200);
+    }
+
+    set_init_bond {
+        let pool = pool_of::<T>(0);
+        <Pools>::append(SYMBOL, &pool);
+        let receiver: T::AccountId = account("receiver", 0, SEED);
+    }: _(RawOrigin::Root, SYMBOL, pool.clone(), receiver, ACTIVE)
+    verify {
+        assert!(BondedPools::get(SYMBOL).contains(&pool));
+    }
+
+    set_chain_bonding_duration {
+    }: _(RawOrigin::Root, SYMBOL, 28)
+    verify {
+        assert_eq!(ChainBondingDuration::get(SYMBOL), Some(28));
+    }
+
+    add_sub_accounts_and_threshold {
+        let pool = pool_of::<T>(0);
+        <Pools>::append(SYMBOL, &pool);
+        let sub_accounts: Vec<Vec<u8>> = (0..10).map(|i| account::<T::AccountId>("sub", i, SEED).encode()).collect();
+    }: _(RawOrigin::Root, SYMBOL, pool.clone(), sub_accounts, 6)
+    verify {
+        assert_eq!(MultiThresholds::get(SYMBOL, &pool), Some(6));
+    }
+
+    clear_current_era_snap_shots {
+        add_snapshot::<T>(PoolBondState::EraUpdated);
+    }: _(RawOrigin::Root, SYMBOL)
+    verify {
+        assert_eq!(<CurrentEraSnapShots<T>>::get(SYMBOL), Some(vec![]));
+    }
+
+    set_least_bond {
+    }: _(RawOrigin::Root, SYMBOL, 1_000)
+    verify {
+        assert_eq!(LeastBond::get(SYMBOL), Some(1_000));
+    }
+
+    set_chain_era {
+        for i in 0..MAX_POOLS {
+            add_bonded_pool::<T>(i, ACTIVE);
+        }
+        <LastVoter<T>>::insert(SYMBOL, account::<T::AccountId>("voter", 0, SEED));
+        <ChainEras>::insert(SYMBOL, 1);
+        let origin = T::VoterOrigin::successful_origin();
+    }: _(origin, SYMBOL, 2)
+    verify {
+        assert_eq!(ChainEras::get(SYMBOL), Some(2));
+        assert_eq!(<CurrentEraSnapShots<T>>::get(SYMBOL).unwrap_or_default().len(), MAX_POOLS as usize);
+    }
+
+    bond_report {
+        let shot_id = add_snapshot::<T>(PoolBondState::EraUpdated);
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone())
+    verify {
+        assert!(<Snapshots<T>>::get(SYMBOL, &shot_id).unwrap().bond_reported());
+    }
+
+    new_bond_report {
+        let shot_id = add_snapshot::<T>(PoolBondState::EraUpdated);
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone(), BondAction::InterDeduct)
+    verify {
+        assert!(<Snapshots<T>>::get(SYMBOL, &shot_id).unwrap().bond_reported());
+    }
+
+    bond_and_report_active {
+        let shot_id = add_snapshot::<T>(PoolBondState::EraUpdated);
+        prepare_active_report::<T>();
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone(), BondAction::InterDeduct, ACTIVE, 1_000_000)
+    verify {
+        assert_eq!(<EraSnapShots<T>>::get(SYMBOL, 1), Some(vec![]));
+    }
+
+    active_report {
+        let shot_id = add_snapshot::<T>(PoolBondState::BondReported);
+        prepare_active_report::<T>();
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone(), ACTIVE + 1_000_000)
+    verify {
+        assert_eq!(<EraSnapShots<T>>::get(SYMBOL, 1), Some(vec![]));
+    }
+
+    new_active_report {
+        let shot_id = add_snapshot::<T>(PoolBondState::BondReported);
+        prepare_active_report::<T>();
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone(), ACTIVE, 1_000_000)
+    verify {
+        assert_eq!(<EraSnapShots<T>>::get(SYMBOL, 1), Some(vec![]));
+    }
+
+    withdraw_report {
+        let shot_id = add_snapshot::<T>(PoolBondState::ActiveReported);
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone())
+    verify {
+        assert_eq!(<Snapshots<T>>::get(SYMBOL, &shot_id).unwrap().bond_state, PoolBondState::WithdrawReported);
+    }
+
+    transfer_report {
+        let shot_id = add_snapshot::<T>(PoolBondState::WithdrawReported);
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone())
+    verify {
+        assert!(<Snapshots<T>>::get(SYMBOL, &shot_id).unwrap().continuable());
+    }
+
+    migrate_pool {
+        let old_pool = add_bonded_pool::<T>(0, ACTIVE);
+        let new_pool = add_bonded_pool::<T>(1, 0);
+    }: _(RawOrigin::Root, SYMBOL, old_pool, new_pool.clone())
+    verify {
+        assert_eq!(BondPipelines::get(SYMBOL, &new_pool).unwrap_or_default().active, ACTIVE);
+    }
+
+    bond_and_report_active_with_pending_value {
+        let shot_id = add_snapshot::<T>(PoolBondState::EraUpdated);
+        prepare_active_report::<T>();
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone(), BondAction::InterDeduct, ACTIVE + 1_000_000, 1_000, 1_000)
+    verify {
+        assert_eq!(PendingStake::get(SYMBOL), Some(1_000));
+    }
+
+    correct_pool_active {
+        let shot_id = add_snapshot::<T>(PoolBondState::TransferReported);
+        let snap = <Snapshots<T>>::get(SYMBOL, &shot_id).unwrap();
+        <EraSnapShots<T>>::insert(SYMBOL, snap.era, Vec::<T::Hash>::new());
+        <TotalExpectedActive>::insert(SYMBOL, snap.era, ACTIVE);
+        <MaxActiveCorrection>::insert(SYMBOL, Perbill::from_percent(10));
+        let active = ACTIVE + ACTIVE / 20;
+        let origin = <T as Trait>::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, snap.era, snap.pool.clone(), active, T::Hash::default())
+    verify {
+        assert_eq!(BondPipelines::get(SYMBOL, &snap.pool).unwrap_or_default().active, active);
+        assert_eq!(TotalExpectedActive::get(SYMBOL, snap.era), Some(active));
+    }
+
+    set_active_change_rate_limit {
+    }: _(RawOrigin::Root, SYMBOL, 20_000_000)
+    verify {
+        assert_eq!(ActiveChangeRateLimit::get(SYMBOL), Perbill::from_parts(20_000_000));
+    }
+
+    set_snapshot_timeout {
+        let timeout: T::BlockNumber = 100u32.into();
+    }: _(RawOrigin::Root, SYMBOL, timeout)
+    verify {
+        assert_eq!(<SnapshotTimeout<T>>::get(SYMBOL), timeout);
+    }
+
+    fail_snapshot {
+        let shot_id = add_snapshot::<T>(PoolBondState::BondReported);
+        <SnapshotTimeout<T>>::insert(SYMBOL, T::BlockNumber::from(100u32));
+        <SnapshotDeductions<T>>::insert(SYMBOL, &shot_id, (1_000_000u128, 500_000u128));
+        frame_system::Module::<T>::set_block_number(100u32.into());
+        prepare_active_report::<T>();
+    }: _(RawOrigin::Root, SYMBOL, shot_id.clone(), true)
+    verify {
+        assert_eq!(<Snapshots<T>>::get(SYMBOL, &shot_id).unwrap().bond_state, PoolBondState::Failed);
+        assert!(CurrentEraSnapShots::<T>::get(SYMBOL).unwrap_or_default().is_empty());
+    }
+
+    set_max_active_correction {
+        let origin = <T as Trait>::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, 50_000_000)
+    verify {
+        assert_eq!(MaxActiveCorrection::get(SYMBOL), Perbill::from_parts(50_000_000));
+    }
+}
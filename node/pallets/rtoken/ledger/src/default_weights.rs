@@ -0,0 +1,84 @@
+//! Default weights for the RTokenLedger Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn set_commission() -> Weight {
+        1_000_000 as Weight
+    }
+    fn add_new_pool() -> Weight {
+        1_000_000 as Weight
+    }
+    fn remove_pool() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_receiver() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_era_unbond_limit() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_init_bond() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_chain_bonding_duration() -> Weight {
+        1_000_000 as Weight
+    }
+    fn add_sub_accounts_and_threshold() -> Weight {
+        1_000_000 as Weight
+    }
+    fn clear_current_era_snap_shots() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_least_bond() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_chain_era() -> Weight {
+        1_000_000 as Weight
+    }
+    fn bond_report() -> Weight {
+        1_000_000 as Weight
+    }
+    fn new_bond_report() -> Weight {
+        1_000_000 as Weight
+    }
+    fn bond_and_report_active() -> Weight {
+        1_000_000 as Weight
+    }
+    fn active_report() -> Weight {
+        1_000_000 as Weight
+    }
+    fn new_active_report() -> Weight {
+        1_000_000 as Weight
+    }
+    fn withdraw_report() -> Weight {
+        1_000_000 as Weight
+    }
+    fn transfer_report() -> Weight {
+        1_000_000 as Weight
+    }
+    fn migrate_pool() -> Weight {
+        1_000_000 as Weight
+    }
+    fn bond_and_report_active_with_pending_value() -> Weight {
+        1_000_000 as Weight
+    }
+    fn correct_pool_active() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_active_change_rate_limit() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_snapshot_timeout() -> Weight {
+        1_000_000 as Weight
+    }
+    fn fail_snapshot() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_max_active_correction() -> Weight {
+        1_000_000 as Weight
+    }
+}
@@ -7,19 +7,60 @@ use frame_support::{
     traits::{
         EnsureOrigin,
     },
+    weights::Weight,
+    transactional,
 };
 use sp_runtime::{
     Perbill,
-    traits::Hash,
+    traits::{Hash, Saturating, Zero},
 };
 use frame_system::{self as system, ensure_root};
 use rtoken_balances::{traits::{Currency as RCurrency}};
 use node_primitives::{RSymbol};
+use rtoken_rate::RateType;
+use rtoken_registry as registry;
 
 pub mod models;
 pub use models::*;
 
-pub trait Trait: system::Trait + rtoken_rate::Trait {
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub trait WeightInfo {
+    fn set_commission() -> Weight;
+    fn add_new_pool() -> Weight;
+    fn remove_pool() -> Weight;
+    fn set_receiver() -> Weight;
+    fn set_era_unbond_limit() -> Weight;
+    fn set_init_bond() -> Weight;
+    fn set_chain_bonding_duration() -> Weight;
+    fn add_sub_accounts_and_threshold() -> Weight;
+    fn clear_current_era_snap_shots() -> Weight;
+    fn set_least_bond() -> Weight;
+    fn set_chain_era() -> Weight;
+    fn bond_report() -> Weight;
+    fn new_bond_report() -> Weight;
+    fn bond_and_report_active() -> Weight;
+    fn active_report() -> Weight;
+    fn new_active_report() -> Weight;
+    fn withdraw_report() -> Weight;
+    fn transfer_report() -> Weight;
+    fn migrate_pool() -> Weight;
+    fn bond_and_report_active_with_pending_value() -> Weight;
+    fn correct_pool_active() -> Weight;
+    fn set_active_change_rate_limit() -> Weight;
+    fn set_snapshot_timeout() -> Weight;
+    fn fail_snapshot() -> Weight;
+    fn set_max_active_correction() -> Weight;
+}
+
+pub trait Trait: system::Trait + rtoken_rate::Trait + registry::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     /// currency of rtoken
@@ -27,12 +68,19 @@ pub trait Trait: system::Trait + rtoken_rate::Trait {
 
     /// Specifies the origin check provided by the voter for calls that can only be called by the votes pallet
     type VoterOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+    /// Origin allowed to correct the active of pools, eg: council
+    type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event! {
     pub enum Event<T> where
         Hash = <T as system::Trait>::Hash,
-        AccountId = <T as system::Trait>::AccountId
+        AccountId = <T as system::Trait>::AccountId,
+        BlockNumber = <T as system::Trait>::BlockNumber
     {
         /// symbol, old_era, new_era
         EraUpdated(RSymbol, u32, u32),
@@ -54,6 +102,16 @@ decl_event! {
         WithdrawReported(RSymbol, Hash, AccountId),
         /// transfer reported
         TransferReported(RSymbol, Hash),
+        /// symbol, blocks after which a pending snapshot may be failed
+        SnapshotTimeoutSet(RSymbol, BlockNumber),
+        /// snapshot failed: symbol, shot_id, pool, restored bond, restored unbond
+        SnapshotFailed(RSymbol, Hash, Vec<u8>, u128, u128),
+        /// no snapshot pending any more, set_chain_era may go on: symbol, era
+        EraContinuable(RSymbol, u32),
+        /// active of pool corrected: symbol, era, pool, old active, new active, total expected active, rate, justification
+        PoolActiveCorrected(RSymbol, u32, Vec<u8>, u128, u128, u128, RateType, Hash),
+        /// symbol, max share of its active a pool may be corrected by
+        MaxActiveCorrectionSet(RSymbol, Perbill),
     }
 }
 
@@ -103,6 +161,22 @@ decl_error! {
         ActionNotSupported,
         /// active not match
         ActiveNotMatch,
+        /// snapshot not pending in the current era
+        SnapShotNotPending,
+        /// snapshot timeout not set
+        SnapShotTimeoutNotSet,
+        /// snapshot not timed out yet
+        SnapShotNotTimedOut,
+        /// era is not the current era
+        EraNotCurrent,
+        /// active of era not reported by all pools
+        EraNotReported,
+        /// correction bigger than the max active correction
+        CorrectionTooLarge,
+        /// rate of symbol paused until governance confirms it
+        RatePaused,
+        /// only root may roll back a snapshot the bond of which was reported
+        RollbackNotAllowed,
     }
 }
 
@@ -149,6 +223,22 @@ decl_storage! {
         pub PendingReward get(fn pending_reward): map hasher(blake2_128_concat) RSymbol => Option<u128>;
         /// active change rate limit
         pub ActiveChangeRateLimit get(fn active_change_rate_limit): map hasher(blake2_128_concat) RSymbol => Perbill = Perbill::from_percent(1);
+
+        /// blocks after which a pending snapshot may be failed, zero means never
+        pub SnapshotTimeout get(fn snapshot_timeout): map hasher(blake2_128_concat) RSymbol => T::BlockNumber;
+        /// block at which a pending snapshot was taken, snapshots older than this storage have none
+        pub SnapshotCreatedAt get(fn snapshot_created_at): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::Hash => Option<T::BlockNumber>;
+        /// (bond, unbond) taken out of the pipeline by the bond report of a pending snapshot
+        pub SnapshotDeductions get(fn snapshot_deductions): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::Hash => Option<(u128, u128)>;
+
+        /// max share of its active a pool may be corrected by, zero means no correction
+        pub MaxActiveCorrection get(fn max_active_correction): map hasher(blake2_128_concat) RSymbol => Perbill;
+        /// active of a pool before its first correction in an era, (symbol, era) => pool => active
+        pub ActiveBeforeCorrection get(fn active_before_correction):
+            double_map hasher(blake2_128_concat) (RSymbol, u32), hasher(blake2_128_concat) Vec<u8> => Option<u128>;
+
+        /// all snapshots taken in an era, reported or not, kept until the era is pruned
+        pub EraSnapshotIds get(fn era_snapshot_ids): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) u32 => Vec<T::Hash>;
     }
 }
 
@@ -159,7 +249,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// Update commission of staking rewards
-		#[weight = 1_000_000]
+		#[weight = <T as Trait>::WeightInfo::set_commission()]
 		fn set_commission(origin, new_part: u32) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -174,9 +264,10 @@ decl_module! {
         }
 
         /// add new pool
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::add_new_pool()]
         pub fn add_new_pool(origin, symbol: RSymbol, pool: Vec<u8>) -> DispatchResult {
             ensure_root(origin)?;
+            registry::Module::<T>::ensure_enabled(symbol)?;
             let mut pools = Self::pools(symbol);
             ensure!(!pools.contains(&pool), Error::<T>::PoolAlreadyAdded);
             pools.push(pool.clone());
@@ -187,7 +278,7 @@ decl_module! {
         }
 
         /// remove pool
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::remove_pool()]
         pub fn remove_pool(origin, symbol: RSymbol, pool: Vec<u8>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -212,7 +303,7 @@ decl_module! {
         }
 
         /// set receiver
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_receiver()]
         pub fn set_receiver(origin, new_receiver: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             <Receiver<T>>::put(new_receiver);
@@ -220,7 +311,7 @@ decl_module! {
         }
 
         /// set era unbond limit
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_era_unbond_limit()]
         pub fn set_era_unbond_limit(origin, symbol: RSymbol, limit: u16) -> DispatchResult {
             ensure_root(origin)?;
             EraUnbondLimit::insert(symbol, limit);
@@ -228,7 +319,7 @@ decl_module! {
         }
 
         /// init bond pool
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_init_bond()]
         pub fn set_init_bond(origin, symbol: RSymbol, pool: Vec<u8>, bond_receiver: T::AccountId, amount: u128) -> DispatchResult {
             ensure_root(origin)?;
             let pools = Self::pools(symbol);
@@ -251,7 +342,7 @@ decl_module! {
         }
 
         /// set chain bonding duration
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_chain_bonding_duration()]
         pub fn set_chain_bonding_duration(origin, symbol: RSymbol, new_bonding_duration: u32) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(new_bonding_duration > 0, Error::<T>::NewBondingDurationZero);
@@ -264,7 +355,7 @@ decl_module! {
         }
 
         /// add sub accounts and threshold of a pool
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::add_sub_accounts_and_threshold()]
         pub fn add_sub_accounts_and_threshold(origin, symbol: RSymbol, pool: Vec<u8>, sub_accounts: Vec<Vec<u8>>, threshold: u16) -> DispatchResult {
             ensure_root(origin)?;
             let pools = Self::pools(symbol);
@@ -276,15 +367,18 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::clear_current_era_snap_shots()]
         pub fn clear_current_era_snap_shots(origin, symbol: RSymbol) -> DispatchResult {
             ensure_root(origin)?;
+            for shot_id in Self::current_era_snap_shots(symbol).unwrap_or_default() {
+                Self::settle_snapshot(symbol, &shot_id);
+            }
             let empty: Vec<T::Hash> = vec![];
             <CurrentEraSnapShots<T>>::insert(symbol, empty);
             Ok(())
         }
 
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_least_bond()]
         pub fn set_least_bond(origin, symbol: RSymbol, least: u128) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -293,7 +387,7 @@ decl_module! {
         }
 
         /// set chain era
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_chain_era()]
         pub fn set_chain_era(origin, symbol: RSymbol, new_era: u32) -> DispatchResult {
             T::VoterOrigin::ensure_origin(origin)?;
             let mut era_shots = Self::current_era_snap_shots(symbol).unwrap_or(vec![]);
@@ -307,6 +401,7 @@ decl_module! {
             let old_era = Self::chain_eras(symbol).unwrap_or(0);
             ensure!(old_era == 0 || old_era.saturating_add(1) == new_era, Error::<T>::EraSkipped);
 
+            let now = system::Module::<T>::block_number();
             let pools = Self::bonded_pools(symbol);
             for pool in pools {
                 let pipe = Self::bond_pipelines(symbol, &pool).unwrap_or_default();
@@ -314,11 +409,13 @@ decl_module! {
                 unbond: pipe.unbond, last_voter: voter.clone(), active: pipe.active, bond_state: PoolBondState::EraUpdated};
                 let shot_id = <T::Hashing as Hash>::hash_of(&snapshot);
                 <Snapshots<T>>::insert(symbol, &shot_id, snapshot.clone());
+                <SnapshotCreatedAt<T>>::insert(symbol, &shot_id, now);
                 era_shots.push(shot_id.clone());
                 Self::deposit_event(RawEvent::EraPoolUpdated(symbol, new_era, shot_id, voter.clone()));
             }
 
             <EraSnapShots<T>>::insert(symbol, new_era, &era_shots);
+            <EraSnapshotIds<T>>::insert(symbol, new_era, &era_shots);
             <CurrentEraSnapShots<T>>::insert(symbol, era_shots);
             <ChainEras>::insert(symbol, new_era);
             Self::deposit_event(RawEvent::EraUpdated(symbol, old_era, new_era));
@@ -326,7 +423,7 @@ decl_module! {
         }
 
         /// bond link success
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::bond_report()]
         pub fn bond_report(origin, symbol: RSymbol, shot_id: T::Hash) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
             let op_snap = Self::snap_shots(symbol, &shot_id);
@@ -336,8 +433,7 @@ decl_module! {
             ensure!(snap.era_updated(), Error::<T>::StateNotEraUpdated);
 
             let mut pipe = Self::bond_pipelines(symbol, &snap.pool).unwrap_or_default();
-            pipe.bond = pipe.bond.saturating_sub(snap.bond);
-            pipe.unbond = pipe.unbond.saturating_sub(snap.unbond);
+            Self::deduct_pipeline(symbol, &shot_id, &snap, &mut pipe, BondAction::BothBondUnbond);
 
             <BondPipelines>::insert(symbol, &snap.pool, pipe);
             snap.update_state(PoolBondState::BondReported);
@@ -348,27 +444,14 @@ decl_module! {
         }
 
         /// bond/unbond only report
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::new_bond_report()]
         pub fn new_bond_report(origin, symbol: RSymbol, shot_id: T::Hash, action: BondAction) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
             let mut snap = Self::snap_shots(symbol, &shot_id).ok_or(Error::<T>::SnapShotNotFound)?;
             ensure!(snap.era_updated(), Error::<T>::StateNotEraUpdated);
 
             let mut pipe = Self::bond_pipelines(symbol, &snap.pool).unwrap_or_default();
-            match action {
-                BondAction::BondOnly => pipe.bond = pipe.bond.saturating_sub(snap.bond),
-                BondAction::UnbondOnly => pipe.unbond = pipe.unbond.saturating_sub(snap.unbond),
-                BondAction::BothBondUnbond => {
-                    pipe.bond = pipe.bond.saturating_sub(snap.bond);
-                    pipe.unbond = pipe.unbond.saturating_sub(snap.unbond);
-                }
-                BondAction::EitherBondUnbond => (),
-                BondAction::InterDeduct => {
-                    let deduct = if snap.bond >= snap.unbond { snap.unbond } else { snap.bond };
-                    pipe.bond = pipe.bond.saturating_sub(deduct);
-                    pipe.unbond = pipe.unbond.saturating_sub(deduct);
-                }
-            }
+            Self::deduct_pipeline(symbol, &shot_id, &snap, &mut pipe, action);
 
             <BondPipelines>::insert(symbol, &snap.pool, pipe);
             snap.update_state(PoolBondState::BondReported);
@@ -379,7 +462,7 @@ decl_module! {
         }
 
         /// bond and report active
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::bond_and_report_active()]
         pub fn bond_and_report_active(origin, symbol: RSymbol, shot_id: T::Hash, action: BondAction, active: u128, reward: u128) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
             let mut snap = Self::snap_shots(symbol, &shot_id).ok_or(Error::<T>::SnapShotNotFound)?;
@@ -388,20 +471,7 @@ decl_module! {
 
             let mut pipe = Self::bond_pipelines(symbol, &snap.pool).unwrap_or_default();
             pipe.bond = pipe.bond.saturating_add(reward);
-            match action {
-                BondAction::BondOnly => pipe.bond = pipe.bond.saturating_sub(snap.bond),
-                BondAction::UnbondOnly => pipe.unbond = pipe.unbond.saturating_sub(snap.unbond),
-                BondAction::BothBondUnbond => {
-                    pipe.bond = pipe.bond.saturating_sub(snap.bond);
-                    pipe.unbond = pipe.unbond.saturating_sub(snap.unbond);
-                }
-                BondAction::EitherBondUnbond => (),
-                BondAction::InterDeduct => {
-                    let deduct = if snap.bond >= snap.unbond { snap.unbond } else { snap.bond };
-                    pipe.bond = pipe.bond.saturating_sub(deduct);
-                    pipe.unbond = pipe.unbond.saturating_sub(deduct);
-                }
-            }
+            Self::deduct_pipeline(symbol, &shot_id, &snap, &mut pipe, action);
 
             let receiver = Self::receiver().ok_or(Error::<T>::NoReceiver)?;
             let mut era_shots = Self::era_snap_shots(symbol, snap.era).unwrap_or(vec![]);
@@ -438,6 +508,7 @@ decl_module! {
                 snap.update_state(PoolBondState::WithdrawSkipped);
                 cur_era_shot.remove(cur_era_index);
                 <CurrentEraSnapShots<T>>::insert(symbol, cur_era_shot);
+                Self::settle_snapshot(symbol, &shot_id);
             }
 
             <Snapshots<T>>::insert(symbol, &shot_id, snap);
@@ -445,7 +516,7 @@ decl_module! {
         }
 
         /// set bond active of pool
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::active_report()]
         pub fn active_report(origin, symbol: RSymbol, shot_id: T::Hash, active: u128) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
 
@@ -498,6 +569,7 @@ decl_module! {
                 snap.update_state(PoolBondState::WithdrawSkipped);
                 cur_era_shot.remove(cur_era_index);
                 <CurrentEraSnapShots<T>>::insert(symbol, cur_era_shot);
+                Self::settle_snapshot(symbol, &shot_id);
             }
 
             <Snapshots<T>>::insert(symbol, &shot_id, snap);
@@ -505,7 +577,7 @@ decl_module! {
         }
 
         /// set bond active of pool with extra info
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::new_active_report()]
         pub fn new_active_report(origin, symbol: RSymbol, shot_id: T::Hash, staked: u128, unstaked: u128) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
 
@@ -560,6 +632,7 @@ decl_module! {
                 snap.update_state(PoolBondState::WithdrawSkipped);
                 cur_era_shot.remove(cur_era_index);
                 <CurrentEraSnapShots<T>>::insert(symbol, cur_era_shot);
+                Self::settle_snapshot(symbol, &shot_id);
             }
 
             <Snapshots<T>>::insert(symbol, &shot_id, snap);
@@ -567,7 +640,7 @@ decl_module! {
         }
 
         /// withdraw success
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::withdraw_report()]
         pub fn withdraw_report(origin, symbol: RSymbol, shot_id: T::Hash) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
 
@@ -584,14 +657,15 @@ decl_module! {
         }
 
         /// transfer success
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::transfer_report()]
         pub fn transfer_report(origin, symbol: RSymbol, shot_id: T::Hash) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
 
             let op_snap = Self::snap_shots(symbol, &shot_id);
             ensure!(op_snap.is_some(), Error::<T>::SnapShotNotFound);
             let mut snap = op_snap.unwrap();
-            ensure!(snap.withdraw_reported(), Error::<T>::StateNotWithdrawReported);
+            let chain_type = registry::Module::<T>::chain_type(symbol)?;
+            ensure!(snap.withdraw_reported(chain_type), Error::<T>::StateNotWithdrawReported);
 
             let mut cur_era_shot = Self::current_era_snap_shots(symbol).unwrap_or(vec![]);
             let op_index = cur_era_shot.iter().position(|shot| shot == &shot_id);
@@ -600,6 +674,7 @@ decl_module! {
             cur_era_shot.remove(cur_era_index);
 
             <CurrentEraSnapShots<T>>::insert(symbol, cur_era_shot);
+            Self::settle_snapshot(symbol, &shot_id);
             snap.update_state(PoolBondState::TransferReported);
             <Snapshots<T>>::insert(symbol, &shot_id, snap);
 
@@ -608,7 +683,7 @@ decl_module! {
         }
 
         /// migrate pool
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::migrate_pool()]
         pub fn migrate_pool(origin, symbol: RSymbol, old_pool: Vec<u8>, new_pool: Vec<u8>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -638,7 +713,7 @@ decl_module! {
         }
 
         /// bond and report active with pending value
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::bond_and_report_active_with_pending_value()]
         pub fn bond_and_report_active_with_pending_value(origin, symbol: RSymbol, shot_id: T::Hash, action: BondAction, active: u128, pending_stake: u128, pending_reward: u128) -> DispatchResult {
             Self::ensure_voter_or_admin(origin)?;
             let mut snap = Self::snap_shots(symbol, &shot_id).ok_or(Error::<T>::SnapShotNotFound)?;
@@ -646,20 +721,7 @@ decl_module! {
             ensure!(rtoken_rate::Rate::get(symbol).is_some(), Error::<T>::RateIsNone);
 
             let mut pipe = Self::bond_pipelines(symbol, &snap.pool).unwrap_or_default();
-            match action {
-                BondAction::BondOnly => pipe.bond = pipe.bond.saturating_sub(snap.bond),
-                BondAction::UnbondOnly => pipe.unbond = pipe.unbond.saturating_sub(snap.unbond),
-                BondAction::BothBondUnbond => {
-                    pipe.bond = pipe.bond.saturating_sub(snap.bond);
-                    pipe.unbond = pipe.unbond.saturating_sub(snap.unbond);
-                }
-                BondAction::EitherBondUnbond => (),
-                BondAction::InterDeduct => {
-                    let deduct = if snap.bond >= snap.unbond { snap.unbond } else { snap.bond };
-                    pipe.bond = pipe.bond.saturating_sub(deduct);
-                    pipe.unbond = pipe.unbond.saturating_sub(deduct);
-                }
-            }
+            Self::deduct_pipeline(symbol, &shot_id, &snap, &mut pipe, action);
 
             let receiver = Self::receiver().ok_or(Error::<T>::NoReceiver)?;
             let mut era_shots = Self::era_snap_shots(symbol, snap.era).unwrap_or(vec![]);
@@ -699,36 +761,52 @@ decl_module! {
                 snap.update_state(PoolBondState::WithdrawSkipped);
                 cur_era_shot.remove(cur_era_index);
                 <CurrentEraSnapShots<T>>::insert(symbol, cur_era_shot);
+                Self::settle_snapshot(symbol, &shot_id);
             }
 
             <Snapshots<T>>::insert(symbol, &shot_id, snap);
             Ok(())
         }
 
-        /// fix rsol rate
-        #[weight = 1_000_000]
-        pub fn fix_rsol_rate(origin, pool: Vec<u8>, active: u128) -> DispatchResult {
-            ensure_root(origin)?;
-
-            let symbol = RSymbol::RSOL;
-            let deal_era = 410;
+        /// correct the active of a pool in the current era, bounded by the max active correction
+        /// and the max rate change, `justification` is the hash of the off-chain reasoning
+        #[weight = <T as Trait>::WeightInfo::correct_pool_active()]
+        #[transactional]
+        pub fn correct_pool_active(origin, symbol: RSymbol, era: u32, pool: Vec<u8>, active: u128, justification: T::Hash) -> DispatchResult {
+            <T as Trait>::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Self::chain_eras(symbol).unwrap_or(0) == era, Error::<T>::EraNotCurrent);
+            ensure!(Self::era_snap_shots(symbol, era).unwrap_or(vec![]).is_empty(), Error::<T>::EraNotReported);
+            ensure!(Self::bonded_pools(symbol).contains(&pool), Error::<T>::PoolNotBonded);
 
-            ensure!(Self::chain_eras(symbol).unwrap_or(0) == deal_era, Error::<T>::ActiveAlreadySet);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
 
             let mut pipe = Self::bond_pipelines(symbol, &pool).ok_or(Error::<T>::PoolNotFound)?;
-            pipe.active = active;
-
+            let old_active = pipe.active;
+            // corrections of a pool in an era add up, all of them are bounded by the active before the first
+            let reported_active = Self::active_before_correction((symbol, era), &pool).unwrap_or(old_active);
+            let change = if active > reported_active { active - reported_active } else { reported_active - active };
+            ensure!(change <= Self::max_active_correction(symbol) * reported_active, Error::<T>::CorrectionTooLarge);
+
+            let total_expected_active = Self::total_expected_active(symbol, era).unwrap_or(0)
+                .saturating_sub(old_active)
+                .saturating_add(active);
             let rbalance = T::RCurrency::total_issuance(symbol);
+            let rate = rtoken_rate::Module::<T>::set_rate(symbol, total_expected_active, rbalance);
+            // a correction beyond the max rate change is undone, the rate is for governance to confirm
+            ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
+            rtoken_rate::EraRate::insert(symbol, era, rate);
 
-            let rate = rtoken_rate::Module::<T>::set_rate(symbol, active, rbalance);
-            rtoken_rate::EraRate::insert(symbol, deal_era, rate);
+            pipe.active = active;
             <BondPipelines>::insert(symbol, &pool, pipe);
+            <TotalExpectedActive>::insert(symbol, era, total_expected_active);
+            <ActiveBeforeCorrection>::insert((symbol, era), &pool, reported_active);
 
+            Self::deposit_event(RawEvent::PoolActiveCorrected(symbol, era, pool, old_active, active, total_expected_active, rate, justification));
             Ok(())
         }
 
         /// set active change rate limit
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_active_change_rate_limit()]
 		fn set_active_change_rate_limit(origin, symbol: RSymbol, new_part: u32) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(new_part < 1_000_000_000, Error::<T>::OverFlow);
@@ -738,7 +816,87 @@ decl_module! {
 			Ok(())
         }
 
-        
+        /// set the blocks after which a pending snapshot may be failed, zero disables it
+        #[weight = <T as Trait>::WeightInfo::set_snapshot_timeout()]
+        pub fn set_snapshot_timeout(origin, symbol: RSymbol, timeout: T::BlockNumber) -> DispatchResult {
+            ensure_root(origin)?;
+            <SnapshotTimeout<T>>::insert(symbol, timeout);
+
+            Self::deposit_event(RawEvent::SnapshotTimeoutSet(symbol, timeout));
+            Ok(())
+        }
+
+        /// give up a snapshot stuck past the timeout, with `rollback` the bond and unbond
+        /// taken out by its bond report go back to the pipeline to be dealt in the next era.
+        /// The bond report means relayers executed them on the chain already, so only root,
+        /// knowing they did not go through, may roll a reported snapshot back
+        #[weight = <T as Trait>::WeightInfo::fail_snapshot()]
+        pub fn fail_snapshot(origin, symbol: RSymbol, shot_id: T::Hash, rollback: bool) -> DispatchResult {
+            let is_root = ensure_root(origin.clone()).is_ok();
+            if !is_root {
+                T::VoterOrigin::ensure_origin(origin)?;
+            }
+            let mut snap = Self::snap_shots(symbol, &shot_id).ok_or(Error::<T>::SnapShotNotFound)?;
+
+            let mut cur_era_shot = Self::current_era_snap_shots(symbol).unwrap_or(vec![]);
+            let cur_era_index = cur_era_shot.iter().position(|shot| shot == &shot_id).ok_or(Error::<T>::SnapShotNotPending)?;
+
+            let timeout = Self::snapshot_timeout(symbol);
+            ensure!(!timeout.is_zero(), Error::<T>::SnapShotTimeoutNotSet);
+            let created_at = Self::snapshot_created_at(symbol, &shot_id).unwrap_or_default();
+            let now = system::Module::<T>::block_number();
+            ensure!(now >= created_at.saturating_add(timeout), Error::<T>::SnapShotNotTimedOut);
+            ensure!(!rollback || is_root || snap.bond_state == PoolBondState::EraUpdated, Error::<T>::RollbackNotAllowed);
+
+            let mut pipe = Self::bond_pipelines(symbol, &snap.pool).unwrap_or_default();
+            let (bond, unbond) = if rollback {
+                Self::snapshot_deductions(symbol, &shot_id).unwrap_or((0, 0))
+            } else {
+                (0, 0)
+            };
+            pipe.bond = pipe.bond.saturating_add(bond);
+            pipe.unbond = pipe.unbond.saturating_add(unbond);
+
+            // active never reported, the era rate goes on with the last known active of the pool
+            let mut era_shots = Self::era_snap_shots(symbol, snap.era).unwrap_or(vec![]);
+            if let Some(era_index) = era_shots.iter().position(|shot| shot == &shot_id) {
+                let total_expected_active = Self::total_expected_active(symbol, snap.era).unwrap_or(0).saturating_add(pipe.active);
+                era_shots.remove(era_index);
+                if era_shots.is_empty() {
+                    let rbalance = T::RCurrency::total_issuance(symbol);
+                    let rate = rtoken_rate::Module::<T>::set_rate(symbol, total_expected_active, rbalance);
+                    rtoken_rate::EraRate::insert(symbol, snap.era, rate);
+                }
+                <EraSnapShots<T>>::insert(symbol, snap.era, era_shots);
+                <TotalExpectedActive>::insert(symbol, snap.era, total_expected_active);
+            }
+
+            <BondPipelines>::insert(symbol, &snap.pool, pipe);
+            cur_era_shot.remove(cur_era_index);
+            let continuable = cur_era_shot.is_empty();
+            <CurrentEraSnapShots<T>>::insert(symbol, cur_era_shot);
+            Self::settle_snapshot(symbol, &shot_id);
+            snap.update_state(PoolBondState::Failed);
+            <Snapshots<T>>::insert(symbol, &shot_id, snap.clone());
+
+            Self::deposit_event(RawEvent::SnapshotFailed(symbol, shot_id, snap.pool, bond, unbond));
+            if continuable {
+                Self::deposit_event(RawEvent::EraContinuable(symbol, snap.era));
+            }
+            Ok(())
+        }
+
+        /// set the max share of its active a pool may be corrected by
+        #[weight = <T as Trait>::WeightInfo::set_max_active_correction()]
+        pub fn set_max_active_correction(origin, symbol: RSymbol, new_part: u32) -> DispatchResult {
+            <T as Trait>::AdminOrigin::ensure_origin(origin)?;
+            ensure!(new_part <= 1_000_000_000, Error::<T>::OverFlow);
+
+            let limit = Perbill::from_parts(new_part);
+            <MaxActiveCorrection>::insert(symbol, limit);
+            Self::deposit_event(RawEvent::MaxActiveCorrectionSet(symbol, limit));
+            Ok(())
+        }
     }
 }
 
@@ -750,6 +908,65 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// takes the deduction of `action` out of the pipeline and keeps it for a rollback
+    fn deduct_pipeline(symbol: RSymbol, shot_id: &T::Hash, snap: &BondSnapshot<T::AccountId>, pipe: &mut LinkChunk, action: BondAction) {
+        let (bond, unbond) = action.deduction(snap.bond, snap.unbond);
+        let bond = bond.min(pipe.bond);
+        let unbond = unbond.min(pipe.unbond);
+        pipe.bond = pipe.bond - bond;
+        pipe.unbond = pipe.unbond - unbond;
+        <SnapshotDeductions<T>>::insert(symbol, shot_id, (bond, unbond));
+    }
+
+    /// drops the recovery records of a snapshot which is no longer pending
+    fn settle_snapshot(symbol: RSymbol, shot_id: &T::Hash) {
+        <SnapshotCreatedAt<T>>::remove(symbol, shot_id);
+        <SnapshotDeductions<T>>::remove(symbol, shot_id);
+    }
+
+    /// snapshots taken in an era, until the era is pruned
+    pub fn era_snapshots(symbol: RSymbol, era: u32) -> Vec<BondSnapshot<T::AccountId>> {
+        Self::era_snapshot_ids(symbol, era).iter()
+            .filter_map(|shot_id| Self::snap_shots(symbol, shot_id))
+            .collect()
+    }
+
+    /// drops up to `limit` snapshots of a past era, returns the number dropped and whether the era is done
+    pub fn prune_era_snapshots(symbol: RSymbol, era: u32, limit: u32) -> (u32, bool) {
+        let mut shot_ids = Self::era_snapshot_ids(symbol, era);
+        let count = shot_ids.len().min(limit as usize);
+        for shot_id in shot_ids.drain(..count) {
+            <Snapshots<T>>::remove(symbol, &shot_id);
+            Self::settle_snapshot(symbol, &shot_id);
+        }
+
+        if !shot_ids.is_empty() {
+            <EraSnapshotIds<T>>::insert(symbol, era, shot_ids);
+            return (count as u32, false)
+        }
+        <EraSnapshotIds<T>>::remove(symbol, era);
+        <EraSnapShots<T>>::remove(symbol, era);
+        <ActiveBeforeCorrection>::remove_prefix((symbol, era));
+        (count as u32, true)
+    }
+
+    /// bond pipelines of all bonded pools of a symbol
+    pub fn pool_pipelines(symbol: RSymbol) -> Vec<(Vec<u8>, LinkChunk)> {
+        Self::bonded_pools(symbol).into_iter()
+            .map(|pool| {
+                let pipe = Self::bond_pipelines(symbol, &pool).unwrap_or_default();
+                (pool, pipe)
+            })
+            .collect()
+    }
+
+    /// snapshots of the current era which are not yet continuable
+    pub fn pending_snapshots(symbol: RSymbol) -> Vec<(T::Hash, BondSnapshot<T::AccountId>)> {
+        Self::current_era_snap_shots(symbol).unwrap_or_default().into_iter()
+            .filter_map(|shot_id| Self::snap_shots(symbol, &shot_id).map(|snap| (shot_id, snap)))
+            .collect()
+    }
+
     pub fn check_active(symbol: RSymbol, snap_active: u128, report_active: u128) -> DispatchResult {
         if snap_active == 0 {
             return Ok(())
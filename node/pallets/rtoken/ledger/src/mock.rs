@@ -0,0 +1,127 @@
+use crate as rtoken_ledger;
+use crate::{Module, Trait};
+use frame_support::{
+    impl_outer_event, impl_outer_origin, parameter_types,
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+use node_primitives::RSymbol;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        frame_system<T>,
+        rtoken_registry,
+        rtoken_rate,
+        rtoken_balances<T>,
+        rtoken_ledger<T>,
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Test;
+
+impl frame_system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = ();
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+}
+
+impl Trait for Test {
+    type Event = TestEvent;
+    type RCurrency = RBalances;
+    // any signed account stands for the votes pallet
+    type VoterOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_rate::Trait for Test {
+    type Event = TestEvent;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_registry::Trait for Test {
+    type Event = TestEvent;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_balances::Trait for Test {
+    type Event = TestEvent;
+}
+
+pub const SYMBOL: RSymbol = RSymbol::RDOT;
+pub const VOTER: u64 = 1;
+
+pub fn pool() -> Vec<u8> {
+    b"pool".to_vec()
+}
+
+/// SYMBOL with `pool` bonded with an active of 100, at block 1
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    rtoken_registry::GenesisConfig {
+        assets: rtoken_registry::builtin_assets(),
+    }
+    .assimilate_storage::<Test>(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        RTokenLedger::add_new_pool(Origin::root(), SYMBOL, pool()).unwrap();
+        RTokenLedger::set_init_bond(Origin::root(), SYMBOL, pool(), 2, 100).unwrap();
+        crate::LastVoter::<Test>::insert(SYMBOL, VOTER);
+    });
+    ext
+}
+
+pub fn last_event() -> TestEvent {
+    System::events().pop().map(|e| e.event).expect("Event expected")
+}
+
+pub type RTokenLedger = Module<Test>;
+pub type System = frame_system::Module<Test>;
+pub type RBalances = rtoken_balances::Module<Test>;
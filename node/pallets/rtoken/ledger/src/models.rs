@@ -2,6 +2,8 @@ use sp_std::prelude::*;
 use codec::{Decode, Encode};
 use sp_runtime::RuntimeDebug;
 use node_primitives::{RSymbol, ChainType};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 pub struct LinkChunk {
@@ -56,9 +58,10 @@ impl<A> BondSnapshot<A> {
         self.bond_state == PoolBondState::ActiveReported
     }
 
-    pub fn withdraw_reported(&self) -> bool {
+    /// tendermint chains withdraw together with the active report, `chain_type` comes from the registry
+    pub fn withdraw_reported(&self, chain_type: ChainType) -> bool {
         self.bond_state == PoolBondState::WithdrawReported ||
-        (self.symbol.chain_type() == ChainType::Tendermint && self.active_reported()) ||
+        (chain_type == ChainType::Tendermint && self.active_reported()) ||
         (self.symbol == RSymbol::RBNB && self.active_reported())
     }
 
@@ -67,7 +70,8 @@ impl<A> BondSnapshot<A> {
     }
 
     pub fn continuable(&self) -> bool {
-        self.bond_state == PoolBondState::WithdrawSkipped || self.bond_state == PoolBondState::TransferReported
+        self.bond_state == PoolBondState::WithdrawSkipped || self.bond_state == PoolBondState::TransferReported ||
+        self.bond_state == PoolBondState::Failed
     }
 }
 
@@ -79,6 +83,7 @@ pub struct Unbonding<AccountId> {
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PoolBondState {
     /// era updated
     EraUpdated,
@@ -92,6 +97,8 @@ pub enum PoolBondState {
     WithdrawReported,
     /// transfer reported
     TransferReported,
+    /// stuck past the snapshot timeout and given up
+    Failed,
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
@@ -108,4 +115,20 @@ pub enum BondAction {
     InterDeduct,
 }
 
+impl BondAction {
+    /// (bond, unbond) taken out of the pipeline for a snapshot with `bond` and `unbond`
+    pub fn deduction(&self, bond: u128, unbond: u128) -> (u128, u128) {
+        match self {
+            BondAction::BondOnly => (bond, 0),
+            BondAction::UnbondOnly => (0, unbond),
+            BondAction::BothBondUnbond => (bond, unbond),
+            BondAction::EitherBondUnbond => (0, 0),
+            BondAction::InterDeduct => {
+                let deduct = if bond >= unbond { unbond } else { bond };
+                (deduct, deduct)
+            }
+        }
+    }
+}
+
 
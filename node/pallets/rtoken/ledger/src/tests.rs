@@ -0,0 +1,155 @@
+use super::*;
+use super::mock::*;
+use frame_support::{assert_ok, assert_noop};
+use sp_core::H256;
+
+/// takes the snapshot of era 1 with a pipeline of bond 30 and unbond 10, and reports its bond
+fn bond_reported_snapshot() -> H256 {
+    <BondPipelines>::insert(SYMBOL, pool(), LinkChunk { bond: 30, unbond: 10, active: 100 });
+    assert_ok!(RTokenLedger::set_chain_era(Origin::signed(VOTER), SYMBOL, 1));
+    let shot_id = RTokenLedger::current_era_snap_shots(SYMBOL).unwrap()[0];
+    assert_ok!(RTokenLedger::bond_report(Origin::signed(VOTER), SYMBOL, shot_id));
+    assert_eq!(RTokenLedger::bond_pipelines(SYMBOL, pool()), Some(LinkChunk { bond: 0, unbond: 0, active: 100 }));
+    assert_eq!(RTokenLedger::snapshot_deductions(SYMBOL, shot_id), Some((30, 10)));
+
+    // bonded by users while the snapshot is pending
+    <BondPipelines>::mutate(SYMBOL, pool(), |pipe| pipe.as_mut().unwrap().bond += 5);
+    shot_id
+}
+
+#[test]
+fn fail_snapshot_should_wait_for_timeout() {
+    new_test_ext().execute_with(|| {
+        let shot_id = bond_reported_snapshot();
+        assert_noop!(
+            RTokenLedger::fail_snapshot(Origin::signed(VOTER), SYMBOL, H256::repeat_byte(1), true),
+            Error::<Test>::SnapShotNotFound
+        );
+        assert_noop!(
+            RTokenLedger::fail_snapshot(Origin::signed(VOTER), SYMBOL, shot_id, true),
+            Error::<Test>::SnapShotTimeoutNotSet
+        );
+
+        assert_ok!(RTokenLedger::set_snapshot_timeout(Origin::root(), SYMBOL, 10));
+        System::set_block_number(10);
+        assert_noop!(
+            RTokenLedger::fail_snapshot(Origin::signed(VOTER), SYMBOL, shot_id, true),
+            Error::<Test>::SnapShotNotTimedOut
+        );
+    });
+}
+
+#[test]
+fn fail_snapshot_with_rollback_should_restore_pipeline() {
+    new_test_ext().execute_with(|| {
+        let shot_id = bond_reported_snapshot();
+        assert_ok!(RTokenLedger::set_snapshot_timeout(Origin::root(), SYMBOL, 10));
+        System::set_block_number(11);
+
+        // the bond report says relayers executed the deductions, voters may not deal them again
+        assert_noop!(
+            RTokenLedger::fail_snapshot(Origin::signed(VOTER), SYMBOL, shot_id, true),
+            Error::<Test>::RollbackNotAllowed
+        );
+        assert_ok!(RTokenLedger::fail_snapshot(Origin::root(), SYMBOL, shot_id, true));
+        assert_eq!(RTokenLedger::bond_pipelines(SYMBOL, pool()), Some(LinkChunk { bond: 35, unbond: 10, active: 100 }));
+        assert_eq!(RTokenLedger::snap_shots(SYMBOL, shot_id).unwrap().bond_state, PoolBondState::Failed);
+        assert_eq!(RTokenLedger::snapshot_deductions(SYMBOL, shot_id), None);
+        assert_eq!(RTokenLedger::snapshot_created_at(SYMBOL, shot_id), None);
+        assert_eq!(last_event(), TestEvent::rtoken_ledger(RawEvent::EraContinuable(SYMBOL, 1)));
+        assert!(System::events().iter().any(|e| e.event == TestEvent::rtoken_ledger(
+            RawEvent::SnapshotFailed(SYMBOL, shot_id, pool(), 30, 10)
+        )));
+
+        // the era goes on with the last known active of the pool
+        assert_eq!(RTokenLedger::current_era_snap_shots(SYMBOL), Some(vec![]));
+        assert_eq!(RTokenLedger::era_snap_shots(SYMBOL, 1), Some(vec![]));
+        assert_eq!(RTokenLedger::total_expected_active(SYMBOL, 1), Some(100));
+        assert_eq!(rtoken_rate::EraRate::get(SYMBOL, 1), Some(rtoken_rate::RATEBASE));
+
+        assert_noop!(
+            RTokenLedger::fail_snapshot(Origin::root(), SYMBOL, shot_id, true),
+            Error::<Test>::SnapShotNotPending
+        );
+        // the bond rolled back is taken by the snapshot of the next era
+        assert_ok!(RTokenLedger::set_chain_era(Origin::signed(VOTER), SYMBOL, 2));
+        let next_shot = RTokenLedger::current_era_snap_shots(SYMBOL).unwrap()[0];
+        let snap = RTokenLedger::snap_shots(SYMBOL, next_shot).unwrap();
+        assert_eq!((snap.bond, snap.unbond), (35, 10));
+    });
+}
+
+#[test]
+fn fail_snapshot_without_rollback_should_keep_deductions() {
+    new_test_ext().execute_with(|| {
+        let shot_id = bond_reported_snapshot();
+        assert_ok!(RTokenLedger::set_snapshot_timeout(Origin::root(), SYMBOL, 10));
+        System::set_block_number(11);
+
+        assert_ok!(RTokenLedger::fail_snapshot(Origin::signed(VOTER), SYMBOL, shot_id, false));
+        assert_eq!(RTokenLedger::bond_pipelines(SYMBOL, pool()), Some(LinkChunk { bond: 5, unbond: 0, active: 100 }));
+        assert!(System::events().iter().any(|e| e.event == TestEvent::rtoken_ledger(
+            RawEvent::SnapshotFailed(SYMBOL, shot_id, pool(), 0, 0)
+        )));
+    });
+}
+
+/// era 1 reported with the 100 active of `pool`, corrections bounded to 10%
+fn reported_era() {
+    ChainEras::insert(SYMBOL, 1);
+    <TotalExpectedActive>::insert(SYMBOL, 1, 100);
+    assert_ok!(RTokenLedger::set_max_active_correction(Origin::root(), SYMBOL, 100_000_000));
+}
+
+#[test]
+fn corrections_should_be_bounded_by_active_before_first_correction() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        let justification = H256::repeat_byte(9);
+
+        assert_ok!(RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 110, justification));
+        assert_eq!(RTokenLedger::active_before_correction((SYMBOL, 1), pool()), Some(100));
+        assert_noop!(
+            RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 111, justification),
+            Error::<Test>::CorrectionTooLarge
+        );
+        assert_ok!(RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 90, justification));
+        assert_noop!(
+            RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 89, justification),
+            Error::<Test>::CorrectionTooLarge
+        );
+
+        let rate = rtoken_rate::RATEBASE / 10 * 9;
+        assert_eq!(last_event(), TestEvent::rtoken_ledger(RawEvent::PoolActiveCorrected(SYMBOL, 1, pool(), 110, 90, 90, rate, justification)));
+        assert_eq!(RTokenLedger::bond_pipelines(SYMBOL, pool()).unwrap().active, 90);
+        assert_eq!(RTokenLedger::total_expected_active(SYMBOL, 1), Some(90));
+        assert_eq!(rtoken_rate::EraRate::get(SYMBOL, 1), Some(rate));
+        assert_eq!(rtoken_rate::Rate::get(SYMBOL), Some(rate));
+
+        // dropped with the era
+        assert_eq!(RTokenLedger::prune_era_snapshots(SYMBOL, 1, 10), (0, true));
+        assert_eq!(RTokenLedger::active_before_correction((SYMBOL, 1), pool()), None);
+    });
+}
+
+#[test]
+fn correction_should_not_pass_paused_rate() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        assert_ok!(rtoken_rate::Module::<Test>::set_max_rate_change(Origin::root(), SYMBOL, 50_000_000));
+
+        // beyond the max rate change, nothing is written
+        assert_noop!(
+            RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 106, H256::zero()),
+            Error::<Test>::RatePaused
+        );
+        assert!(!rtoken_rate::Module::<Test>::is_paused(SYMBOL));
+        assert_ok!(RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 105, H256::zero()));
+
+        rtoken_rate::PausedRate::insert(SYMBOL, rtoken_rate::RATEBASE);
+        assert_noop!(
+            RTokenLedger::correct_pool_active(Origin::root(), SYMBOL, 1, pool(), 104, H256::zero()),
+            Error::<Test>::RatePaused
+        );
+    });
+}
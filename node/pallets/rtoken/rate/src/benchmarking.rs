@@ -0,0 +1,27 @@
+//! Benchmarks for the RTokenRate Pallet
+
+use super::*;
+use frame_benchmarking::benchmarks;
+
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+benchmarks! {
+    _ { }
+
+    set_max_rate_change {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, 10_000_000)
+    verify {
+        assert_eq!(Module::<T>::max_rate_change(SYMBOL), Perbill::from_percent(1));
+    }
+
+    confirm_rate {
+        <Rate>::insert(SYMBOL, RATEBASE);
+        <PausedRate>::insert(SYMBOL, RATEBASE * 2);
+        let origin = T::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, RATEBASE * 2)
+    verify {
+        assert!(!Module::<T>::is_paused(SYMBOL));
+        assert_eq!(Module::<T>::rate(SYMBOL), Some(RATEBASE * 2));
+    }
+}
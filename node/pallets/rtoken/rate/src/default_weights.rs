@@ -0,0 +1,15 @@
+//! Default weights for the RTokenRate Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn set_max_rate_change() -> Weight {
+        10_000_000 as Weight
+    }
+    fn confirm_rate() -> Weight {
+        10_000_000 as Weight
+    }
+}
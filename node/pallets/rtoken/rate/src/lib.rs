@@ -2,22 +2,63 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::prelude::*;
-use frame_support::{decl_event, decl_module, decl_storage};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::DispatchResult, ensure,
+    traits::EnsureOrigin,
+    weights::Weight,
+};
 use frame_system::{self as system};
 use pallet_staking::EraIndex;
 use sp_arithmetic::{helpers_128bit::multiply_by_rational};
+use sp_runtime::Perbill;
 use node_primitives::{RSymbol};
 
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 pub type RateType = u64;
 pub const RATEBASE: RateType = 1_000_000_000_000;
 
+pub trait WeightInfo {
+    fn set_max_rate_change() -> Weight;
+    fn confirm_rate() -> Weight;
+}
+
 pub trait Trait: system::Trait {
     type Event: From<Event> + Into<<Self as frame_system::Trait>::Event>;
+    /// Origin allowed to bound rate changes and confirm a paused rate, eg: council
+    type AdminOrigin: EnsureOrigin<Self::Origin>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event! {
     pub enum Event {
         RateSet(RSymbol, RateType),
+        /// new rate out of the max rate change, symbol paused: symbol, current rate, new rate
+        RateAnomaly(RSymbol, RateType, RateType),
+        /// max rate change of symbol set
+        MaxRateChangeSet(RSymbol, Perbill),
+        /// paused rate confirmed by governance, symbol resumed
+        RateConfirmed(RSymbol, RateType),
+    }
+}
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// rate change limit larger than 100%
+        OverFlow,
+        /// rate of symbol not paused
+        RateNotPaused,
+        /// rate zero
+        RateZero,
     }
 }
 
@@ -29,25 +70,69 @@ decl_storage! {
         
         /// current rate
         pub Rate get(fn rate): map hasher(blake2_128_concat) RSymbol => Option<RateType>;
+
+        /// max change of the rate each time it is set, that is once per era. Zero means unbounded
+        pub MaxRateChange get(fn max_rate_change): map hasher(blake2_128_concat) RSymbol => Perbill;
+
+        /// latest rate out of the max rate change, the symbol is paused until governance confirms a rate
+        pub PausedRate get(fn paused_rate): map hasher(blake2_128_concat) RSymbol => Option<RateType>;
     }
 }
 
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
         fn deposit_event() = default;
+
+        /// set max rate change of symbol, in parts per billion
+        #[weight = T::WeightInfo::set_max_rate_change()]
+        pub fn set_max_rate_change(origin, symbol: RSymbol, new_part: u32) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(new_part <= 1_000_000_000, Error::<T>::OverFlow);
+
+            let limit = Perbill::from_parts(new_part);
+            <MaxRateChange>::insert(symbol, limit);
+            Self::deposit_event(Event::MaxRateChangeSet(symbol, limit));
+            Ok(())
+        }
+
+        /// confirm the rate of a paused symbol, which may be the paused rate or a corrected one
+        #[weight = T::WeightInfo::confirm_rate()]
+        pub fn confirm_rate(origin, symbol: RSymbol, rate: RateType) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Self::paused_rate(symbol).is_some(), Error::<T>::RateNotPaused);
+            ensure!(rate > 0, Error::<T>::RateZero);
+
+            <PausedRate>::remove(symbol);
+            <Rate>::insert(symbol, rate);
+            Self::deposit_event(Event::RateSet(symbol, rate));
+            Self::deposit_event(Event::RateConfirmed(symbol, rate));
+            Ok(())
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
     pub fn set_rate(symbol: RSymbol, total: u128, rtotal: u128) -> RateType {
-        if total == 0 || rtotal == 0 {
-            <Rate>::insert(symbol, RATEBASE);
-            Self::deposit_event(Event::RateSet(symbol, RATEBASE));
-            return RATEBASE;
+        let op_rate = <Rate>::get(symbol);
+        // nothing bonded or issued counts as the base rate, bounded like any other once a rate exists
+        let new_rate = if total == 0 || rtotal == 0 {
+            RATEBASE
+        } else {
+            multiply_by_rational(total, RATEBASE.into(), rtotal).unwrap_or(RATEBASE.into()) as RateType
+        };
+        if let Some(old_rate) = op_rate {
+            // keep the current rate while paused or out of bound, until governance confirms
+            if Self::is_paused(symbol) || Self::out_of_bound(symbol, old_rate, new_rate) {
+                if !Self::is_paused(symbol) {
+                    Self::deposit_event(Event::RateAnomaly(symbol, old_rate, new_rate));
+                }
+                <PausedRate>::insert(symbol, new_rate);
+                return old_rate;
+            }
         }
 
-        let new_rate = multiply_by_rational(total, RATEBASE.into(), rtotal).unwrap_or(RATEBASE.into()) as RateType;
-        let op_rate = <Rate>::get(symbol);
         if op_rate.is_none() || op_rate.unwrap() != new_rate {
             <Rate>::insert(symbol, new_rate);
             Self::deposit_event(Event::RateSet(symbol, new_rate));
@@ -56,6 +141,20 @@ impl<T: Trait> Module<T> {
         new_rate
     }
 
+    pub fn is_paused(symbol: RSymbol) -> bool {
+        <PausedRate>::contains_key(symbol)
+    }
+
+    fn out_of_bound(symbol: RSymbol, old_rate: RateType, new_rate: RateType) -> bool {
+        let limit = Self::max_rate_change(symbol);
+        if limit == Perbill::zero() {
+            return false;
+        }
+
+        let change = if new_rate > old_rate { new_rate - old_rate } else { old_rate - new_rate };
+        change > limit * old_rate
+    }
+
     pub fn token_to_rtoken(symbol: RSymbol, balance: u128) -> u128 {
         let op_rate = Rate::get(symbol);
         if op_rate.is_none() || op_rate.unwrap() == 0 {
@@ -0,0 +1,118 @@
+use super::*;
+use super::mock::*;
+use frame_support::{assert_ok, assert_noop};
+use sp_runtime::traits::BadOrigin;
+
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+/// rate of `total` tokens against 100 rtokens
+fn set_rate(total: u128) -> RateType {
+    RTokenRate::set_rate(SYMBOL, total, 100)
+}
+
+fn rate(total: u128) -> RateType {
+    RATEBASE / 100 * total as RateType
+}
+
+#[test]
+fn set_max_rate_change_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(RTokenRate::set_max_rate_change(Origin::signed(1), SYMBOL, 100_000_000), BadOrigin);
+        assert_noop!(
+            RTokenRate::set_max_rate_change(Origin::root(), SYMBOL, 1_000_000_001),
+            Error::<Test>::OverFlow
+        );
+        assert_ok!(RTokenRate::set_max_rate_change(Origin::root(), SYMBOL, 100_000_000));
+        assert_eq!(RTokenRate::max_rate_change(SYMBOL), Perbill::from_percent(10));
+        assert_eq!(events().pop(), Some(TestEvent::rtoken_rate(Event::MaxRateChangeSet(SYMBOL, Perbill::from_percent(10)))));
+    });
+}
+
+#[test]
+fn rate_should_be_unbounded_by_default() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(RTokenRate::set_rate(SYMBOL, 0, 0), RATEBASE);
+        assert_eq!(set_rate(300), rate(300));
+        assert_eq!(set_rate(101), rate(101));
+        assert_eq!(RTokenRate::rate(SYMBOL), Some(rate(101)));
+        assert!(!RTokenRate::is_paused(SYMBOL));
+    });
+}
+
+#[test]
+fn rate_within_bound_should_be_set() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(RTokenRate::set_max_rate_change(Origin::root(), SYMBOL, 100_000_000));
+        // the first rate has nothing to be compared with
+        assert_eq!(set_rate(200), rate(200));
+        assert_eq!(set_rate(220), rate(220));
+        assert_eq!(set_rate(198), rate(198));
+        assert_eq!(RTokenRate::rate(SYMBOL), Some(rate(198)));
+        assert!(!RTokenRate::is_paused(SYMBOL));
+        // other symbols are not bounded
+        assert_eq!(RTokenRate::set_rate(RSymbol::RKSM, 100, 100), RATEBASE);
+        assert_eq!(RTokenRate::set_rate(RSymbol::RKSM, 300, 100), rate(300));
+    });
+}
+
+#[test]
+fn rate_out_of_bound_should_pause_symbol() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(RTokenRate::set_max_rate_change(Origin::root(), SYMBOL, 100_000_000));
+        assert_eq!(set_rate(100), rate(100));
+
+        assert_eq!(set_rate(111), rate(100));
+        assert_eq!(RTokenRate::rate(SYMBOL), Some(rate(100)));
+        assert_eq!(RTokenRate::paused_rate(SYMBOL), Some(rate(111)));
+        assert!(RTokenRate::is_paused(SYMBOL));
+        assert_eq!(events().pop(), Some(TestEvent::rtoken_rate(Event::RateAnomaly(SYMBOL, rate(100), rate(111)))));
+
+        // while paused, even a rate within the bound is only kept as the paused rate, without a new anomaly
+        let count = events().len();
+        assert_eq!(set_rate(101), rate(100));
+        assert_eq!(RTokenRate::paused_rate(SYMBOL), Some(rate(101)));
+        assert_eq!(events().len(), count);
+        assert_eq!(RTokenRate::token_to_rtoken(SYMBOL, 100), 100);
+    });
+}
+
+#[test]
+fn zero_total_should_be_bounded_once_rate_exists() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(RTokenRate::set_max_rate_change(Origin::root(), SYMBOL, 100_000_000));
+        assert_eq!(set_rate(200), rate(200));
+
+        assert_eq!(set_rate(0), rate(200));
+        assert_eq!(RTokenRate::rate(SYMBOL), Some(rate(200)));
+        assert_eq!(RTokenRate::paused_rate(SYMBOL), Some(RATEBASE));
+        assert_eq!(events().pop(), Some(TestEvent::rtoken_rate(Event::RateAnomaly(SYMBOL, rate(200), RATEBASE))));
+
+        // still paused, so even a zero issuance keeps the rate
+        assert_eq!(RTokenRate::set_rate(SYMBOL, 200, 0), rate(200));
+        assert!(RTokenRate::is_paused(SYMBOL));
+    });
+}
+
+#[test]
+fn confirm_rate_should_resume_symbol() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(RTokenRate::confirm_rate(Origin::root(), SYMBOL, rate(100)), Error::<Test>::RateNotPaused);
+        assert_ok!(RTokenRate::set_max_rate_change(Origin::root(), SYMBOL, 100_000_000));
+        set_rate(100);
+        set_rate(50);
+        assert!(RTokenRate::is_paused(SYMBOL));
+
+        assert_noop!(RTokenRate::confirm_rate(Origin::signed(1), SYMBOL, rate(50)), BadOrigin);
+        assert_noop!(RTokenRate::confirm_rate(Origin::root(), SYMBOL, 0), Error::<Test>::RateZero);
+        assert_ok!(RTokenRate::confirm_rate(Origin::root(), SYMBOL, rate(50)));
+        assert!(!RTokenRate::is_paused(SYMBOL));
+        assert_eq!(RTokenRate::rate(SYMBOL), Some(rate(50)));
+        assert_eq!(events().pop(), Some(TestEvent::rtoken_rate(Event::RateConfirmed(SYMBOL, rate(50)))));
+        assert_eq!(RTokenRate::rtoken_to_token(SYMBOL, 100), 50);
+
+        // the bound applies again from the confirmed rate
+        assert_eq!(set_rate(54), rate(54));
+        assert_eq!(set_rate(60), rate(54));
+        assert!(RTokenRate::is_paused(SYMBOL));
+    });
+}
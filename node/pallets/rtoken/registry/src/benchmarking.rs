@@ -0,0 +1,48 @@
+//! Benchmarks for the RTokenRegistry Pallet
+
+use super::*;
+use frame_benchmarking::benchmarks;
+
+const SYMBOL: RSymbol = RSymbol(100);
+const DECIMALS: u8 = 12;
+
+fn name() -> Vec<u8> {
+    b"rTEST".to_vec()
+}
+
+fn register() {
+    <Assets>::insert(SYMBOL, AssetInfo {
+        name: name(),
+        decimals: DECIMALS,
+        chain_type: ChainType::Substrate,
+        resource_id: None,
+        enabled: true,
+    });
+}
+
+benchmarks! {
+    _ { }
+
+    register_asset {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, name(), DECIMALS, ChainType::Substrate)
+    verify {
+        assert!(Module::<T>::is_enabled(SYMBOL));
+    }
+
+    update_asset {
+        register();
+        let origin = T::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, b"rTEST2".to_vec(), DECIMALS + 6)
+    verify {
+        assert_eq!(Module::<T>::assets(SYMBOL).map(|info| info.decimals), Some(DECIMALS + 6));
+    }
+
+    set_asset_enabled {
+        register();
+        let origin = T::AdminOrigin::successful_origin();
+    }: _(origin, SYMBOL, false)
+    verify {
+        assert!(!Module::<T>::is_enabled(SYMBOL));
+    }
+}
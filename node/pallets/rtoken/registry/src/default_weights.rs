@@ -0,0 +1,18 @@
+//! Default weights for the RTokenRegistry Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn register_asset() -> Weight {
+        50_000_000 as Weight
+    }
+    fn update_asset() -> Weight {
+        50_000_000 as Weight
+    }
+    fn set_asset_enabled() -> Weight {
+        50_000_000 as Weight
+    }
+}
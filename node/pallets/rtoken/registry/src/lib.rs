@@ -0,0 +1,206 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::DispatchResult, ensure,
+    traits::{EnsureOrigin, Get},
+    weights::Weight,
+};
+
+use frame_system::{self as system};
+use sp_runtime::DispatchError;
+use node_primitives::{ChainType, RSymbol, ResourceId};
+
+pub mod models;
+pub use models::*;
+
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub trait WeightInfo {
+    fn register_asset() -> Weight;
+    fn update_asset() -> Weight;
+    fn set_asset_enabled() -> Weight;
+}
+
+pub trait Trait: system::Trait {
+    type Event: From<Event> + Into<<Self as system::Trait>::Event>;
+    /// Origin allowed to register and update assets, eg: council
+    type AdminOrigin: EnsureOrigin<Self::Origin>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
+}
+
+decl_event! {
+    pub enum Event {
+        /// asset registered: symbol, name, decimals, chain type
+        AssetRegistered(RSymbol, Vec<u8>, u8, ChainType),
+        /// asset metadata updated: symbol, name, decimals
+        AssetUpdated(RSymbol, Vec<u8>, u8),
+        /// asset enabled or disabled
+        AssetEnabledSet(RSymbol, bool),
+        /// bridge resource id of asset set or cleared
+        ResourceIdSet(RSymbol, Option<ResourceId>),
+    }
+}
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// asset already registered
+        AssetAlreadyRegistered,
+        /// asset not registered
+        AssetNotRegistered,
+        /// asset disabled
+        AssetDisabled,
+        /// asset name empty
+        EmptyName,
+        /// resource id used by another asset
+        ResourceIdInUse,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as RTokenRegistry {
+        /// metadata of registered assets
+        pub Assets get(fn assets): map hasher(blake2_128_concat) RSymbol => Option<AssetInfo>;
+        /// resource id => asset
+        pub ResourceAssets get(fn resource_asset): map hasher(blake2_128_concat) ResourceId => Option<RSymbol>;
+        /// storage version, genesis assets need no migration
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig| Releases::V2_0_0): Releases;
+    }
+    add_extra_genesis {
+        config(assets): Vec<(RSymbol, AssetInfo)>;
+        build(|config: &GenesisConfig| {
+            for (symbol, info) in config.assets.iter() {
+                if let Some(resource_id) = info.resource_id {
+                    <ResourceAssets>::insert(resource_id, symbol);
+                }
+                <Assets>::insert(symbol, info);
+            }
+        });
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        fn on_runtime_upgrade() -> Weight {
+            Self::migrate_builtin_assets()
+        }
+
+        /// register a new asset, enabled and without resource id
+        #[weight = T::WeightInfo::register_asset()]
+        pub fn register_asset(origin, symbol: RSymbol, name: Vec<u8>, decimals: u8, chain_type: ChainType) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(!name.is_empty(), Error::<T>::EmptyName);
+            ensure!(Self::assets(symbol).is_none(), Error::<T>::AssetAlreadyRegistered);
+
+            let info = AssetInfo {
+                name: name.clone(),
+                decimals,
+                chain_type,
+                resource_id: None,
+                enabled: true,
+            };
+            <Assets>::insert(symbol, info);
+            Self::deposit_event(Event::AssetRegistered(symbol, name, decimals, chain_type));
+            Ok(())
+        }
+
+        /// update the name and decimals of an asset. chain type is fixed once registered,
+        /// pools and signatures of the asset are checked against it
+        #[weight = T::WeightInfo::update_asset()]
+        pub fn update_asset(origin, symbol: RSymbol, name: Vec<u8>, decimals: u8) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(!name.is_empty(), Error::<T>::EmptyName);
+            let mut info = Self::assets(symbol).ok_or(Error::<T>::AssetNotRegistered)?;
+
+            info.name = name.clone();
+            info.decimals = decimals;
+            <Assets>::insert(symbol, info);
+            Self::deposit_event(Event::AssetUpdated(symbol, name, decimals));
+            Ok(())
+        }
+
+        /// enable or disable an asset
+        #[weight = T::WeightInfo::set_asset_enabled()]
+        pub fn set_asset_enabled(origin, symbol: RSymbol, enabled: bool) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let mut info = Self::assets(symbol).ok_or(Error::<T>::AssetNotRegistered)?;
+
+            info.enabled = enabled;
+            <Assets>::insert(symbol, info);
+            Self::deposit_event(Event::AssetEnabledSet(symbol, enabled));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// chain type of a registered asset
+    pub fn chain_type(symbol: RSymbol) -> Result<ChainType, DispatchError> {
+        let info = Self::assets(symbol).ok_or(Error::<T>::AssetNotRegistered)?;
+        Ok(info.chain_type)
+    }
+
+    pub fn is_enabled(symbol: RSymbol) -> bool {
+        Self::assets(symbol).map_or(false, |info| info.enabled)
+    }
+
+    pub fn ensure_enabled(symbol: RSymbol) -> DispatchResult {
+        let info = Self::assets(symbol).ok_or(Error::<T>::AssetNotRegistered)?;
+        ensure!(info.enabled, Error::<T>::AssetDisabled);
+        Ok(())
+    }
+
+    pub fn resource_id(symbol: RSymbol) -> Option<ResourceId> {
+        Self::assets(symbol).and_then(|info| info.resource_id)
+    }
+
+    /// set or clear the bridge resource id of an asset, it is up to the bridge who may do so
+    pub fn set_resource_id(symbol: RSymbol, resource_id: Option<ResourceId>) -> DispatchResult {
+        let mut info = Self::assets(symbol).ok_or(Error::<T>::AssetNotRegistered)?;
+        if let Some(rid) = resource_id {
+            let owner = Self::resource_asset(rid);
+            ensure!(owner.is_none() || owner == Some(symbol), Error::<T>::ResourceIdInUse);
+        }
+
+        if let Some(old_rid) = info.resource_id {
+            <ResourceAssets>::remove(old_rid);
+        }
+        if let Some(rid) = resource_id {
+            <ResourceAssets>::insert(rid, symbol);
+        }
+        info.resource_id = resource_id;
+        <Assets>::insert(symbol, info);
+        Self::deposit_event(Event::ResourceIdSet(symbol, resource_id));
+        Ok(())
+    }
+
+    /// registers the rtokens that existed before the registry, once
+    pub fn migrate_builtin_assets() -> Weight {
+        if StorageVersion::get() == Releases::V2_0_0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let assets = builtin_assets();
+        let count = assets.len() as Weight;
+        for (symbol, info) in assets {
+            if !<Assets>::contains_key(symbol) {
+                <Assets>::insert(symbol, info);
+            }
+        }
+        StorageVersion::put(Releases::V2_0_0);
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+}
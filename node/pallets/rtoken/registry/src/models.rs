@@ -0,0 +1,57 @@
+use codec::{Decode, Encode};
+use node_primitives::{ChainType, RSymbol, ResourceId};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+/// metadata of a registered rtoken
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetInfo {
+    /// name, eg: rDOT
+    pub name: Vec<u8>,
+    /// decimals of the native token on its chain
+    pub decimals: u8,
+    /// chain type, decides how signatures and recipients are checked
+    pub chain_type: ChainType,
+    /// resource id on the bridge, if the rtoken can be bridged
+    pub resource_id: Option<ResourceId>,
+    /// disabled assets can't be minted or bonded any more
+    pub enabled: bool,
+}
+
+/// metadata of the rtokens that existed before the registry
+pub fn builtin_assets() -> Vec<(RSymbol, AssetInfo)> {
+    let asset = |name: &[u8], decimals: u8, chain_type: ChainType| AssetInfo {
+        name: name.to_vec(),
+        decimals,
+        chain_type,
+        resource_id: None,
+        enabled: true,
+    };
+
+    vec![
+        (RSymbol::RFIS, asset(b"rFIS", 12, ChainType::Substrate)),
+        (RSymbol::RDOT, asset(b"rDOT", 10, ChainType::Substrate)),
+        (RSymbol::RKSM, asset(b"rKSM", 12, ChainType::Substrate)),
+        (RSymbol::RATOM, asset(b"rATOM", 6, ChainType::Tendermint)),
+        (RSymbol::RSOL, asset(b"rSOL", 9, ChainType::Solana)),
+        (RSymbol::RMATIC, asset(b"rMATIC", 18, ChainType::Ethereum)),
+        (RSymbol::RBNB, asset(b"rBNB", 18, ChainType::Ethereum)),
+        (RSymbol::RETH, asset(b"rETH", 18, ChainType::Ethereum)),
+    ]
+}
+
+/// storage releases of this pallet
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum Releases {
+    V1_0_0,
+    V2_0_0,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use super::mock::*;
+use frame_support::{assert_ok, assert_noop};
+use sp_runtime::traits::BadOrigin;
+
+const SYMBOL: RSymbol = RSymbol(100);
+
+fn register() -> DispatchResult {
+    RTokenRegistry::register_asset(Origin::root(), SYMBOL, b"rTEST".to_vec(), 12, ChainType::Substrate)
+}
+
+#[test]
+fn genesis_should_need_no_migration() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(RTokenRegistry::storage_version(), Releases::V2_0_0);
+        assert!(RTokenRegistry::is_enabled(RSymbol::RDOT));
+        assert_eq!(RTokenRegistry::chain_type(RSymbol::RATOM), Ok(ChainType::Tendermint));
+        assert_eq!(RTokenRegistry::migrate_builtin_assets(), <Test as frame_system::Trait>::DbWeight::get().reads(1));
+    });
+}
+
+#[test]
+fn register_asset_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RTokenRegistry::register_asset(Origin::signed(1), SYMBOL, b"rTEST".to_vec(), 12, ChainType::Substrate),
+            BadOrigin
+        );
+        assert_noop!(
+            RTokenRegistry::register_asset(Origin::root(), SYMBOL, vec![], 12, ChainType::Substrate),
+            Error::<Test>::EmptyName
+        );
+        assert_noop!(
+            RTokenRegistry::register_asset(Origin::root(), RSymbol::RDOT, b"rDOT".to_vec(), 10, ChainType::Substrate),
+            Error::<Test>::AssetAlreadyRegistered
+        );
+
+        assert_ok!(register());
+        assert_eq!(last_event(), TestEvent::rtoken_registry(Event::AssetRegistered(SYMBOL, b"rTEST".to_vec(), 12, ChainType::Substrate)));
+        assert_eq!(RTokenRegistry::assets(SYMBOL), Some(AssetInfo {
+            name: b"rTEST".to_vec(),
+            decimals: 12,
+            chain_type: ChainType::Substrate,
+            resource_id: None,
+            enabled: true,
+        }));
+        assert_noop!(register(), Error::<Test>::AssetAlreadyRegistered);
+    });
+}
+
+#[test]
+fn update_asset_should_keep_chain_type() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RTokenRegistry::update_asset(Origin::root(), SYMBOL, b"rTEST".to_vec(), 12),
+            Error::<Test>::AssetNotRegistered
+        );
+        assert_ok!(register());
+        assert_noop!(
+            RTokenRegistry::update_asset(Origin::root(), SYMBOL, vec![], 12),
+            Error::<Test>::EmptyName
+        );
+        assert_noop!(
+            RTokenRegistry::update_asset(Origin::signed(1), SYMBOL, b"rTEST2".to_vec(), 18),
+            BadOrigin
+        );
+
+        assert_ok!(RTokenRegistry::update_asset(Origin::root(), SYMBOL, b"rTEST2".to_vec(), 18));
+        assert_eq!(last_event(), TestEvent::rtoken_registry(Event::AssetUpdated(SYMBOL, b"rTEST2".to_vec(), 18)));
+        let info = RTokenRegistry::assets(SYMBOL).unwrap();
+        assert_eq!(info.name, b"rTEST2".to_vec());
+        assert_eq!(info.decimals, 18);
+        assert_eq!(info.chain_type, ChainType::Substrate);
+        assert!(info.enabled);
+    });
+}
+
+#[test]
+fn set_asset_enabled_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RTokenRegistry::set_asset_enabled(Origin::root(), SYMBOL, false),
+            Error::<Test>::AssetNotRegistered
+        );
+        assert_noop!(RTokenRegistry::ensure_enabled(SYMBOL), Error::<Test>::AssetNotRegistered);
+        assert_ok!(register());
+        assert_ok!(RTokenRegistry::ensure_enabled(SYMBOL));
+
+        assert_ok!(RTokenRegistry::set_asset_enabled(Origin::root(), SYMBOL, false));
+        assert_eq!(last_event(), TestEvent::rtoken_registry(Event::AssetEnabledSet(SYMBOL, false)));
+        assert!(!RTokenRegistry::is_enabled(SYMBOL));
+        assert_noop!(RTokenRegistry::ensure_enabled(SYMBOL), Error::<Test>::AssetDisabled);
+        // metadata is kept while disabled
+        assert_eq!(RTokenRegistry::chain_type(SYMBOL), Ok(ChainType::Substrate));
+
+        assert_ok!(RTokenRegistry::set_asset_enabled(Origin::root(), SYMBOL, true));
+        assert_ok!(RTokenRegistry::ensure_enabled(SYMBOL));
+    });
+}
+
+#[test]
+fn set_resource_id_should_not_reuse_resource_id() {
+    new_test_ext().execute_with(|| {
+        let rid = [1u8; 32];
+        assert_ok!(register());
+        assert_ok!(RTokenRegistry::set_resource_id(RSymbol::RDOT, Some(rid)));
+        assert_eq!(RTokenRegistry::resource_asset(rid), Some(RSymbol::RDOT));
+        assert_noop!(RTokenRegistry::set_resource_id(SYMBOL, Some(rid)), Error::<Test>::ResourceIdInUse);
+
+        assert_ok!(RTokenRegistry::set_resource_id(RSymbol::RDOT, None));
+        assert_eq!(RTokenRegistry::resource_asset(rid), None);
+        assert_ok!(RTokenRegistry::set_resource_id(SYMBOL, Some(rid)));
+        assert_eq!(RTokenRegistry::resource_id(SYMBOL), Some(rid));
+    });
+}
+
+#[test]
+fn migrate_builtin_assets_should_keep_registered_assets() {
+    new_test_ext().execute_with(|| {
+        // storage as it was before the registry
+        for (symbol, _) in builtin_assets() {
+            <Assets>::remove(symbol);
+        }
+        StorageVersion::put(Releases::V1_0_0);
+        assert_ok!(RTokenRegistry::register_asset(Origin::root(), RSymbol::RDOT, b"DOT".to_vec(), 10, ChainType::Substrate));
+        assert_ok!(RTokenRegistry::set_asset_enabled(Origin::root(), RSymbol::RDOT, false));
+
+        let count = builtin_assets().len() as Weight;
+        assert_eq!(
+            RTokenRegistry::migrate_builtin_assets(),
+            <Test as frame_system::Trait>::DbWeight::get().reads_writes(count + 1, count + 1)
+        );
+        assert_eq!(RTokenRegistry::storage_version(), Releases::V2_0_0);
+        for (symbol, info) in builtin_assets() {
+            if symbol == RSymbol::RDOT {
+                assert_eq!(RTokenRegistry::assets(symbol).map(|info| info.name), Some(b"DOT".to_vec()));
+                assert!(!RTokenRegistry::is_enabled(symbol));
+            } else {
+                assert_eq!(RTokenRegistry::assets(symbol), Some(info));
+            }
+        }
+
+        // runs only once
+        assert_ok!(RTokenRegistry::set_asset_enabled(Origin::root(), RSymbol::RKSM, false));
+        RTokenRegistry::migrate_builtin_assets();
+        assert!(!RTokenRegistry::is_enabled(RSymbol::RKSM));
+    });
+}
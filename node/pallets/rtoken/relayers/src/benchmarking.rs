@@ -0,0 +1,36 @@
+//! Benchmarks for the RTokenRelayers Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account};
+
+const SEED: u32 = 0;
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+benchmarks! {
+    _ { }
+
+    set_threshold {
+    }: _(RawOrigin::Root, SYMBOL, 2)
+    verify {
+        assert_eq!(RelayerThreshold::get(SYMBOL), 2);
+    }
+
+    add_relayer {
+        let relayer: T::AccountId = account("relayer", 0, SEED);
+        let source = T::Lookup::unlookup(relayer.clone());
+    }: _(RawOrigin::Root, SYMBOL, source)
+    verify {
+        assert!(Module::<T>::is_relayer(SYMBOL, &relayer));
+    }
+
+    remove_relayer {
+        let relayer: T::AccountId = account("relayer", 0, SEED);
+        <Relayers<T>>::insert(SYMBOL, &relayer, true);
+        RelayerCount::insert(SYMBOL, 1);
+        let source = T::Lookup::unlookup(relayer.clone());
+    }: _(RawOrigin::Root, SYMBOL, source)
+    verify {
+        assert!(!Module::<T>::is_relayer(SYMBOL, &relayer));
+    }
+}
@@ -0,0 +1,18 @@
+//! Default weights for the RTokenRelayers Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn set_threshold() -> Weight {
+        10_000 as Weight
+    }
+    fn add_relayer() -> Weight {
+        10_000 as Weight
+    }
+    fn remove_relayer() -> Weight {
+        10_000 as Weight
+    }
+}
@@ -3,7 +3,7 @@
 use sp_std::prelude::*;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
-    dispatch::DispatchResult, ensure
+    dispatch::DispatchResult, ensure, weights::Weight,
 };
 
 use frame_system::{self as system, ensure_root};
@@ -12,8 +12,20 @@ use sp_runtime::{
 };
 use node_primitives::{RSymbol};
 
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub trait WeightInfo {
+    fn set_threshold() -> Weight;
+    fn add_relayer() -> Weight;
+    fn remove_relayer() -> Weight;
+}
+
 pub trait Trait: system::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event! {
@@ -62,7 +74,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// Sets the vote threshold for proposals.
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::set_threshold()]
         pub fn set_threshold(origin, symbol: RSymbol, threshold: u32) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(threshold > 0, Error::<T>::InvalidThreshold);
@@ -73,7 +85,7 @@ decl_module! {
         }
 
         /// Adds a new relayer to the relayer set.
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::add_relayer()]
         pub fn add_relayer(origin, symbol: RSymbol, who: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
             ensure_root(origin)?;
             let relayer = T::Lookup::lookup(who)?;
@@ -87,7 +99,7 @@ decl_module! {
         }
 
         /// Removes an existing relayer from the set.
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::remove_relayer()]
         pub fn remove_relayer(origin, symbol: RSymbol, who: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
             ensure_root(origin)?;
             let relayer = T::Lookup::lookup(who)?;
@@ -2,11 +2,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::prelude::*;
+use codec::{Decode, Encode};
 use frame_support::{
     debug, decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchResult},
     ensure,
     traits::{Currency, Get, ExistenceRequirement::{KeepAlive}},
+    weights::Weight,
 };
 use frame_system::{
     self as system, ensure_signed, ensure_root, ensure_none,
@@ -24,8 +26,9 @@ use sp_runtime::{
 };
 use pallet_staking::{
     self as staking, MAX_NOMINATIONS, Nominations,
-    RewardDestination, StakingLedger, EraIndex, UnlockChunk,
+    RewardDestination, StakingLedger, EraIndex, UnlockChunk, Exposure,
 };
+use sp_staking::SessionIndex;
 use pallet_session as session;
 use rtoken_balances::{traits::{Currency as RCurrency}};
 use node_primitives::{RSymbol, ChainId};
@@ -33,9 +36,23 @@ use rclaim;
 use bridge_common as bridge;
 use sp_core::U256;
 
+pub mod nomination;
+pub use nomination::*;
+pub mod slash;
+pub use slash::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 const SYMBOL: RSymbol = RSymbol::RFIS;
 const MAX_ONBOARD_VALIDATORS: usize = 300;
 const DEFAULT_LONGEVITY: u64 = 600;
+/// eras in which offences are recent for the nomination score
+const RECENT_OFFENCE_ERAS: EraIndex = 4;
+/// offences kept per validator, older ones are dropped
+const MAX_RECENT_OFFENCES: usize = 16;
 
 pub(crate) const LOG_TARGET: &'static str = "rfis";
 
@@ -61,6 +78,9 @@ pub trait Trait: system::Trait + staking::Trait + SendTransactionTypes<Call<Self
 
     /// A configuration for base priority of unsigned transactions.
     type UnsignedPriority: Get<TransactionPriority>;
+
+    /// liveness of validators for the nomination score
+    type ValidatorStatus: ValidatorStatus<Self::AccountId>;
 }
 
 decl_event! {
@@ -100,6 +120,18 @@ decl_event! {
         MaxNominationNumSet(u8),
         /// Nomination Updated for a pool
         NominationUpdated(EraIndex, Vec<AccountId>, AccountId),
+        /// weights of the nomination score updated
+        ScoreWeightsSet(ScoreWeights),
+        /// slash applied to a pool by staking: offence era, pool, slash
+        PoolSlashed(EraIndex, AccountId, Balance),
+        /// slash of pool covered from the insurance account: era, pool, value
+        PoolSlashCovered(EraIndex, AccountId, Balance),
+        /// slash policy set
+        SlashPolicySet(SlashPolicy),
+        /// eras slashed validators are not nominated for set
+        SlashExclusionErasSet(EraIndex),
+        /// rebond of unbonding: who, pool, value, rfis minted
+        Rebond(AccountId, AccountId, Balance, u128),
     }
 }
 
@@ -157,6 +189,12 @@ decl_error! {
         UnlockChunkValueTooBig,
         /// No chunk to withdraw
         NoChunkToWithdraw,
+        /// rebond Zero
+        RebondZero,
+        /// rebond more than unbonding not unlocked
+        RebondTooBig,
+        /// rate of rfis paused until governance confirms it
+        RatePaused,
     }
 }
 
@@ -177,6 +215,8 @@ decl_storage! {
         MinNominationNum get(fn min_nomination_num): u8 = 3;
         /// max nomination
         MaxNominationNum get(fn max_nomination_num): u8 = 10;
+        /// weights of the nomination score
+        NominationScoreWeights get(fn score_weights): ScoreWeights;
 
         TotalBondedBeforePayout get(fn total_bonded_before_payout): map hasher(blake2_128_concat) EraIndex => Option<BalanceOf<T>>;
         TotalBondedAfterPayout get(fn total_bonded_after_payout): map hasher(blake2_128_concat) EraIndex => Option<BalanceOf<T>>;
@@ -199,6 +239,25 @@ decl_storage! {
 
         /// Unbond commission
         UnbondCommission get(fn unbond_commission): Perbill = Perbill::from_parts(2000000);
+
+        /// slashes of pools applied by staking: (offence era, pool) => slash
+        pub PoolSlashes get(fn pool_slashes):
+            double_map hasher(blake2_128_concat) EraIndex, hasher(twox_64_concat) T::AccountId => BalanceOf<T>;
+        /// era an onboard validator was last slashed in
+        pub SlashedValidators get(fn slashed_validators): map hasher(twox_64_concat) T::AccountId => Option<EraIndex>;
+        /// slashes of pools deferred by staking, by the era staking queued them in
+        pub PendingPoolSlashes get(fn pending_pool_slashes):
+            map hasher(twox_64_concat) EraIndex => Vec<PendingSlash<T::AccountId, BalanceOf<T>>>;
+        /// eras of the last offences reported against a validator, at most MAX_RECENT_OFFENCES
+        pub RecentOffences get(fn recent_offences): map hasher(twox_64_concat) T::AccountId => Vec<EraIndex>;
+        /// eras a slashed validator is not nominated for
+        SlashExclusionEras get(fn slash_exclusion_eras): EraIndex = 28;
+        /// how slashes of pools are borne
+        PoolSlashPolicy get(fn slash_policy): SlashPolicy;
+        /// account covering slashes under SlashPolicy::Cover
+        InsuranceAccount get(fn insurance_account): Option<T::AccountId>;
+        /// covered slashes bonded between total bonded before and after payout of an era, they are no reward
+        CoveredSlashes get(fn covered_slashes): map hasher(blake2_128_concat) EraIndex => BalanceOf<T>;
     }
 }
 
@@ -214,6 +273,7 @@ decl_module! {
                 return;
             }
             let era = op_active.unwrap().index;
+            Self::settle_pending_slashes(era);
 
             if rtoken_rate::EraRate::get(SYMBOL, era).is_some() {
                 return;
@@ -227,9 +287,10 @@ decl_module! {
             }
             let before = op_before.unwrap();
             let after = op_after.unwrap();
+            let rewarded = after.saturating_sub(Self::covered_slashes(era));
             let op_receiver = Self::receiver();
-            if after > before && op_receiver.is_some() {
-                let fee = (Self::commission() * (after - before)).saturated_into::<u128>();
+            if rewarded > before && op_receiver.is_some() {
+                let fee = (Self::commission() * (rewarded - before)).saturated_into::<u128>();
                 let rfis = rtoken_rate::Module::<T>::token_to_rtoken(SYMBOL, fee);
                 let receiver = op_receiver.unwrap();
                 if let Err(e) = <T as Trait>::RCurrency::mint(&receiver, SYMBOL, rfis) {
@@ -351,6 +412,42 @@ decl_module! {
 			Ok(())
         }
 
+        /// set weights of the nomination score
+        #[weight = 10_000]
+        fn set_score_weights(origin, weights: ScoreWeights) -> DispatchResult {
+            ensure_root(origin)?;
+            NominationScoreWeights::put(weights);
+            Self::deposit_event(RawEvent::ScoreWeightsSet(weights));
+            Ok(())
+        }
+
+        /// set how slashes of pools are borne
+        #[weight = 10_000]
+        fn set_slash_policy(origin, policy: SlashPolicy) -> DispatchResult {
+            ensure_root(origin)?;
+            PoolSlashPolicy::put(policy);
+            Self::deposit_event(RawEvent::SlashPolicySet(policy));
+            Ok(())
+        }
+
+        /// set insurance account
+        #[weight = 10_000]
+        pub fn set_insurance_account(origin, account: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
+            ensure_root(origin)?;
+            let dest = T::Lookup::lookup(account)?;
+            <InsuranceAccount<T>>::put(dest);
+            Ok(())
+        }
+
+        /// set eras slashed validators are not nominated for
+        #[weight = 10_000]
+        fn set_slash_exclusion_eras(origin, eras: EraIndex) -> DispatchResult {
+            ensure_root(origin)?;
+            SlashExclusionEras::put(eras);
+            Self::deposit_event(RawEvent::SlashExclusionErasSet(eras));
+            Ok(())
+        }
+
         /// Update pool balance limit
         #[weight = 10_000]
         fn set_balance_limit(origin, new_limit: BalanceOf<T>) -> DispatchResult {
@@ -473,7 +570,10 @@ decl_module! {
             }
 
             pools.sort_by(|a, b| Self::bonded_of(&b).cmp(&Self::bonded_of(&a)));
-            let mut validators = Self::nominatable_validators(last_era, era);
+            let candidates = Self::nomination_candidates(last_era, era);
+            let taken = Self::nominated_validators(era);
+            let weights = Self::score_weights();
+            let max_commission = Self::max_validator_commission();
             let min = Self::min_nomination_num();
             let max = Self::max_nomination_num();
             for p in pools {
@@ -481,23 +581,8 @@ decl_module! {
                     continue;
                 }
 
-                if validators.len() < min.into() {
-                    let call = Call::submit_nomination(era, p, vec![]).into();
-                    if let Err(e) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call) {
-                        debug::error!("failed to submit nomination: {:?}", e);
-                    }
-                    return;
-                }
-
-                let mut targets: Vec<T::AccountId> = Self::nominated(last_era, &p).unwrap_or(vec![]).into_iter().filter(|v| validators.contains(&v)).collect();
-                let mut op_val = validators.pop();
-                while targets.len() < max.into() && op_val.is_some() {
-                    let val = op_val.unwrap();
-                    if !targets.contains(&val) {
-                        targets.push(val);
-                    }
-                    op_val = validators.pop();
-                }
+                let previous = Self::nominated(last_era, &p).unwrap_or(vec![]);
+                let targets = select_targets(&candidates, &previous, &taken, min.into(), max.into(), &weights, max_commission);
 
                 let call = Call::submit_nomination(era, p, targets).into();
                 if let Err(e) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call) {
@@ -628,6 +713,7 @@ decl_module! {
             let mut ledger = staking::Ledger::<T>::get(&controller).ok_or(Error::<T>::PoolUnbond)?;
             let active_era_info = staking::ActiveEra::get().ok_or(Error::<T>::NoCurrentEra)?;
             ensure!(rtoken_rate::EraRate::get(SYMBOL, active_era_info.index).is_some(), Error::<T>::EraRateNotUpdated);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(SYMBOL), Error::<T>::RatePaused);
 
             let limit = Self::pool_balance_limit();
             let bonded = Self::bonded_of(&controller).checked_add(&value).ok_or(Error::<T>::Overflow)?;
@@ -660,6 +746,7 @@ decl_module! {
             let mut ledger = staking::Ledger::<T>::get(&controller).ok_or(Error::<T>::PoolUnbond)?;
             let active_era_info = staking::ActiveEra::get().ok_or(Error::<T>::NoCurrentEra)?;
             ensure!(rtoken_rate::EraRate::get(SYMBOL, active_era_info.index).is_some(), Error::<T>::EraRateNotUpdated);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(SYMBOL), Error::<T>::RatePaused);
 
             let limit = Self::pool_balance_limit();
             let bonded = Self::bonded_of(&controller).checked_add(&value).ok_or(Error::<T>::Overflow)?;
@@ -707,6 +794,7 @@ decl_module! {
             let mut ledger = staking::Ledger::<T>::get(&controller).ok_or(staking::Error::<T>::NotController)?;
             let active_era_info = staking::ActiveEra::get().ok_or(Error::<T>::NoCurrentEra)?;
             ensure!(rtoken_rate::EraRate::get(SYMBOL, active_era_info.index).is_some(), Error::<T>::EraRateNotUpdated);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(SYMBOL), Error::<T>::RatePaused);
 
             let free = <T as Trait>::RCurrency::free_balance(&who, SYMBOL);
             free.checked_sub(value).ok_or(Error::<T>::InsufficientBalance)?;
@@ -744,6 +832,61 @@ decl_module! {
             Ok(())
         }
 
+        /// rebond unbonding chunks not unlocked yet, latest first, to get rfis at the current rate.
+        /// the unbond commission already charged is not refunded
+        #[weight = 10_000_000_000]
+        pub fn rebond(origin, pool: <T::Lookup as StaticLookup>::Source, value: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::nominate_switch(), Error::<T>::NominateSwitchClosed);
+            ensure!(!value.is_zero(), Error::<T>::RebondZero);
+            ensure!(staking::EraElectionStatus::<T>::get().is_closed(), staking::Error::<T>::CallNotAllowed);
+            let controller = T::Lookup::lookup(pool)?;
+            ensure!(Self::is_in_pools(&controller), Error::<T>::PoolNotFound);
+            let mut ledger = staking::Ledger::<T>::get(&controller).ok_or(staking::Error::<T>::NotController)?;
+            let active_era_info = staking::ActiveEra::get().ok_or(Error::<T>::NoCurrentEra)?;
+            ensure!(rtoken_rate::EraRate::get(SYMBOL, active_era_info.index).is_some(), Error::<T>::EraRateNotUpdated);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(SYMBOL), Error::<T>::RatePaused);
+            let current_era = staking::CurrentEra::get().ok_or(Error::<T>::NoCurrentEra)?;
+
+            let limit = Self::pool_balance_limit();
+            let bonded = Self::bonded_of(&controller).checked_add(&value).ok_or(Error::<T>::Overflow)?;
+            ensure!(limit.is_zero() || bonded <= limit, Error::<T>::PoolLimitReached);
+
+            let mut unbonding = <Unbonding<T>>::get(&who, &controller).unwrap_or(vec![]);
+            let mut left = value;
+            for chunk in unbonding.iter_mut().rev().filter(|chunk| chunk.era > current_era) {
+                if left.is_zero() {
+                    break;
+                }
+                let rebonded = chunk.value.min(left);
+                let pool_chunk = ledger.unlocking.iter_mut()
+                    .find(|pool_chunk| pool_chunk.era == chunk.era && pool_chunk.value >= rebonded)
+                    .ok_or(Error::<T>::RebondTooBig)?;
+                pool_chunk.value -= rebonded;
+                chunk.value -= rebonded;
+                left -= rebonded;
+            }
+            ensure!(left.is_zero(), Error::<T>::RebondTooBig);
+            unbonding.retain(|chunk| !chunk.value.is_zero());
+            ledger.unlocking.retain(|chunk| !chunk.value.is_zero());
+            ledger.active = ledger.active.checked_add(&value).ok_or(Error::<T>::Overflow)?;
+
+            let v = value.saturated_into::<u128>();
+            let rbalance = rtoken_rate::Module::<T>::token_to_rtoken(SYMBOL, v);
+            <T as Trait>::RCurrency::mint(&who, SYMBOL, rbalance)?;
+            //update claim info
+            rclaim::Module::<T>::update_claim_info(&who, SYMBOL, rbalance, v);
+            staking::Module::<T>::update_ledger(&controller, &ledger);
+            if unbonding.is_empty() {
+                <Unbonding<T>>::remove(&who, &controller);
+            } else {
+                <Unbonding<T>>::insert(&who, &controller, unbonding);
+            }
+
+            Self::deposit_event(RawEvent::Rebond(who, controller, value, rbalance));
+            Ok(())
+        }
+
         /// liquitidy withdraw unbond: get undonded balance to free_balance
         #[weight = 100_000_000]
         pub fn liquidity_withdraw_unbond(origin, pool: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
@@ -808,16 +951,215 @@ impl<T: Trait> Module<T> {
         Self::pools().into_iter().filter(|p| staking::Ledger::<T>::get(&p).is_some()).collect()
     }
 
-    fn nominatable_validators(last_era: EraIndex, current_era: EraIndex) -> Vec<T::AccountId> {
-        let mut onboards = Self::onboard_validators();
-        let max_commission = Self::max_validator_commission();
-        let nomee = Self::nominated_validators(current_era);
-        onboards = onboards.into_iter()
-            .filter(|v| staking::Validators::<T>::contains_key(&v) && staking::Validators::<T>::get(&v).commission <= max_commission && !nomee.contains(&v))
+    /// onboard validators which are validating and not excluded by a slash, with what the nomination score needs
+    fn nomination_candidates(last_era: EraIndex, era: EraIndex) -> Vec<Candidate<T::AccountId>> {
+        Self::onboard_validators().into_iter()
+            .filter(|v| staking::Validators::<T>::contains_key(&v) && !Self::is_slash_excluded(&v, era))
+            .map(|v| Candidate {
+                commission: staking::Validators::<T>::get(&v).commission,
+                online: T::ValidatorStatus::is_online(&v),
+                offences: Self::recent_offence_count(&v, era),
+                stake: Self::validator_stake(last_era, &v).saturated_into::<u128>(),
+                who: v,
+            })
+            .collect()
+    }
+
+    fn is_slash_excluded(v: &T::AccountId, era: EraIndex) -> bool {
+        Self::slashed_validators(v).map_or(false, |slashed| era < slashed.saturating_add(Self::slash_exclusion_eras()))
+    }
+
+    /// offences reported against a validator in the last RECENT_OFFENCE_ERAS eras
+    fn recent_offence_count(v: &T::AccountId, era: EraIndex) -> u32 {
+        Self::recent_offences(v).into_iter().filter(|offence_era| offence_era.saturating_add(RECENT_OFFENCE_ERAS) > era).count() as u32
+    }
+
+    /// counts an offence reported against a validator, slashing or not, for the nomination score
+    pub fn note_offence_report(validator: &T::AccountId) -> Weight {
+        let era = match staking::ActiveEra::get() {
+            Some(info) => info.index,
+            None => return T::DbWeight::get().reads(1),
+        };
+        <RecentOffences<T>>::mutate(validator, |offences| {
+            offences.push(era);
+            if offences.len() > MAX_RECENT_OFFENCES {
+                offences.remove(0);
+            }
+        });
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+
+    /// era an offence in session belongs to, as staking reckons it
+    fn offence_era(session: SessionIndex, active_era: EraIndex) -> EraIndex {
+        let mut era = active_era;
+        for _ in 0..<T as staking::Trait>::BondingDuration::get() {
+            match staking::Module::<T>::eras_start_session_index(era) {
+                Some(start) if start > session && era > 0 => era -= 1,
+                _ => break,
+            }
+        }
+        era
+    }
+
+    /// slashes staking queued in era and has not applied or cancelled yet, none if
+    /// the layout of staking's slashes no longer matches QueuedSlash
+    fn queued_slashes(era: EraIndex) -> Option<Vec<QueuedSlash<T::AccountId, BalanceOf<T>>>> {
+        let queued: Result<Vec<_>, _> = staking::UnappliedSlashes::<T>::get(era).into_iter()
+            .map(|slash| QueuedSlash::<T::AccountId, BalanceOf<T>>::decode(&mut &slash.encode()[..]))
             .collect();
+        queued.map_err(|e| debug::error!("rfis queued slash decode err: {:?}", e)).ok()
+    }
+
+    /// era and number of the slashes staking has deferred so far, none if staking applies slashes at once
+    pub fn deferred_slashes() -> Option<(EraIndex, usize)> {
+        if <T as staking::Trait>::SlashDeferDuration::get() == 0 {
+            return None;
+        }
+        let era = staking::ActiveEra::get()?.index;
+        Some((era, staking::UnappliedSlashes::<T>::get(era).len()))
+    }
+
+    /// bonded of each bonded pool
+    pub fn pool_bonds() -> Vec<(T::AccountId, BalanceOf<T>)> {
+        Self::bonded_pools().into_iter().map(|pool| {
+            let bonded = Self::bonded_of(&pool);
+            (pool, bonded)
+        }).collect()
+    }
+
+    /// records the slashes staking took for an offence in session against validators, with their exposure
+    /// and slash fraction. slashes applied at once show in the bonded of pools, deferred ones are the fraction
+    /// of the exposure of pools, if staking queued slashes after `deferred`
+    pub fn note_offence(
+        offences: &[(T::AccountId, Exposure<T::AccountId, BalanceOf<T>>, Perbill)],
+        session: SessionIndex,
+        deferred: Option<(EraIndex, usize)>,
+        bonds: Vec<(T::AccountId, BalanceOf<T>)>,
+    ) -> Weight {
+        if offences.is_empty() {
+            return 0;
+        }
+        let active_era = match staking::ActiveEra::get() {
+            Some(info) => info.index,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let era = Self::offence_era(session, active_era);
+
+        let mut reads: Weight = 2 + <T as staking::Trait>::BondingDuration::get() as Weight;
+        let mut writes: Weight = 0;
+        let onboards = Self::onboard_validators();
+        for (validator, _, _) in offences {
+            if onboards.binary_search(validator).is_ok() {
+                <SlashedValidators<T>>::insert(validator, active_era);
+                writes += 1;
+            }
+        }
+
+        match deferred {
+            Some((queued_era, queued)) => {
+                reads += 1;
+                // staking queues nothing for offences too old or already slashed harder in the span
+                if staking::UnappliedSlashes::<T>::decode_len(queued_era).unwrap_or(0) <= queued {
+                    return T::DbWeight::get().reads_writes(reads, writes);
+                }
+                let pools: Vec<T::AccountId> = bonds.into_iter().map(|(pool, _)| pool).collect();
+                let mut pending = vec![];
+                for (validator, exposure, fraction) in offences {
+                    for other in &exposure.others {
+                        let value = *fraction * other.value;
+                        if pools.contains(&other.who) && !value.is_zero() {
+                            pending.push(PendingSlash { era, validator: validator.clone(), pool: other.who.clone(), value });
+                        }
+                    }
+                }
+                for slash in pending {
+                    <PendingPoolSlashes<T>>::append(queued_era, slash);
+                    writes += 1;
+                }
+            },
+            None => {
+                reads += bonds.len() as Weight;
+                for (pool, bonded) in bonds {
+                    let slash = bonded.saturating_sub(Self::bonded_of(&pool));
+                    if !slash.is_zero() {
+                        let (r, w) = Self::book_pool_slash(era, active_era, &pool, slash);
+                        reads += r;
+                        writes += w;
+                    }
+                }
+            },
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// books the deferred slashes staking applied at the start of active_era, and drops those cancelled
+    /// from the slashes staking applies at the start of the next era
+    fn settle_pending_slashes(active_era: EraIndex) {
+        let defer = <T as staking::Trait>::SlashDeferDuration::get();
+        if let Some(applied_era) = active_era.checked_sub(defer.saturating_add(1)) {
+            if <PendingPoolSlashes<T>>::contains_key(applied_era) {
+                for pending in <PendingPoolSlashes<T>>::take(applied_era) {
+                    Self::book_pool_slash(pending.era, active_era, &pending.pool, pending.value);
+                }
+            }
+        }
+
+        if let Some(next_era) = active_era.checked_sub(defer) {
+            if <PendingPoolSlashes<T>>::contains_key(next_era) {
+                // pending slashes are kept, rather than taken as cancelled, if staking's slashes can not be read
+                let queued = match Self::queued_slashes(next_era) {
+                    Some(queued) => queued,
+                    None => return,
+                };
+                let mut queued: Vec<(T::AccountId, T::AccountId, BalanceOf<T>)> = queued.into_iter()
+                    .flat_map(|slash| {
+                        let validator = slash.validator;
+                        slash.others.into_iter().map(move |(who, value)| (validator.clone(), who, value))
+                    })
+                    .collect();
+                <PendingPoolSlashes<T>>::mutate(next_era, |pendings| pendings.retain(|pending| {
+                    match queued.iter().position(|(v, who, value)| *v == pending.validator && *who == pending.pool && *value == pending.value) {
+                        Some(i) => {
+                            queued.swap_remove(i);
+                            true
+                        },
+                        None => false,
+                    }
+                }));
+            }
+        }
+    }
+
+    /// books a slash staking applied to a pool, covering it from the insurance account under SlashPolicy::Cover.
+    /// returns the reads and writes
+    fn book_pool_slash(era: EraIndex, active_era: EraIndex, pool: &T::AccountId, slash: BalanceOf<T>) -> (Weight, Weight) {
+        <PoolSlashes<T>>::mutate(era, pool, |total| *total = total.saturating_add(slash));
+        Self::deposit_event(RawEvent::PoolSlashed(era, pool.clone(), slash));
+        if Self::slash_policy() == SlashPolicy::Cover {
+            Self::cover_slash(active_era, pool, slash);
+            return (7, 5);
+        }
+        (2, 1)
+    }
+
+    fn cover_slash(era: EraIndex, pool: &T::AccountId, slash: BalanceOf<T>) {
+        let insurance = match Self::insurance_account() {
+            Some(account) => account,
+            None => return,
+        };
+        if let Err(e) = <T as staking::Trait>::Currency::transfer(&insurance, pool, slash, KeepAlive) {
+            debug::error!("rfis cover slash err: {:?}", e);
+            return;
+        }
+        if let Some(mut ledger) = staking::Ledger::<T>::get(pool) {
+            Self::bond_extra(pool, &mut ledger, slash);
+        }
+        if Self::total_bonded_before_payout(era).is_some() && Self::total_bonded_after_payout(era).is_none() {
+            <CoveredSlashes<T>>::mutate(era, |covered| *covered = covered.saturating_add(slash));
+        }
 
-        onboards.sort_by(|a, b| Self::validator_stake(last_era, &b).cmp(&Self::validator_stake(last_era, &a)));
-        onboards
+        Self::deposit_event(RawEvent::PoolSlashCovered(era, pool.clone(), slash));
     }
 
     fn validator_stake(era: EraIndex, v: &T::AccountId) -> BalanceOf<T> {
@@ -0,0 +1,309 @@
+use crate::{Module, Trait};
+use frame_support::{
+    impl_outer_dispatch, impl_outer_origin, parameter_types,
+    traits::{Currency, Get, OnFinalize},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    curve::PiecewiseLinear,
+    key_types,
+    testing::{Header, TestXt, UintAuthorityId},
+    traits::{BlakeTwo256, Convert, IdentityLookup, OpaqueKeys, SaturatedConversion},
+    transaction_validity::TransactionPriority,
+    KeyTypeId, Perbill,
+};
+use sp_staking::SessionIndex;
+use sp_std::cell::RefCell;
+use pallet_staking::{EraIndex, Exposure, IndividualExposure};
+use node_primitives::ChainId;
+
+pub(crate) type Balance = u128;
+
+thread_local! {
+    static SLASH_DEFER_DURATION: RefCell<EraIndex> = RefCell::new(0);
+}
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+impl_outer_dispatch! {
+    pub enum Call for Test where origin: Origin {
+        frame_system::System,
+        pallet_staking::Staking,
+        self::RFis,
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MinimumPeriod: u64 = 5;
+    pub const Period: u64 = 3;
+    pub const Offset: u64 = 0;
+    pub const DisabledValidatorsThreshold: Perbill = Perbill::from_percent(25);
+    pub const SessionsPerEra: SessionIndex = 3;
+    pub const BondingDuration: EraIndex = 3;
+    pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
+    pub const ElectionLookahead: u64 = 0;
+    pub const MaxIterations: u32 = 0;
+    pub const MinSolutionScoreBump: Perbill = Perbill::zero();
+    pub const MaxNominatorRewardedPerValidator: u32 = 64;
+    pub const StakingUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+    pub const RFisUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+    pub const ChainIdentity: ChainId = 1;
+    pub const ProposalLifetime: u64 = 50;
+}
+
+pallet_staking_reward_curve::build! {
+    const REWARD_CURVE: PiecewiseLinear<'static> = curve!(
+        min_inflation: 0_025_000,
+        max_inflation: 0_100_000,
+        ideal_stake: 0_500_000,
+        falloff: 0_050_000,
+        max_piece_count: 40,
+        test_precision: 0_005_000,
+    );
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Test;
+
+impl frame_system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = ();
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test where Call: From<C> {
+    type OverarchingCall = Call;
+    type Extrinsic = TestXt<Call, ()>;
+}
+
+impl Trait for Test {
+    type Event = ();
+    type RCurrency = RBalances;
+    type UnsignedPriority = RFisUnsignedPriority;
+    type ValidatorStatus = ();
+}
+
+impl pallet_balances::Trait for Test {
+    type MaxLocks = ();
+    type Balance = Balance;
+    type DustRemoval = ();
+    type Event = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+impl pallet_timestamp::Trait for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<u64> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [KeyTypeId] = &[key_types::DUMMY];
+    fn on_genesis_session<Ks: OpaqueKeys>(_validators: &[(u64, Ks)]) {}
+    fn on_new_session<Ks: OpaqueKeys>(_changed: bool, _validators: &[(u64, Ks)], _queued_validators: &[(u64, Ks)]) {}
+    fn on_disabled(_validator_index: usize) {}
+}
+
+impl pallet_session::Trait for Test {
+    type Event = ();
+    type ValidatorId = u64;
+    type ValidatorIdOf = pallet_staking::StashOf<Test>;
+    type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+    type SessionManager = pallet_session::historical::NoteHistoricalRoot<Test, Staking>;
+    type SessionHandler = TestSessionHandler;
+    type Keys = UintAuthorityId;
+    type DisabledValidatorsThreshold = DisabledValidatorsThreshold;
+    type WeightInfo = ();
+}
+
+impl pallet_session::historical::Trait for Test {
+    type FullIdentification = Exposure<u64, Balance>;
+    type FullIdentificationOf = pallet_staking::ExposureOf<Test>;
+}
+
+pub struct CurrencyToVoteHandler;
+impl Convert<Balance, u64> for CurrencyToVoteHandler {
+    fn convert(x: Balance) -> u64 {
+        x.saturated_into()
+    }
+}
+impl Convert<u128, Balance> for CurrencyToVoteHandler {
+    fn convert(x: u128) -> Balance {
+        x
+    }
+}
+
+impl pallet_staking::Trait for Test {
+    type Currency = Balances;
+    type UnixTime = Timestamp;
+    type CurrencyToVote = CurrencyToVoteHandler;
+    type RewardRemainder = ();
+    type Event = ();
+    type Slash = ();
+    type Reward = ();
+    type SessionsPerEra = SessionsPerEra;
+    type BondingDuration = BondingDuration;
+    type SlashDeferDuration = SlashDeferDuration;
+    type SlashCancelOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type SessionInterface = Self;
+    type RewardCurve = RewardCurve;
+    type NextNewSession = Session;
+    type ElectionLookahead = ElectionLookahead;
+    type Call = Call;
+    type MaxIterations = MaxIterations;
+    type MinSolutionScoreBump = MinSolutionScoreBump;
+    type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
+    type UnsignedPriority = StakingUnsignedPriority;
+    type WeightInfo = ();
+}
+
+impl rtoken_rate::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_registry::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_balances::Trait for Test {
+    type Event = ();
+}
+
+impl rclaim::Trait for Test {
+    type Event = ();
+    type Currency = Balances;
+    type WeightInfo = ();
+}
+
+impl bridge_relayers::Trait for Test {
+    type Event = ();
+}
+
+impl bridge_common::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type Proposal = Call;
+    type ChainIdentity = ChainIdentity;
+    type ProposalLifetime = ProposalLifetime;
+    type WeightInfo = ();
+}
+
+/// validator the pool nominates
+pub const VALIDATOR: u64 = 10;
+/// insurance account making up slashes under SlashPolicy::Cover
+pub const INSURANCE: u64 = 3;
+
+/// fis balances of accounts 1 to 3, at block 1 of era 0
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000_000), (2, 1_000_000), (INSURANCE, 1_000_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    rtoken_registry::GenesisConfig {
+        assets: rtoken_registry::builtin_assets(),
+    }
+    .assimilate_storage::<Test>(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        start_era(0);
+    });
+    ext
+}
+
+pub fn set_slash_defer_duration(eras: EraIndex) {
+    SLASH_DEFER_DURATION.with(|v| *v.borrow_mut() = eras);
+}
+
+/// finalizes the block and starts era in the next one, the way session rotation does
+pub fn start_era(era: EraIndex) {
+    RFis::on_finalize(System::block_number());
+    System::set_block_number(System::block_number() + 1);
+    let session = era * SessionsPerEra::get();
+    pallet_staking::ErasStartSessionIndex::insert(era, session);
+    pallet_staking::CurrentEra::put(era);
+    <Staking as pallet_session::SessionManager<u64>>::start_session(session);
+    assert_eq!(Staking::active_era().map(|info| info.index), Some(era));
+    RFis::on_finalize(System::block_number());
+}
+
+/// adds a pool bonded with `active` nominating VALIDATOR
+pub fn bond_pool(active: Balance) -> u64 {
+    RFis::add_new_pool(Origin::root(), b"rfispool".to_vec()).unwrap();
+    let pool = RFis::pools()[0];
+    RFis::bond_for_pool(Origin::root(), pool).unwrap();
+    let _ = Balances::deposit_creating(&pool, active + 1);
+    let mut ledger = Staking::ledger(&pool).unwrap();
+    ledger.total = active;
+    ledger.active = active;
+    Staking::update_ledger(&pool, &ledger);
+    pool
+}
+
+/// exposure of VALIDATOR, backed by `value` of pool
+pub fn exposure(pool: u64, value: Balance) -> Exposure<u64, Balance> {
+    Exposure { total: value, own: 0, others: vec![IndividualExposure { who: pool, value }] }
+}
+
+pub type RFis = Module<Test>;
+pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type RBalances = rtoken_balances::Module<Test>;
+pub type RClaim = rclaim::Module<Test>;
+pub type Timestamp = pallet_timestamp::Module<Test>;
+pub type Session = pallet_session::Module<Test>;
+pub type Staking = pallet_staking::Module<Test>;
+
+pub struct SlashDeferDuration;
+impl Get<EraIndex> for SlashDeferDuration {
+    fn get() -> EraIndex {
+        SLASH_DEFER_DURATION.with(|v| *v.borrow())
+    }
+}
@@ -0,0 +1,115 @@
+use sp_std::prelude::*;
+use codec::{Decode, Encode};
+use sp_runtime::{Perbill, RuntimeDebug};
+
+/// Liveness of validators, provided by the runtime. offences are counted by the pallet itself
+pub trait ValidatorStatus<AccountId> {
+    /// validator is known to be online in the current session
+    fn is_online(who: &AccountId) -> bool;
+}
+
+/// every validator is online
+impl<AccountId> ValidatorStatus<AccountId> for () {
+    fn is_online(_: &AccountId) -> bool {
+        true
+    }
+}
+
+/// weights of the parts of the nomination score
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct ScoreWeights {
+    /// lower commission within the max validator commission
+    pub commission: Perbill,
+    /// online in the current session
+    pub liveness: Perbill,
+    /// fewer recent offences
+    pub offence: Perbill,
+    /// lower stake than the most staked candidate
+    pub saturation: Perbill,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        ScoreWeights {
+            commission: Perbill::from_percent(25),
+            liveness: Perbill::from_percent(25),
+            offence: Perbill::from_percent(25),
+            saturation: Perbill::from_percent(25),
+        }
+    }
+}
+
+/// a validator which may be nominated
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Candidate<AccountId> {
+    pub who: AccountId,
+    pub commission: Perbill,
+    pub online: bool,
+    pub offences: u32,
+    /// total stake of the last era
+    pub stake: u128,
+}
+
+impl ScoreWeights {
+    /// score of a candidate whose commission is within max_commission, higher is better
+    pub fn score<A>(&self, candidate: &Candidate<A>, max_commission: Perbill, max_stake: u128) -> u64 {
+        let max = max_commission.deconstruct();
+        let commission = if max == 0 {
+            Perbill::one()
+        } else {
+            Perbill::from_rational_approximation(max.saturating_sub(candidate.commission.deconstruct()), max)
+        };
+        let liveness = if candidate.online { Perbill::one() } else { Perbill::zero() };
+        let offence = Perbill::from_rational_approximation(1, candidate.offences.saturating_add(1));
+        let saturation = if max_stake == 0 {
+            Perbill::one()
+        } else {
+            let saturated = Perbill::from_rational_approximation(candidate.stake.min(max_stake), max_stake);
+            Perbill::from_parts(Perbill::one().deconstruct() - saturated.deconstruct())
+        };
+
+        [(self.commission, commission), (self.liveness, liveness), (self.offence, offence), (self.saturation, saturation)]
+            .iter()
+            .map(|(weight, part)| (*weight * part.deconstruct()) as u64)
+            .sum()
+    }
+}
+
+/// picks up to `max` targets for a pool by score, none if fewer than `min` are available. candidates over
+/// max_commission or already taken by other pools are skipped, previous targets are kept while they are
+/// online and without offences
+pub fn select_targets<A: Clone + PartialEq>(
+    candidates: &[Candidate<A>],
+    previous: &[A],
+    taken: &[A],
+    min: usize,
+    max: usize,
+    weights: &ScoreWeights,
+    max_commission: Perbill,
+) -> Vec<A> {
+    let max_stake = candidates.iter().map(|c| c.stake).max().unwrap_or(0);
+    let mut scored: Vec<(u64, &Candidate<A>)> = candidates.iter()
+        .filter(|c| c.commission <= max_commission && !taken.contains(&c.who))
+        .map(|c| (weights.score(c, max_commission, max_stake), c))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut targets: Vec<A> = previous.iter()
+        .filter(|v| scored.iter().any(|(_, c)| &c.who == *v && c.online && c.offences == 0))
+        .take(max)
+        .cloned()
+        .collect();
+    for (_, c) in scored {
+        if targets.len() >= max {
+            break;
+        }
+        if !targets.contains(&c.who) {
+            targets.push(c.who.clone());
+        }
+    }
+
+    if targets.len() < min {
+        return vec![];
+    }
+    targets
+}
@@ -0,0 +1,79 @@
+use sp_std::{marker::PhantomData, prelude::*};
+use codec::{Decode, Encode};
+use frame_support::weights::Weight;
+use sp_runtime::{Perbill, RuntimeDebug, traits::Zero};
+use sp_staking::{SessionIndex, offence::{OffenceDetails, OnOffenceHandler}};
+use pallet_staking::{EraIndex, Exposure};
+use crate::{BalanceOf, Module, Trait};
+
+/// how slashes of pools are borne
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum SlashPolicy {
+    /// slashes lower the rfis rate of all holders
+    Socialize,
+    /// slashes are made up from the insurance account while it has enough
+    Cover,
+}
+
+impl Default for SlashPolicy {
+    fn default() -> Self {
+        SlashPolicy::Socialize
+    }
+}
+
+/// slash of a pool staking has deferred
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct PendingSlash<AccountId, Balance> {
+    /// era of the offence
+    pub era: EraIndex,
+    /// the offending validator
+    pub validator: AccountId,
+    /// the pool nominating it
+    pub pool: AccountId,
+    /// slash of the pool
+    pub value: Balance,
+}
+
+/// layout of a slash queued by staking, whose fields staking keeps private.
+/// Only read to tell the deferred slashes staking cancelled
+#[derive(Encode, Decode, RuntimeDebug)]
+pub struct QueuedSlash<AccountId, Balance> {
+    pub validator: AccountId,
+    pub own: Balance,
+    pub others: Vec<(AccountId, Balance)>,
+    pub reporters: Vec<AccountId>,
+    pub payout: Balance,
+}
+
+/// Offence handler passing offences on to `H`, usually staking, and recording the
+/// slashes `H` takes from the pools, either at once or once it applies its deferred slashes
+pub struct SlashRecorder<T, H>(PhantomData<(T, H)>);
+
+impl<T: Trait, H> OnOffenceHandler<T::AccountId, (T::AccountId, Exposure<T::AccountId, BalanceOf<T>>), Weight>
+    for SlashRecorder<T, H> where
+    H: OnOffenceHandler<T::AccountId, (T::AccountId, Exposure<T::AccountId, BalanceOf<T>>), Weight>,
+{
+    fn on_offence(
+        offenders: &[OffenceDetails<T::AccountId, (T::AccountId, Exposure<T::AccountId, BalanceOf<T>>)>],
+        slash_fraction: &[Perbill],
+        session: SessionIndex,
+    ) -> Result<Weight, ()> {
+        let deferred = Module::<T>::deferred_slashes();
+        let bonds = Module::<T>::pool_bonds();
+        // an offence `H` can not take yet is reported again later, it is recorded then
+        let weight = H::on_offence(offenders, slash_fraction, session)?;
+        let mut slashed = vec![];
+        let weight = offenders.iter().zip(slash_fraction).fold(weight, |weight, (details, fraction)| {
+            let (validator, exposure) = &details.offender;
+            if !fraction.is_zero() {
+                slashed.push((validator.clone(), exposure.clone(), *fraction));
+            }
+            weight.saturating_add(Module::<T>::note_offence_report(validator))
+        });
+        Ok(weight.saturating_add(Module::<T>::note_offence(&slashed, session, deferred, bonds)))
+    }
+
+    fn can_report() -> bool {
+        H::can_report()
+    }
+}
@@ -0,0 +1,252 @@
+use super::{
+    select_targets, Candidate, ScoreWeights, SlashRecorder, SlashPolicy, PendingSlash, OnboardValidators,
+    NominateSwitch, Unbonding, Error, SYMBOL,
+};
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_staking::UnlockChunk;
+use sp_runtime::Perbill;
+use sp_staking::{SessionIndex, offence::{OffenceDetails, OnOffenceHandler}};
+
+fn candidate(who: u64, commission: u32, online: bool, offences: u32, stake: u128) -> Candidate<u64> {
+    Candidate { who, commission: Perbill::from_percent(commission), online, offences, stake }
+}
+
+fn max_commission() -> Perbill {
+    Perbill::from_percent(10)
+}
+
+#[test]
+fn commission_over_max_should_be_skipped() {
+    let candidates = vec![candidate(1, 20, true, 0, 100), candidate(2, 5, true, 0, 100), candidate(3, 10, true, 0, 100)];
+
+    let targets = select_targets(&candidates, &[], &[], 1, 3, &ScoreWeights::default(), max_commission());
+
+    assert_eq!(targets, vec![2, 3]);
+}
+
+#[test]
+fn offline_and_offended_should_rank_lower() {
+    let candidates = vec![candidate(1, 5, false, 0, 100), candidate(2, 5, true, 2, 100), candidate(3, 5, true, 0, 100)];
+
+    let targets = select_targets(&candidates, &[], &[], 1, 3, &ScoreWeights::default(), max_commission());
+
+    assert_eq!(targets, vec![3, 2, 1]);
+}
+
+#[test]
+fn saturated_stake_should_rank_lower() {
+    let candidates = vec![candidate(1, 5, true, 0, 1000), candidate(2, 5, true, 0, 500), candidate(3, 5, true, 0, 10)];
+
+    let targets = select_targets(&candidates, &[], &[], 1, 2, &ScoreWeights::default(), max_commission());
+
+    assert_eq!(targets, vec![3, 2]);
+}
+
+#[test]
+fn weights_should_decide_ranking() {
+    // 1 has the lowest commission, 2 the lowest stake
+    let candidates = vec![candidate(1, 0, true, 0, 1000), candidate(2, 9, true, 0, 10)];
+    let commission_only = ScoreWeights {
+        commission: Perbill::one(),
+        liveness: Perbill::zero(),
+        offence: Perbill::zero(),
+        saturation: Perbill::zero(),
+    };
+    let saturation_only = ScoreWeights {
+        commission: Perbill::zero(),
+        liveness: Perbill::zero(),
+        offence: Perbill::zero(),
+        saturation: Perbill::one(),
+    };
+
+    assert_eq!(select_targets(&candidates, &[], &[], 1, 1, &commission_only, max_commission()), vec![1]);
+    assert_eq!(select_targets(&candidates, &[], &[], 1, 1, &saturation_only, max_commission()), vec![2]);
+}
+
+#[test]
+fn taken_validators_should_be_spread() {
+    let candidates = vec![candidate(1, 5, true, 0, 10), candidate(2, 5, true, 0, 20), candidate(3, 5, true, 0, 30), candidate(4, 5, true, 0, 40)];
+    let weights = ScoreWeights::default();
+
+    let first = select_targets(&candidates, &[], &[], 1, 2, &weights, max_commission());
+    let second = select_targets(&candidates, &[], &first, 1, 2, &weights, max_commission());
+
+    assert_eq!(first, vec![1, 2]);
+    assert_eq!(second, vec![3, 4]);
+}
+
+#[test]
+fn healthy_previous_targets_should_be_kept() {
+    let candidates = vec![candidate(1, 5, true, 0, 10), candidate(2, 5, true, 0, 20), candidate(3, 5, false, 0, 5), candidate(4, 5, true, 0, 1000)];
+
+    let targets = select_targets(&candidates, &[4, 3], &[], 1, 2, &ScoreWeights::default(), max_commission());
+
+    assert_eq!(targets, vec![4, 1]);
+}
+
+#[test]
+fn fewer_than_min_should_select_none() {
+    let candidates = vec![candidate(1, 5, true, 0, 10), candidate(2, 50, true, 0, 20), candidate(3, 5, true, 0, 30)];
+
+    assert!(select_targets(&candidates, &[], &[3], 2, 4, &ScoreWeights::default(), max_commission()).is_empty());
+    assert_eq!(select_targets(&candidates, &[], &[], 2, 4, &ScoreWeights::default(), max_commission()), vec![1, 3]);
+}
+
+/// reports VALIDATOR for an offence in session, backed by the whole bonded of pool
+fn report_offence(pool: u64, session: SessionIndex, fraction: u32) {
+    let offenders = [OffenceDetails { offender: (VALIDATOR, exposure(pool, 1000)), reporters: vec![] }];
+    assert!(SlashRecorder::<Test, Staking>::on_offence(&offenders, &[Perbill::from_percent(fraction)], session).is_ok());
+}
+
+fn pool_active(pool: u64) -> Balance {
+    Staking::ledger(&pool).unwrap().active
+}
+
+#[test]
+fn slash_applied_at_once_should_be_booked_in_offence_era() {
+    new_test_ext().execute_with(|| {
+        set_slash_defer_duration(0);
+        let pool = bond_pool(1000);
+        OnboardValidators::<Test>::put(vec![VALIDATOR]);
+        start_era(1);
+        start_era(2);
+
+        // session 4 is in era 1
+        report_offence(pool, 4, 10);
+
+        assert_eq!(pool_active(pool), 900);
+        assert_eq!(RFis::pool_slashes(1, pool), 100);
+        assert_eq!(RFis::pool_slashes(2, pool), 0);
+        assert_eq!(RFis::slashed_validators(VALIDATOR), Some(2));
+        assert_eq!(RFis::recent_offences(VALIDATOR), vec![2]);
+        assert!(RFis::pending_pool_slashes(2).is_empty());
+    });
+}
+
+#[test]
+fn deferred_slash_should_be_booked_once_applied() {
+    new_test_ext().execute_with(|| {
+        set_slash_defer_duration(2);
+        let pool = bond_pool(1000);
+        start_era(1);
+        start_era(2);
+
+        report_offence(pool, 6, 10);
+
+        assert_eq!(pool_active(pool), 1000);
+        assert_eq!(RFis::pool_slashes(2, pool), 0);
+        assert_eq!(RFis::pending_pool_slashes(2), vec![PendingSlash { era: 2, validator: VALIDATOR, pool, value: 100 }]);
+
+        start_era(3);
+        start_era(4);
+        assert_eq!(pool_active(pool), 1000);
+        assert_eq!(RFis::pool_slashes(2, pool), 0);
+        assert_eq!(RFis::pending_pool_slashes(2).len(), 1);
+
+        // staking applies the slashes of era 2 once era 2 + 2 is over
+        start_era(5);
+        assert_eq!(pool_active(pool), 900);
+        assert_eq!(RFis::pool_slashes(2, pool), 100);
+        assert!(RFis::pending_pool_slashes(2).is_empty());
+    });
+}
+
+#[test]
+fn queued_slash_should_match_staking_layout() {
+    new_test_ext().execute_with(|| {
+        set_slash_defer_duration(2);
+        let pool = bond_pool(1000);
+        start_era(1);
+        start_era(2);
+        report_offence(pool, 6, 10);
+
+        let queued = RFis::queued_slashes(2).unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].validator, VALIDATOR);
+        assert_eq!(queued[0].others, vec![(pool, 100)]);
+    });
+}
+
+#[test]
+fn cancelled_deferred_slash_should_not_be_booked() {
+    new_test_ext().execute_with(|| {
+        set_slash_defer_duration(2);
+        let pool = bond_pool(1000);
+        start_era(1);
+        start_era(2);
+        report_offence(pool, 6, 10);
+
+        start_era(3);
+        assert_ok!(Staking::cancel_deferred_slash(Origin::root(), 2, vec![0]));
+        start_era(4);
+        assert!(RFis::pending_pool_slashes(2).is_empty());
+
+        start_era(5);
+        assert_eq!(pool_active(pool), 1000);
+        assert_eq!(RFis::pool_slashes(2, pool), 0);
+        assert!(!crate::PendingPoolSlashes::<Test>::contains_key(2));
+    });
+}
+
+#[test]
+fn slash_should_be_covered_by_insurance() {
+    new_test_ext().execute_with(|| {
+        set_slash_defer_duration(0);
+        let pool = bond_pool(1000);
+        assert_ok!(RFis::set_slash_policy(Origin::root(), SlashPolicy::Cover));
+        assert_ok!(RFis::set_insurance_account(Origin::root(), INSURANCE));
+        start_era(1);
+
+        report_offence(pool, 3, 10);
+
+        assert_eq!(RFis::pool_slashes(1, pool), 100);
+        assert_eq!(pool_active(pool), 1000);
+        assert_eq!(Balances::free_balance(INSURANCE), 1_000_000 - 100);
+    });
+}
+
+/// pool bonded with 1000 at era 1, 100 of which unbonding by 1 until era 4
+fn rebond_setup() -> u64 {
+    let pool = bond_pool(1000);
+    start_era(1);
+    NominateSwitch::put(true);
+    rtoken_rate::EraRate::insert(SYMBOL, 1, rtoken_rate::RATEBASE);
+    let _ = Balances::deposit_creating(&pool, 100);
+    let mut ledger = Staking::ledger(&pool).unwrap();
+    ledger.total = 1100;
+    ledger.unlocking = vec![UnlockChunk { value: 100, era: 4 }];
+    Staking::update_ledger(&pool, &ledger);
+    Unbonding::<Test>::insert(1, pool, vec![UnlockChunk { value: 100, era: 4 }]);
+    pool
+}
+
+#[test]
+fn rebond_should_restore_unbonding_and_update_claim_info() {
+    new_test_ext().execute_with(|| {
+        let pool = rebond_setup();
+        assert_ok!(RClaim::add_rtoken_reward_act(Origin::root(), 1, 100, SYMBOL, 1_000_000, 0, 10, rtoken_rate::RATEBASE));
+
+        assert_noop!(RFis::rebond(Origin::signed(1), pool, 101), Error::<Test>::RebondTooBig);
+        assert_ok!(RFis::rebond(Origin::signed(1), pool, 60));
+
+        let ledger = Staking::ledger(&pool).unwrap();
+        assert_eq!(ledger.active, 1060);
+        assert_eq!(ledger.unlocking, vec![UnlockChunk { value: 40, era: 4 }]);
+        assert_eq!(RFis::unbonding(1, pool), Some(vec![UnlockChunk { value: 40, era: 4 }]));
+        assert_eq!(RBalances::free_balance(&1, SYMBOL), 60);
+        assert_eq!(RClaim::user_mints_count((1, SYMBOL, 1)), 1);
+    });
+}
+
+#[test]
+fn rebond_should_respect_pool_balance_limit() {
+    new_test_ext().execute_with(|| {
+        let pool = rebond_setup();
+        assert_ok!(RFis::set_balance_limit(Origin::root(), 1050));
+
+        assert_noop!(RFis::rebond(Origin::signed(1), pool, 60), Error::<Test>::PoolLimitReached);
+        assert_ok!(RFis::rebond(Origin::signed(1), pool, 50));
+        assert_eq!(Staking::ledger(&pool).unwrap().active, 1050);
+    });
+}
@@ -0,0 +1,35 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for user bonds and unbonds of rtoken series.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use codec::Codec;
+use node_primitives::RSymbol;
+use rtoken_series::{UserUnlockChunk, UnlockState, BondRecord, BondState, InvariantViolation};
+
+sp_api::decl_runtime_apis! {
+    pub trait RTokenSeriesApi<AccountId, Hash> where
+        AccountId: Codec,
+        Hash: Codec,
+    {
+        /// unbonds of a user with their unlock state
+        fn unbond_status(who: AccountId, symbol: RSymbol) -> Vec<(UserUnlockChunk, UnlockState)>;
+        /// bond records of a user with their ids and bond state
+        fn bond_records(who: AccountId, symbol: RSymbol) -> Vec<(Hash, BondRecord<AccountId>, Option<BondState>)>;
+        /// accounting invariants of ledger and series broken for a symbol
+        fn check_invariants(symbol: RSymbol) -> Vec<InvariantViolation>;
+        /// pool a bond of amount should be sent to, for symbols in auto pool selection
+        fn select_bond_pool(symbol: RSymbol, amount: u128) -> Option<Vec<u8>>;
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for user bonds and unbonds of rtoken series.
+
+use std::sync::Arc;
+use codec::Codec;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use serde::{Serialize, Deserialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use node_primitives::RSymbol;
+use rtoken_series::{UserUnlockChunk, UnlockState, BondRecord, BondState, InvariantCheck, InvariantViolation};
+use rtoken_ledger_rpc::{to_number_or_hex, to_u128, runtime_error_into_rpc_err};
+pub use rtoken_series_rpc_runtime_api::RTokenSeriesApi as RTokenSeriesRuntimeApi;
+
+/// Unbond of a user, payable to the recipient from the unlock era
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnbondStatus {
+    pub pool: Bytes,
+    pub unlock_era: u32,
+    pub value: NumberOrHex,
+    pub recipient: Bytes,
+    pub state: UnlockState,
+}
+
+impl From<(UserUnlockChunk, UnlockState)> for UnbondStatus {
+    fn from((chunk, state): (UserUnlockChunk, UnlockState)) -> Self {
+        Self {
+            pool: chunk.pool.into(),
+            unlock_era: chunk.unlock_era,
+            value: to_number_or_hex(chunk.value),
+            recipient: chunk.recipient.into(),
+            state,
+        }
+    }
+}
+
+/// Bond record of a user, state is none if the record was never executed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BondInfo<Hash> {
+    pub bond_id: Hash,
+    pub pubkey: Bytes,
+    pub pool: Bytes,
+    pub blockhash: Bytes,
+    pub txhash: Bytes,
+    pub amount: NumberOrHex,
+    pub state: Option<BondState>,
+}
+
+impl<AccountId, Hash> From<(Hash, BondRecord<AccountId>, Option<BondState>)> for BondInfo<Hash> {
+    fn from((bond_id, record, state): (Hash, BondRecord<AccountId>, Option<BondState>)) -> Self {
+        Self {
+            bond_id,
+            pubkey: record.pubkey.into(),
+            pool: record.pool.into(),
+            blockhash: record.blockhash.into(),
+            txhash: record.txhash.into(),
+            amount: to_number_or_hex(record.amount),
+            state,
+        }
+    }
+}
+
+/// Broken accounting invariant, pool is none for the symbol wide checks
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    pub symbol: RSymbol,
+    pub pool: Option<Bytes>,
+    pub check: InvariantCheck,
+    pub era: u32,
+    pub actual: NumberOrHex,
+    pub expected: NumberOrHex,
+}
+
+impl From<InvariantViolation> for Violation {
+    fn from(violation: InvariantViolation) -> Self {
+        Self {
+            symbol: violation.symbol,
+            pool: violation.pool.map(Into::into),
+            check: violation.check,
+            era: violation.era,
+            actual: to_number_or_hex(violation.actual),
+            expected: to_number_or_hex(violation.expected),
+        }
+    }
+}
+
+#[rpc]
+pub trait RTokenSeriesApi<BlockHash, AccountId, Hash> {
+    /// unbonds of a user with their unlock era and state
+    #[rpc(name = "rtoken_unbondStatus")]
+    fn unbond_status(&self, who: AccountId, symbol: RSymbol, at: Option<BlockHash>) -> Result<Vec<UnbondStatus>>;
+
+    /// bond records of a user with their state
+    #[rpc(name = "rtoken_bondRecords")]
+    fn bond_records(&self, who: AccountId, symbol: RSymbol, at: Option<BlockHash>) -> Result<Vec<BondInfo<Hash>>>;
+
+    /// accounting invariants of ledger and series broken for a symbol
+    #[rpc(name = "rtoken_checkInvariants")]
+    fn check_invariants(&self, symbol: RSymbol, at: Option<BlockHash>) -> Result<Vec<Violation>>;
+
+    /// pool a bond of amount should be sent to, for symbols in auto pool selection
+    #[rpc(name = "rtoken_selectBondPool")]
+    fn select_bond_pool(&self, symbol: RSymbol, amount: NumberOrHex, at: Option<BlockHash>) -> Result<Option<Bytes>>;
+}
+
+/// An implementation of rtoken series specific RPC methods.
+pub struct RTokenSeries<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> RTokenSeries<C, B> {
+    /// Create new `RTokenSeries` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, AccountId, Hash> RTokenSeriesApi<<Block as BlockT>::Hash, AccountId, Hash> for RTokenSeries<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: RTokenSeriesRuntimeApi<Block, AccountId, Hash>,
+    AccountId: Codec,
+    Hash: Codec,
+{
+    fn unbond_status(&self, who: AccountId, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<UnbondStatus>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.unbond_status(&at, who, symbol)
+            .map(|chunks| chunks.into_iter().map(Into::into).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn bond_records(&self, who: AccountId, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<BondInfo<Hash>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.bond_records(&at, who, symbol)
+            .map(|records| records.into_iter().map(Into::into).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn check_invariants(&self, symbol: RSymbol, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<Violation>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.check_invariants(&at, symbol)
+            .map(|violations| violations.into_iter().map(Into::into).collect())
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn select_bond_pool(&self, symbol: RSymbol, amount: NumberOrHex, at: Option<<Block as BlockT>::Hash>) -> Result<Option<Bytes>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let amount = to_u128(amount)?;
+
+        api.select_bond_pool(&at, symbol, amount)
+            .map(|pool| pool.map(Into::into))
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
@@ -0,0 +1,372 @@
+//! Benchmarks for the RTokenSeries Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use hex_literal::hex;
+use node_primitives::ETH_CHAIN_ID;
+
+const SEED: u32 = 0;
+const MAX_VALIDATORS: u32 = 16;
+const AMOUNT: u128 = 1_000_000_000_000;
+/// enough to pay the default bond and unbond fees
+const FUNDS: u128 = 1_000_000_000_000_000;
+const DEST_ID: ChainId = ETH_CHAIN_ID;
+/// solana pools verify ed25519 signatures, so the bond signature is signed by an ed25519 key
+const SYMBOL: RSymbol = RSymbol::RSOL;
+/// ed25519 pubkey and its signature of the ascii hex of the encoded `whitelisted_caller`
+const PUBKEY: [u8; 32] = hex!("96dbc2747898cab3fd9b8cde80efc58cec3fe3d35ab37d89821cae9781da1194");
+const SIGNATURE: [u8; 64] = hex!("51443d59119eeab759c102797e0120c2cc399111ac2cb4d1827f1b23fa59fbc114a53a269d18bee48b73f1d2923b1ae4965a3deb66ba154c967fa8ee09ea820d");
+
+fn funded_account<T: Trait>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    <T as Trait>::Currency::make_free_balance_be(&who, FUNDS.saturated_into());
+    who
+}
+
+fn bonded_pool<T: Trait>() -> Vec<u8> {
+    let pool = account::<T::AccountId>("pool", 0, SEED).encode();
+    ledger::Pools::append(SYMBOL, &pool);
+    ledger::BondedPools::append(SYMBOL, &pool);
+    ledger::BondPipelines::insert(SYMBOL, &pool, ledger::LinkChunk {bond: 0, unbond: 0, active: AMOUNT});
+    pool
+}
+
+fn validators<T: Trait>(name: &'static str, n: u32) -> Vec<Vec<u8>> {
+    (0..n).map(|i| account::<T::AccountId>(name, i, SEED).encode()).collect()
+}
+
+fn prepare_bond<T: Trait>() -> (T::AccountId, Vec<u8>) {
+    let caller: T::AccountId = whitelisted_caller();
+    <T as Trait>::Currency::make_free_balance_be(&caller, FUNDS.saturated_into());
+    <RelayFeesReceiver<T>>::put(funded_account::<T>("receiver", 0));
+    (caller, bonded_pool::<T>())
+}
+
+fn prepare_swap<T: Trait>() {
+    let _ = <bridge::Module<T>>::whitelist_chain(RawOrigin::Root.into(), DEST_ID);
+    let _ = <bridge::Module<T>>::set_fees_recipient_account(RawOrigin::Root.into(), funded_account::<T>("swap_receiver", 0));
+    bridge::ChainFees::insert(DEST_ID, 1_000_000_000);
+    let _ = registry::Module::<T>::set_resource_id(SYMBOL, Some(bridge::derive_resource_id(DEST_ID, b"rsol")));
+    <T as Trait>::Currency::make_free_balance_be(&<bridge::Module<T>>::account_id(), FUNDS.saturated_into());
+}
+
+fn dealing_bond<T: Trait>(swap: bool) -> T::Hash {
+    let pool = bonded_pool::<T>();
+    let bonder = funded_account::<T>("bonder", 0);
+    let record = BondRecord::new(bonder.clone(), SYMBOL, PUBKEY.to_vec(), pool, b"blockhash".to_vec(), b"txhash".to_vec(), AMOUNT);
+    let bond_id = <T::Hashing as Hash>::hash_of(&record);
+    <BondStates>::insert(SYMBOL, (&record.blockhash, &record.txhash), BondState::Dealing);
+    <BondRecords<T>>::insert(SYMBOL, &bond_id, &record);
+    rtoken_rate::Module::<T>::set_rate(SYMBOL, 0, 0);
+    if swap {
+        prepare_swap::<T>();
+        let bridger = <bridge::Module<T>>::account_id();
+        let bond_swap = BondSwap {bonder, swap_fee: 1_000_000_000, swap_receiver: funded_account::<T>("swap_receiver", 0), bridger,
+            recipient: vec![1u8; 20], dest_id: DEST_ID, expire: Zero::zero(), bond_state: BondState::Dealing, refunded: false};
+        <BondSwaps<T>>::insert(SYMBOL, &bond_id, bond_swap);
+    }
+    bond_id
+}
+
+benchmarks! {
+    _ { }
+
+    toggle_bond_switch {
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(!BondSwitch::get());
+    }
+
+    toggle_rtoken_bond_switch {
+    }: _(RawOrigin::Root, SYMBOL)
+    verify {
+        assert!(!RtokenBondSwitch::get(SYMBOL));
+    }
+
+    set_relay_fees_receiver {
+        let receiver: T::AccountId = account("receiver", 0, SEED);
+    }: _(RawOrigin::Root, receiver.clone())
+    verify {
+        assert_eq!(<RelayFeesReceiver<T>>::get(), Some(receiver));
+    }
+
+    set_proxy_accounts {
+        let proxy: T::AccountId = account("proxy", 0, SEED);
+    }: _(RawOrigin::Root, proxy.clone())
+    verify {
+        assert!(<ProxyAccounts<T>>::contains_key(&proxy));
+    }
+
+    remove_proxy_accounts {
+        let proxy: T::AccountId = account("proxy", 0, SEED);
+        <ProxyAccounts<T>>::insert(&proxy, 0);
+    }: _(RawOrigin::Root, proxy.clone())
+    verify {
+        assert!(!<ProxyAccounts<T>>::contains_key(&proxy));
+    }
+
+    set_bond_fees {
+        let proxy: T::AccountId = whitelisted_caller();
+        <ProxyAccounts<T>>::insert(&proxy, 0);
+    }: _(RawOrigin::Signed(proxy), SYMBOL, 1_000)
+    verify {
+        assert_eq!(BondFees::get(SYMBOL), 1_000);
+    }
+
+    set_unbond_fees {
+        let proxy: T::AccountId = whitelisted_caller();
+        <ProxyAccounts<T>>::insert(&proxy, 0);
+    }: _(RawOrigin::Signed(proxy), SYMBOL, 1_000)
+    verify {
+        assert_eq!(UnbondFees::get(SYMBOL), 1_000);
+    }
+
+    set_balance_limit {
+    }: _(RawOrigin::Root, SYMBOL, AMOUNT)
+    verify {
+        assert_eq!(PoolBalanceLimit::get(SYMBOL), AMOUNT);
+    }
+
+    set_unbond_commission {
+    }: _(RawOrigin::Root, 3_000_000)
+    verify {
+        assert_eq!(UnbondCommission::get(), Perbill::from_parts(3_000_000));
+    }
+
+    init_nominations {
+        let pool = bonded_pool::<T>();
+        let new_validators = validators::<T>("validator", MAX_VALIDATORS);
+    }: _(RawOrigin::Root, SYMBOL, pool.clone(), new_validators)
+    verify {
+        assert!(Nominated::get(SYMBOL, &pool).is_some());
+    }
+
+    update_nominations {
+        let pool = bonded_pool::<T>();
+        Nominated::insert(SYMBOL, &pool, validators::<T>("validator", MAX_VALIDATORS));
+        ledger::LastVoter::<T>::insert(SYMBOL, account::<T::AccountId>("voter", 0, SEED));
+        ledger::ChainEras::insert(SYMBOL, 1);
+        let new_validators = validators::<T>("new_validator", MAX_VALIDATORS);
+    }: _(RawOrigin::Root, SYMBOL, pool.clone(), new_validators.clone(), 1)
+    verify {
+        assert_eq!(Nominated::get(SYMBOL, &pool), Some(new_validators));
+    }
+
+    update_validator {
+        let pool = bonded_pool::<T>();
+        let old_validators = validators::<T>("validator", MAX_VALIDATORS);
+        let old_validator = old_validators[0].clone();
+        Nominated::insert(SYMBOL, &pool, old_validators);
+        let new_validator = account::<T::AccountId>("new_validator", 0, SEED).encode();
+    }: _(RawOrigin::Root, SYMBOL, pool.clone(), old_validator, new_validator.clone(), 1)
+    verify {
+        assert!(Nominated::get(SYMBOL, &pool).unwrap_or_default().contains(&new_validator));
+    }
+
+    swap_refund_expire {
+        let number: T::BlockNumber = 100u32.into();
+    }: _(RawOrigin::Root, SYMBOL, number)
+    verify {
+        assert_eq!(<BondSwapRefundExpire<T>>::get(SYMBOL), Some(number));
+    }
+
+    liquidity_bond {
+        let (caller, pool) = prepare_bond::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), PUBKEY.to_vec(), SIGNATURE.to_vec(), pool, b"blockhash".to_vec(), b"txhash".to_vec(), AMOUNT, SYMBOL)
+    verify {
+        assert_eq!(<AccountBondCount<T>>::get(SYMBOL, &caller), 1);
+    }
+
+    liquidity_bond_and_swap {
+        let (caller, pool) = prepare_bond::<T>();
+        prepare_swap::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), PUBKEY.to_vec(), SIGNATURE.to_vec(), pool, b"blockhash".to_vec(), b"txhash".to_vec(), AMOUNT, SYMBOL, vec![1u8; 20], DEST_ID)
+    verify {
+        assert_eq!(<AccountBondCount<T>>::get(SYMBOL, &caller), 1);
+    }
+
+    execute_bond_record {
+        let bond_id = dealing_bond::<T>(true);
+        let origin = T::VoterOrigin::successful_origin();
+    }: _(origin, SYMBOL, bond_id.clone(), BondReason::Pass)
+    verify {
+        assert_eq!(<BondReasons<T>>::get(SYMBOL, &bond_id), Some(BondReason::Pass));
+    }
+
+    liquidity_unbond {
+        let caller: T::AccountId = whitelisted_caller();
+        <T as Trait>::Currency::make_free_balance_be(&caller, FUNDS.saturated_into());
+        let pool = bonded_pool::<T>();
+        ledger::ChainEras::insert(SYMBOL, 1);
+        ledger::ChainBondingDuration::insert(SYMBOL, 2);
+        <ledger::Receiver<T>>::put(account::<T::AccountId>("receiver", 0, SEED));
+        <RelayFeesReceiver<T>>::put(funded_account::<T>("relay_receiver", 0));
+        UnbondFees::insert(SYMBOL, 1_000);
+        rtoken_rate::Module::<T>::set_rate(SYMBOL, 0, 0);
+        <T as Trait>::RCurrency::mint(&caller, SYMBOL, AMOUNT)?;
+        let chunks: Vec<UserUnlockChunk> = (0..MAX_UNLOCKING_CHUNKS as u32 - 1)
+            .map(|i| UserUnlockChunk {pool: pool.clone(), unlock_era: i + 1, value: 1, recipient: PUBKEY.to_vec()})
+            .collect();
+        AccountUnbonds::<T>::insert(&caller, SYMBOL, chunks);
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, pool, AMOUNT / 2, PUBKEY.to_vec())
+    verify {
+        assert_eq!(AccountUnbonds::<T>::get(&caller, SYMBOL).unwrap_or_default().len(), MAX_UNLOCKING_CHUNKS);
+    }
+
+    submit_signatures {
+        let caller: T::AccountId = whitelisted_caller();
+        relayers::Relayers::<T>::insert(SYMBOL, &caller, true);
+        let pool = bonded_pool::<T>();
+        ledger::ChainEras::insert(SYMBOL, 1);
+        ledger::MultiThresholds::insert(SYMBOL, &pool, 1);
+        // the signer is the last sub account, so that all of them are tried
+        let mut sub_accounts: Vec<Vec<u8>> = (0..MAX_VALIDATORS).map(|i| [i as u8; 32].to_vec()).collect();
+        sub_accounts.push(PUBKEY.to_vec());
+        ledger::SubAccounts::insert(SYMBOL, &pool, sub_accounts);
+        // SIGNATURE signs the ascii hex of the encoded caller
+        let proposal_id = to_ascii_hex(&caller.encode());
+    }: _(RawOrigin::Signed(caller), SYMBOL, 1, pool.clone(), OriginalTxType::Bond, proposal_id.clone(), SIGNATURE.to_vec())
+    verify {
+        assert!(Signatures::get(SYMBOL, (1, &pool, OriginalTxType::Bond, &proposal_id)).is_some());
+        assert_eq!(SignatureSigners::get(SYMBOL, (1, &pool, OriginalTxType::Bond, &proposal_id)), vec![PUBKEY.to_vec()]);
+    }
+
+    refund_swap_fee {
+        let caller: T::AccountId = whitelisted_caller();
+        let bond_id = dealing_bond::<T>(true);
+        let mut swap = <BondSwaps<T>>::get(SYMBOL, &bond_id).unwrap();
+        swap.bond_state = BondState::Fail;
+        swap.expire = 100u32.into();
+        <BondSwaps<T>>::insert(SYMBOL, &bond_id, swap);
+    }: _(RawOrigin::Signed(caller), SYMBOL, bond_id.clone())
+    verify {
+        assert!(<BondSwaps<T>>::get(SYMBOL, &bond_id).unwrap().refunded);
+    }
+
+    execute_bond_and_swap {
+        let pool = bonded_pool::<T>();
+        prepare_swap::<T>();
+        rtoken_rate::Module::<T>::set_rate(SYMBOL, 0, 0);
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let origin = T::VoterOrigin::successful_origin();
+    }: _(origin, pool, b"blockhash".to_vec(), b"txhash".to_vec(), AMOUNT, SYMBOL, recipient.clone(), vec![1u8; 20], DEST_ID, BondReason::Pass)
+    verify {
+        assert_eq!(<AccountBondCount<T>>::get(SYMBOL, &recipient), 1);
+    }
+
+    set_pool_selection {
+    }: _(RawOrigin::Root, SYMBOL, PoolSelection::Auto(PoolStrategy::LeastLoaded))
+    verify {
+        assert_eq!(PoolSelections::get(SYMBOL), PoolSelection::Auto(PoolStrategy::LeastLoaded));
+    }
+
+    instant_unbond {
+        let caller: T::AccountId = whitelisted_caller();
+        <T as Trait>::Currency::make_free_balance_be(&caller, FUNDS.saturated_into());
+        let pool = bonded_pool::<T>();
+        ledger::ChainEras::insert(SYMBOL, 1);
+        ledger::ChainBondingDuration::insert(SYMBOL, 2);
+        <ledger::Receiver<T>>::put(account::<T::AccountId>("receiver", 0, SEED));
+        <RelayFeesReceiver<T>>::put(funded_account::<T>("relay_receiver", 0));
+        rtoken_rate::Module::<T>::set_rate(SYMBOL, 0, 0);
+        <T as Trait>::RCurrency::mint(&caller, SYMBOL, AMOUNT)?;
+
+        let dex = rdex_swap::Module::<T>::account_id();
+        <T as rdex_swap::Trait>::Currency::make_free_balance_be(&dex, FUNDS.saturated_into());
+        <T as rdex_swap::Trait>::RCurrency::mint(&dex, SYMBOL, AMOUNT * 10)?;
+        rdex_swap::Module::<T>::help_set_pool(SYMBOL, rdex_swap::SwapPool {symbol: SYMBOL, fis_balance: AMOUNT * 10,
+            rtoken_balance: AMOUNT * 10, total_unit: AMOUNT * 10, fee_model: rdex_swap::FeeModel::Slip});
+        InstantUnbondCap::insert(SYMBOL, Perbill::from_percent(5));
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, pool, AMOUNT, PUBKEY.to_vec(), 1)
+    verify {
+        assert_eq!(AccountUnbonds::<T>::get(&caller, SYMBOL).unwrap_or_default().len(), 1);
+    }
+
+    set_instant_unbond_cap {
+    }: _(RawOrigin::Root, SYMBOL, 50_000_000)
+    verify {
+        assert_eq!(InstantUnbondCap::get(SYMBOL), Perbill::from_percent(5));
+    }
+
+    set_bond_dealing_expire {
+        let number: T::BlockNumber = 100u32.into();
+    }: _(RawOrigin::Root, SYMBOL, number)
+    verify {
+        assert_eq!(<BondDealingExpire<T>>::get(SYMBOL), Some(number));
+    }
+
+    cancel_bond_record {
+        let bond_id = dealing_bond::<T>(true);
+        let bonder = <BondRecords<T>>::get(SYMBOL, &bond_id).unwrap().bonder;
+        let dealing = BondDealing { bond_fee: BondFees::get(SYMBOL), fee_receiver: funded_account::<T>("receiver", 0), expire: Zero::zero() };
+        <BondDealings<T>>::insert(SYMBOL, &bond_id, dealing);
+    }: _(RawOrigin::Signed(bonder), SYMBOL, bond_id.clone())
+    verify {
+        assert_eq!(<BondReasons<T>>::get(SYMBOL, &bond_id), Some(BondReason::Expired));
+        assert!(<BondSwaps<T>>::get(SYMBOL, &bond_id).unwrap().refunded);
+    }
+
+    set_retention_eras {
+        ledger::ChainEras::insert(SYMBOL, 10);
+    }: _(RawOrigin::Root, SYMBOL, 84)
+    verify {
+        assert_eq!(RetentionEras::get(SYMBOL), 84);
+        assert_eq!(PruneCursor::get(SYMBOL), 10);
+    }
+
+    rebond {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool = bonded_pool::<T>();
+        ledger::ChainEras::insert(SYMBOL, 1);
+        ledger::ChainBondingDuration::insert(SYMBOL, 2);
+        rtoken_rate::Module::<T>::set_rate(SYMBOL, 0, 0);
+        // every chunk is unbonded in the current era, so that all of them are rebonded
+        let n = MAX_UNLOCKING_CHUNKS as u128 - 1;
+        let chunks: Vec<UserUnlockChunk> = (0..n)
+            .map(|_| UserUnlockChunk {pool: pool.clone(), unlock_era: 3, value: AMOUNT / n, recipient: PUBKEY.to_vec()})
+            .collect();
+        let unbonds: Vec<ledger::Unbonding<T::AccountId>> = (0..n)
+            .map(|_| ledger::Unbonding {who: caller.clone(), value: AMOUNT / n, recipient: PUBKEY.to_vec()})
+            .collect();
+        AccountUnbonds::<T>::insert(&caller, SYMBOL, chunks);
+        ledger::PoolUnbonds::<T>::insert(SYMBOL, (&pool, 3), unbonds);
+        ledger::BondPipelines::insert(SYMBOL, &pool, ledger::LinkChunk {bond: 0, unbond: AMOUNT, active: AMOUNT});
+    }: _(RawOrigin::Signed(caller.clone()), SYMBOL, pool, AMOUNT / n * n)
+    verify {
+        assert!(AccountUnbonds::<T>::get(&caller, SYMBOL).unwrap_or_default().is_empty());
+    }
+
+    prune_eras {
+        let pool = bonded_pool::<T>();
+        let bonder: T::AccountId = account("bonder", 0, SEED);
+        for i in 0..MAX_PRUNE_ITEMS {
+            let record = BondRecord::new(bonder.clone(), SYMBOL, PUBKEY.to_vec(), pool.clone(), b"blockhash".to_vec(), i.encode(), AMOUNT);
+            let bond_id = <T::Hashing as Hash>::hash_of(&record);
+            <BondStates>::insert(SYMBOL, (&record.blockhash, &record.txhash), BondState::Success);
+            <BondReasons<T>>::insert(SYMBOL, &bond_id, BondReason::Pass);
+            <BondRecords<T>>::insert(SYMBOL, &bond_id, &record);
+            <EraBonds<T>>::append(SYMBOL, 1, &bond_id);
+        }
+        ledger::ChainEras::insert(SYMBOL, 2);
+    }: _(RawOrigin::Root, SYMBOL, 1, 2)
+    verify {
+        assert!(<EraBonds<T>>::get(SYMBOL, 1).is_empty());
+    }
+
+    index_legacy_history {
+        let pool = bonded_pool::<T>();
+        let bonder: T::AccountId = account("bonder", 0, SEED);
+        let mut bond_ids = vec![];
+        for i in 0..MAX_PRUNE_ITEMS {
+            let record = BondRecord::new(bonder.clone(), SYMBOL, PUBKEY.to_vec(), pool.clone(), b"blockhash".to_vec(), i.encode(), AMOUNT);
+            let bond_id = <T::Hashing as Hash>::hash_of(&record);
+            <BondRecords<T>>::insert(SYMBOL, &bond_id, &record);
+            bond_ids.push(bond_id);
+        }
+        ledger::ChainEras::insert(SYMBOL, 2);
+    }: _(RawOrigin::Root, SYMBOL, 1, bond_ids, vec![], vec![])
+    verify {
+        assert_eq!(<EraBonds<T>>::get(SYMBOL, 1).len(), MAX_PRUNE_ITEMS as usize);
+    }
+}
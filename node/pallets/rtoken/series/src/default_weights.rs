@@ -0,0 +1,96 @@
+//! Default weights for the RTokenSeries Pallet
+//!
+//! These are the weights used before the pallet was benchmarked. They are only
+//! meant for tests and mocks; the runtime uses its own benchmarked weights.
+
+use frame_support::weights::Weight;
+
+impl crate::WeightInfo for () {
+    fn toggle_bond_switch() -> Weight {
+        1_000_000 as Weight
+    }
+    fn toggle_rtoken_bond_switch() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_relay_fees_receiver() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_proxy_accounts() -> Weight {
+        1_000_000 as Weight
+    }
+    fn remove_proxy_accounts() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_bond_fees() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_unbond_fees() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_balance_limit() -> Weight {
+        1_000_000 as Weight
+    }
+    fn set_unbond_commission() -> Weight {
+        1_000_000 as Weight
+    }
+    fn init_nominations() -> Weight {
+        1_000_000 as Weight
+    }
+    fn update_nominations() -> Weight {
+        1_000_000 as Weight
+    }
+    fn update_validator() -> Weight {
+        1_000_000 as Weight
+    }
+    fn swap_refund_expire() -> Weight {
+        1_000_000 as Weight
+    }
+    fn liquidity_bond() -> Weight {
+        10_000_000_000 as Weight
+    }
+    fn liquidity_bond_and_swap() -> Weight {
+        30_000_000_000 as Weight
+    }
+    fn execute_bond_record() -> Weight {
+        100_000 as Weight
+    }
+    fn liquidity_unbond() -> Weight {
+        30_000_000_000 as Weight
+    }
+    fn submit_signatures() -> Weight {
+        10_000_000 as Weight
+    }
+    fn refund_swap_fee() -> Weight {
+        5_000_000_000 as Weight
+    }
+    fn execute_bond_and_swap() -> Weight {
+        100_000 as Weight
+    }
+    fn set_pool_selection() -> Weight {
+        10_000_000 as Weight
+    }
+    fn instant_unbond() -> Weight {
+        30_000_000_000 as Weight
+    }
+    fn set_instant_unbond_cap() -> Weight {
+        10_000_000 as Weight
+    }
+    fn set_bond_dealing_expire() -> Weight {
+        10_000_000 as Weight
+    }
+    fn cancel_bond_record() -> Weight {
+        5_000_000_000 as Weight
+    }
+    fn set_retention_eras() -> Weight {
+        10_000_000 as Weight
+    }
+    fn prune_eras() -> Weight {
+        5_000_000_000 as Weight
+    }
+    fn index_legacy_history() -> Weight {
+        1_000_000_000 as Weight
+    }
+    fn rebond() -> Weight {
+        30_000_000_000 as Weight
+    }
+}
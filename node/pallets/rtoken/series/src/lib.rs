@@ -1,47 +1,101 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use sp_std::prelude::*;
+use sp_std::{prelude::*, collections::btree_map::BTreeMap};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchResult}, ensure,
-    traits::{Currency, Get, EnsureOrigin, ExistenceRequirement::{KeepAlive}}
+    traits::{Currency, Get, EnsureOrigin, ExistenceRequirement::{KeepAlive}},
+    weights::Weight,
+    storage::{IterableStorageMap, IterableStorageDoubleMap},
+    transactional,
 };
 
 use frame_system::{self as system, ensure_signed, ensure_root};
 use sp_runtime::{
     Perbill,
-    traits::{Hash, Zero},
-    SaturatedConversion
+    traits::{Hash, Zero, Saturating},
+    helpers_128bit::multiply_by_rational,
+    SaturatedConversion, DispatchError
 };
 use rtoken_balances::{traits::{Currency as RCurrency}};
 use node_primitives::{RSymbol, Balance, ChainType, ChainId};
-use general_signature::{verify_recipient, to_ascii_hex, SigVerifyResult, verify_signature};
+use general_signature::{verify_recipient, to_ascii_hex, SigVerifyResult, verify_signature, verify_proposal_signature};
 use rtoken_ledger::{self as ledger, Unbonding};
 use rtoken_relayers as relayers;
+use rtoken_registry as registry;
 use codec::{Encode};
 use rclaim;
 use bridge_common as bridge;
+use rdex_swap;
 use sp_core::U256;
 
 pub mod models;
 pub use models::*;
+pub mod pool_selector;
+pub use pool_selector::*;
+
+mod default_weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 pub const MAX_UNLOCKING_CHUNKS: usize = 32;
 pub const MIN_UNLOCKING_CHUNKS: usize = 16;
+/// max items pruned by a block or a prune_eras call
+pub const MAX_PRUNE_ITEMS: u32 = 64;
+
+pub trait WeightInfo {
+    fn toggle_bond_switch() -> Weight;
+    fn toggle_rtoken_bond_switch() -> Weight;
+    fn set_relay_fees_receiver() -> Weight;
+    fn set_proxy_accounts() -> Weight;
+    fn remove_proxy_accounts() -> Weight;
+    fn set_bond_fees() -> Weight;
+    fn set_unbond_fees() -> Weight;
+    fn set_balance_limit() -> Weight;
+    fn set_unbond_commission() -> Weight;
+    fn init_nominations() -> Weight;
+    fn update_nominations() -> Weight;
+    fn update_validator() -> Weight;
+    fn swap_refund_expire() -> Weight;
+    fn liquidity_bond() -> Weight;
+    fn liquidity_bond_and_swap() -> Weight;
+    fn execute_bond_record() -> Weight;
+    fn liquidity_unbond() -> Weight;
+    fn submit_signatures() -> Weight;
+    fn refund_swap_fee() -> Weight;
+    fn execute_bond_and_swap() -> Weight;
+    fn set_pool_selection() -> Weight;
+    fn instant_unbond() -> Weight;
+    fn set_instant_unbond_cap() -> Weight;
+    fn set_bond_dealing_expire() -> Weight;
+    fn cancel_bond_record() -> Weight;
+    fn set_retention_eras() -> Weight;
+    fn prune_eras() -> Weight;
+    fn index_legacy_history() -> Weight;
+    fn rebond() -> Weight;
+}
 
-pub trait Trait: system::Trait + rtoken_rate::Trait + rtoken_ledger::Trait + relayers::Trait + rclaim::Trait + bridge::Trait {
+pub trait Trait: system::Trait + rtoken_rate::Trait + rtoken_ledger::Trait + relayers::Trait + rclaim::Trait + bridge::Trait + rdex_swap::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
     /// The currency mechanism.
     type Currency: Currency<Self::AccountId>;
     /// currency of rtoken
     type RCurrency: RCurrency<Self::AccountId>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event! {
     pub enum Event<T> where
         Hash = <T as system::Trait>::Hash,
-        <T as frame_system::Trait>::AccountId
+        <T as frame_system::Trait>::AccountId,
+        <T as frame_system::Trait>::BlockNumber
     {
         /// LiquidityBond
         LiquidityBond(AccountId, RSymbol, Hash),
@@ -69,6 +123,24 @@ decl_event! {
         SwapFeeRefunded(RSymbol, Hash),
         /// ExecuteBondAndSwap
         ExecuteBondAndSwap(AccountId, RSymbol, Hash, u128, Vec<u8>, ChainId),
+        /// pool selection of symbol set
+        PoolSelectionSet(RSymbol, PoolSelection),
+        /// instant unbond: who, symbol, value, value swapped in rdex, fis out, value unbonded
+        InstantUnbond(AccountId, RSymbol, u128, u128, u128, u128),
+        /// symbol, max share of the rdex pool rtoken balance an instant unbond may swap
+        InstantUnbondCapSet(RSymbol, Perbill),
+        /// dealing bond record cancelled: bonder, symbol, bond_id, bond fee refunded, swap fee refunded
+        BondRecordCancelled(AccountId, RSymbol, Hash, Balance, Balance),
+        /// symbol, eras of history kept
+        RetentionErasSet(RSymbol, u32),
+        /// symbol, start era, first era not fully pruned, items pruned
+        ErasPruned(RSymbol, u32, u32, u32),
+        /// symbol, era, legacy bonds, signatures and snapshots indexed
+        LegacyHistoryIndexed(RSymbol, u32, u32, u32, u32),
+        /// rebond of unbonding: who, symbol, pool, value, rtoken minted
+        Rebond(AccountId, RSymbol, Vec<u8>, u128, u128),
+        /// symbol, blocks a dealing bond record lives before it can be cancelled
+        BondDealingExpireSet(RSymbol, BlockNumber),
     }
 }
 
@@ -128,6 +200,32 @@ decl_error! {
         ExpireNotSet,
         /// swap not exist
         SwapNotExist,
+        /// rate of symbol paused until governance confirms it
+        RatePaused,
+        /// pool is selected on chain, pass an empty pool
+        PoolSelectedOnChain,
+        /// no pool available for the amount
+        NoPoolAvailable,
+        /// bond record not dealing
+        BondNotDealing,
+        /// bond record has no dealing expire
+        BondDealingNotExpirable,
+        /// dealing expire of bond record not reached
+        BondDealingNotExpired,
+        /// not the bonder of the record
+        NotBonder,
+        /// retention not longer than the bonding duration
+        RetentionTooShort,
+        /// era range empty or not in the past
+        InvalidEraRange,
+        /// rebond Zero
+        RebondZero,
+        /// rebond more than unbonding still in the pipeline
+        RebondTooBig,
+        /// more than MAX_PRUNE_ITEMS legacy keys in a call
+        TooManyLegacyKeys,
+        /// legacy key not found, or not of the era given
+        LegacyKeyNotFound,
     }
 }
 
@@ -145,6 +243,10 @@ decl_storage! {
         pub BondStates get(fn bond_states): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) (Vec<u8>, Vec<u8>) => Option<BondState>;
         pub BondSwapRefundExpire get(fn bond_swap_refund_expire): map hasher(blake2_128_concat) RSymbol => Option<T::BlockNumber>;
         pub BondSwaps get(fn bond_swaps): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::Hash => Option<BondSwap<T::AccountId, T::BlockNumber>>;
+        /// blocks after which a dealing bond record may be cancelled by its bonder, none means never
+        pub BondDealingExpire get(fn bond_dealing_expire): map hasher(blake2_128_concat) RSymbol => Option<T::BlockNumber>;
+        /// fees and expire of dealing bond records. symbol, bond_id => dealing
+        pub BondDealings get(fn bond_dealings): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::Hash => Option<BondDealing<T::AccountId, T::BlockNumber>>;
 
         /// Recipient account for relay fees
         pub RelayFeesReceiver get(fn relay_fees_receiver): Option<T::AccountId>;
@@ -158,6 +260,10 @@ decl_storage! {
         pub UnbondFees get(fn unbond_fees): map hasher(blake2_128_concat) RSymbol => Balance = 3000000000000;
 
         PoolBalanceLimit get(fn pool_balance_limit): map hasher(blake2_128_concat) RSymbol => u128;
+        /// how the pools of bonds and unbonds are chosen
+        pub PoolSelections get(fn pool_selection): map hasher(blake2_128_concat) RSymbol => PoolSelection;
+        /// max share of the rdex pool rtoken balance an instant unbond may swap, zero means no instant unbond
+        pub InstantUnbondCap get(fn instant_unbond_cap): map hasher(blake2_128_concat) RSymbol => Perbill;
 
         /// Unbond commission
         UnbondCommission get(fn unbond_commission): Perbill = Perbill::from_parts(2000000);
@@ -167,9 +273,23 @@ decl_storage! {
 
         pub Signatures get(fn signatures): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) (u32, Vec<u8>, OriginalTxType, Vec<u8>) => Option<Vec<Vec<u8>>>;
         pub AccountSignature get(fn account_signature): map hasher(blake2_128_concat) (T::AccountId, RSymbol, u32, Vec<u8>, OriginalTxType, Vec<u8>) => Option<Vec<u8>>;
+        /// sub accounts of the pool which signed, in the order of Signatures
+        pub SignatureSigners get(fn signature_signers): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) (u32, Vec<u8>, OriginalTxType, Vec<u8>) => Vec<Vec<u8>>;
 
         pub Nominated get(fn nominated): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) Vec<u8> => Option<Vec<Vec<u8>>>;
         pub EraNominated get(fn era_nominated): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) (Vec<u8>, u32) => Option<Vec<Vec<u8>>>;
+
+        /// eras of history kept, older eras are pruned. zero means kept forever
+        pub RetentionEras get(fn retention_eras): map hasher(blake2_128_concat) RSymbol => u32;
+        /// next era to be pruned
+        pub PruneCursor get(fn prune_cursor): map hasher(blake2_128_concat) RSymbol => u32;
+        /// bond ids by the era they were bonded in
+        pub EraBonds get(fn era_bonds): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) u32 => Vec<T::Hash>;
+        /// signature keys by era: who, pool, tx_type, proposal_id
+        pub EraSignatures get(fn era_signatures): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) u32 => Vec<(T::AccountId, Vec<u8>, OriginalTxType, Vec<u8>)>;
+        /// hash of (blockhash, txhash) of pruned successful bonds, keeps them from being bonded again.
+        /// never pruned, it grows by one small entry per successful bond, which pruning its record still saves
+        pub PrunedTxhashes get(fn pruned_txhashes): double_map hasher(blake2_128_concat) RSymbol, hasher(blake2_128_concat) T::Hash => bool;
     }
 }
 
@@ -177,8 +297,12 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        fn on_initialize() -> Weight {
+            Self::prune_expired_eras()
+        }
+
         /// turn on/off bond switch
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::toggle_bond_switch()]
         fn toggle_bond_switch(origin) -> DispatchResult {
             ensure_root(origin)?;
             let state = Self::bond_switch();
@@ -187,7 +311,7 @@ decl_module! {
         }
 
         /// turn on/off rtoken bond switch
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::toggle_rtoken_bond_switch()]
         fn toggle_rtoken_bond_switch(origin, symbol: RSymbol) -> DispatchResult {
             ensure_root(origin)?;
             let state = Self::rtoken_bond_switch(symbol);
@@ -196,7 +320,7 @@ decl_module! {
         }
 
         /// set relay fees receiver
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_relay_fees_receiver()]
         pub fn set_relay_fees_receiver(origin, new_receiver: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             <RelayFeesReceiver<T>>::put(new_receiver);
@@ -204,7 +328,7 @@ decl_module! {
         }
 
         /// Set proxy accounts.
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_proxy_accounts()]
         pub fn set_proxy_accounts(origin, account: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             <ProxyAccounts<T>>::insert(account, 0);
@@ -213,7 +337,7 @@ decl_module! {
         }
 
         /// Remove proxy accounts.
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::remove_proxy_accounts()]
         pub fn remove_proxy_accounts(origin, account: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             <ProxyAccounts<T>>::remove(account);
@@ -222,7 +346,7 @@ decl_module! {
         }
 
         /// Set fees for bond.
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_bond_fees()]
         pub fn set_bond_fees(origin, symbol: RSymbol, fees: Balance) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -234,7 +358,7 @@ decl_module! {
         }
 
         /// Set fees for unbond.
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_unbond_fees()]
         pub fn set_unbond_fees(origin, symbol: RSymbol, fees: Balance) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -246,7 +370,7 @@ decl_module! {
         }
 
         /// Update pool balance limit
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_balance_limit()]
         fn set_balance_limit(origin, symbol: RSymbol, new_limit: u128) -> DispatchResult {
             ensure_root(origin)?;
             let old_limit = Self::pool_balance_limit(symbol);
@@ -256,8 +380,30 @@ decl_module! {
 			Ok(())
         }
 
+        /// set how the pools of bonds and unbonds of a symbol are chosen
+        #[weight = <T as Trait>::WeightInfo::set_pool_selection()]
+        pub fn set_pool_selection(origin, symbol: RSymbol, selection: PoolSelection) -> DispatchResult {
+            ensure_root(origin)?;
+            <PoolSelections>::insert(symbol, selection);
+
+            Self::deposit_event(RawEvent::PoolSelectionSet(symbol, selection));
+            Ok(())
+        }
+
+        /// set the max share of the rdex pool rtoken balance an instant unbond may swap
+        #[weight = <T as Trait>::WeightInfo::set_instant_unbond_cap()]
+        pub fn set_instant_unbond_cap(origin, symbol: RSymbol, new_part: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(new_part <= 1_000_000_000, Error::<T>::OverFlow);
+
+            let cap = Perbill::from_parts(new_part);
+            <InstantUnbondCap>::insert(symbol, cap);
+            Self::deposit_event(RawEvent::InstantUnbondCapSet(symbol, cap));
+            Ok(())
+        }
+
         /// set unbond commission
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::set_unbond_commission()]
         pub fn set_unbond_commission(origin, new_part: u32) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -272,7 +418,7 @@ decl_module! {
         }
 
         /// init nominatons
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::init_nominations()]
         pub fn init_nominations(origin, symbol: RSymbol, pool: Vec<u8>, validators: Vec<Vec<u8>>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -285,7 +431,7 @@ decl_module! {
         }
 
         /// update nominatons
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::update_nominations()]
         pub fn update_nominations(origin, symbol: RSymbol, pool: Vec<u8>, new_validators: Vec<Vec<u8>>, era: u32) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -307,7 +453,7 @@ decl_module! {
         }
 
         /// update validator
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::update_validator()]
         pub fn update_validator(origin, symbol: RSymbol, pool: Vec<u8>, old_validator: Vec<u8>, new_validator: Vec<u8>, era: u32) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(ledger::BondedPools::get(symbol).contains(&pool), ledger::Error::<T>::PoolNotBonded);
@@ -327,7 +473,7 @@ decl_module! {
         }
 
         /// set
-        #[weight = 1_000_000]
+        #[weight = <T as Trait>::WeightInfo::swap_refund_expire()]
         pub fn swap_refund_expire(origin, symbol: RSymbol, number: T::BlockNumber) -> DispatchResult {
             ensure_root(origin)?;
             <BondSwapRefundExpire<T>>::insert(symbol, number);
@@ -335,8 +481,101 @@ decl_module! {
             Ok(())
         }
 
+        /// set the blocks after which a dealing bond record may be cancelled
+        #[weight = <T as Trait>::WeightInfo::set_bond_dealing_expire()]
+        pub fn set_bond_dealing_expire(origin, symbol: RSymbol, number: T::BlockNumber) -> DispatchResult {
+            ensure_root(origin)?;
+            <BondDealingExpire<T>>::insert(symbol, number);
+            Self::deposit_event(RawEvent::BondDealingExpireSet(symbol, number));
+            Ok(())
+        }
+
+        /// set the eras of history kept for a symbol, zero keeps it forever. pruning starts from the current era,
+        /// older eras are left to prune_eras
+        #[weight = <T as Trait>::WeightInfo::set_retention_eras()]
+        pub fn set_retention_eras(origin, symbol: RSymbol, eras: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            if eras == 0 {
+                <RetentionEras>::remove(symbol);
+            } else {
+                let bonding_duration = ledger::ChainBondingDuration::get(symbol).unwrap_or(0);
+                ensure!(eras > bonding_duration, Error::<T>::RetentionTooShort);
+                if !<PruneCursor>::contains_key(symbol) {
+                    <PruneCursor>::insert(symbol, ledger::ChainEras::get(symbol).unwrap_or(0));
+                }
+                <RetentionEras>::insert(symbol, eras);
+            }
+
+            Self::deposit_event(RawEvent::RetentionErasSet(symbol, eras));
+            Ok(())
+        }
+
+        /// prune the history of past eras in [start_era, end_era), at most MAX_PRUNE_ITEMS items a call
+        #[weight = <T as Trait>::WeightInfo::prune_eras()]
+        pub fn prune_eras(origin, symbol: RSymbol, start_era: u32, end_era: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            let current_era = ledger::ChainEras::get(symbol).ok_or(Error::<T>::NoCurrentEra)?;
+            ensure!(start_era < end_era && end_era <= current_era, Error::<T>::InvalidEraRange);
+
+            let mut budget = MAX_PRUNE_ITEMS;
+            let mut era = start_era;
+            while era < end_era && budget > 0 {
+                let (pruned, done) = Self::prune_era(symbol, era, current_era, budget);
+                budget = budget.saturating_sub(pruned);
+                if !done {
+                    break;
+                }
+                era += 1;
+            }
+
+            Self::deposit_event(RawEvent::ErasPruned(symbol, start_era, era, MAX_PRUNE_ITEMS - budget));
+            Ok(())
+        }
+
+        /// index history kept from before the era indexes, so that pruning reaches it. the keys are read
+        /// off chain, at most MAX_PRUNE_ITEMS a call. bonds carry no era, they are pruned with `era`.
+        /// eras before the prune cursor are left to prune_eras
+        #[weight = <T as Trait>::WeightInfo::index_legacy_history()]
+        pub fn index_legacy_history(origin, symbol: RSymbol, era: u32, bond_ids: Vec<T::Hash>,
+            signatures: Vec<(T::AccountId, Vec<u8>, OriginalTxType, Vec<u8>)>, shot_ids: Vec<T::Hash>) -> DispatchResult {
+            ensure_root(origin)?;
+            let current_era = ledger::ChainEras::get(symbol).ok_or(Error::<T>::NoCurrentEra)?;
+            ensure!(era < current_era, Error::<T>::InvalidEraRange);
+            let count = bond_ids.len() + signatures.len() + shot_ids.len();
+            ensure!(count <= MAX_PRUNE_ITEMS as usize, Error::<T>::TooManyLegacyKeys);
+
+            for bond_id in &bond_ids {
+                ensure!(<BondRecords<T>>::contains_key(symbol, bond_id), Error::<T>::LegacyKeyNotFound);
+            }
+            for (who, pool, tx_type, proposal_id) in &signatures {
+                ensure!(<AccountSignature<T>>::contains_key((who, symbol, era, pool, *tx_type, proposal_id)), Error::<T>::LegacyKeyNotFound);
+            }
+            for shot_id in &shot_ids {
+                let snap = ledger::Snapshots::<T>::get(symbol, shot_id).ok_or(Error::<T>::LegacyKeyNotFound)?;
+                ensure!(snap.era == era, Error::<T>::LegacyKeyNotFound);
+            }
+
+            let mut era_bonds = Self::era_bonds(symbol, era);
+            let new_bonds: Vec<T::Hash> = bond_ids.into_iter().filter(|bond_id| !era_bonds.contains(bond_id)).collect();
+            let mut era_signatures = Self::era_signatures(symbol, era);
+            let new_signatures: Vec<_> = signatures.into_iter().filter(|key| !era_signatures.contains(key)).collect();
+            let mut era_shots = ledger::EraSnapshotIds::<T>::get(symbol, era);
+            let new_shots: Vec<T::Hash> = shot_ids.into_iter().filter(|shot_id| !era_shots.contains(shot_id)).collect();
+
+            let indexed = (new_bonds.len() as u32, new_signatures.len() as u32, new_shots.len() as u32);
+            era_bonds.extend(new_bonds);
+            era_signatures.extend(new_signatures);
+            era_shots.extend(new_shots);
+            <EraBonds<T>>::insert(symbol, era, era_bonds);
+            <EraSignatures<T>>::insert(symbol, era, era_signatures);
+            ledger::EraSnapshotIds::<T>::insert(symbol, era, era_shots);
+
+            Self::deposit_event(RawEvent::LegacyHistoryIndexed(symbol, era, indexed.0, indexed.1, indexed.2));
+            Ok(())
+        }
+
         /// liquidity bond token to get rtoken
-        #[weight = 10_000_000_000]
+        #[weight = <T as Trait>::WeightInfo::liquidity_bond()]
         pub fn liquidity_bond(origin, pubkey: Vec<u8>, signature: Vec<u8>, pool: Vec<u8>, blockhash: Vec<u8>, txhash: Vec<u8>, amount: u128, symbol: RSymbol) -> DispatchResult {
             let who = ensure_signed(origin)?;
             Self::bondable(&who, &pubkey, &signature, &pool, &blockhash, &txhash, amount, symbol)?;
@@ -344,26 +583,22 @@ decl_module! {
             let receiver = Self::relay_fees_receiver().ok_or(Error::<T>::NoRelayFeesReceiver)?;
             let record = BondRecord::new(who.clone(), symbol, pubkey.clone(), pool.clone(), blockhash.clone(), txhash.clone(), amount);
             let bond_id = <T::Hashing as Hash>::hash_of(&record);
-            ensure!(Self::bond_records(symbol, &bond_id).is_none(), Error::<T>::BondRepeated);
-            let old_count = Self::account_bond_count(symbol, &who);
-            let new_count = old_count.checked_add(1).ok_or(Error::<T>::OverFlow)?;
+            let index = Self::bond_record_index(&who, symbol, &bond_id)?;
 
             let bond_fee = Self::bond_fees(symbol);
             if bond_fee > 0 {
                 <T as Trait>::Currency::transfer(&who, &receiver, bond_fee.saturated_into(), KeepAlive)?;
             }
 
-            <BondStates>::insert(symbol, (&blockhash, &txhash), BondState::Dealing);
-            <AccountBondCount<T>>::insert(symbol, &who, new_count);
-            <AccountBondRecords<T>>::insert(symbol, (&who, old_count), &bond_id);
-            <BondRecords<T>>::insert(symbol, &bond_id, &record);
+            Self::insert_bond_record(&who, symbol, &bond_id, &record, index);
+            Self::insert_dealing(symbol, &bond_id, bond_fee, receiver);
 
             Self::deposit_event(RawEvent::LiquidityBond(who, symbol, bond_id));
             Ok(())
         }
 
         /// new liquidity bond token to get rtoken
-        #[weight = 30_000_000_000]
+        #[weight = <T as Trait>::WeightInfo::liquidity_bond_and_swap()]
         pub fn liquidity_bond_and_swap(origin, pubkey: Vec<u8>, signature: Vec<u8>,
             pool: Vec<u8>, blockhash: Vec<u8>, txhash: Vec<u8>, amount: u128,
             symbol: RSymbol, recipient: Vec<u8>, dest_id: ChainId) -> DispatchResult {
@@ -373,14 +608,12 @@ decl_module! {
             let bond_receiver = Self::relay_fees_receiver().ok_or(Error::<T>::NoRelayFeesReceiver)?;
             let record = BondRecord::new(who.clone(), symbol, pubkey.clone(), pool.clone(), blockhash.clone(), txhash.clone(), amount);
             let bond_id = <T::Hashing as Hash>::hash_of(&record);
-            ensure!(Self::bond_records(symbol, &bond_id).is_none(), Error::<T>::BondRepeated);
-            let old_count = Self::account_bond_count(symbol, &who);
-            let new_count = old_count.checked_add(1).ok_or(Error::<T>::OverFlow)?;
+            let index = Self::bond_record_index(&who, symbol, &bond_id)?;
             let bond_fee = Self::bond_fees(symbol);
 
             if dest_id != T::ChainIdentity::get() {
                 let (swap_fee, swap_receiver, bridger) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
-                <bridge::Module<T>>::rsymbol_resource(&symbol).ok_or(bridge::Error::<T>::RsymbolNotMapped)?;
+                <bridge::Module<T>>::rsymbol_resource(symbol).ok_or(bridge::Error::<T>::RsymbolNotMapped)?;
 
                 if swap_fee > 0 && bond_fee > 0 {
                     let total_fee = swap_fee.saturating_add(bond_fee);
@@ -398,30 +631,31 @@ decl_module! {
                 <T as Trait>::Currency::transfer(&who, &bond_receiver, bond_fee.saturated_into(), KeepAlive)?;
             }
 
-            <BondStates>::insert(symbol, (&blockhash, &txhash), BondState::Dealing);
-            <AccountBondCount<T>>::insert(symbol, &who, new_count);
-            <AccountBondRecords<T>>::insert(symbol, (&who, old_count), &bond_id);
-            <BondRecords<T>>::insert(symbol, &bond_id, &record);
+            Self::insert_bond_record(&who, symbol, &bond_id, &record, index);
+            Self::insert_dealing(symbol, &bond_id, bond_fee, bond_receiver);
 
             Self::deposit_event(RawEvent::LiquidityBond(who, symbol, bond_id));
             Ok(())
         }
 
         /// execute bond record
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::execute_bond_record()]
         pub fn execute_bond_record(origin, symbol: RSymbol, bond_id: T::Hash, reason: BondReason) -> DispatchResult {
             T::VoterOrigin::ensure_origin(origin)?;
             let op_record = Self::bond_records(symbol, &bond_id);
             ensure!(op_record.is_some(), Error::<T>::BondNotFound);
             let record = op_record.unwrap();
             ensure!(Self::is_txhash_executable(symbol, &record.blockhash, &record.txhash), Error::<T>::TxhashUnexecutable);
+            // the record stays dealing while paused, to be executed once governance confirms the rate
+            ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
             let op_swap = Self::bond_swaps(symbol, &bond_id);
+            <BondDealings<T>>::remove(symbol, &bond_id);
 
             if reason != BondReason::Pass {
                 if let Some(mut swap) = op_swap {
                     if !swap.refunded {
                         let expire = Self::bond_swap_refund_expire(symbol).ok_or(Error::<T>::ExpireNotSet)?;
-                        let expire = expire + system::Module::<T>::block_number();
+                        let expire = expire.saturating_add(system::Module::<T>::block_number());
 
                         swap.expire = expire;
                         swap.bond_state = BondState::Fail;
@@ -440,8 +674,9 @@ decl_module! {
             pipe.active = pipe.active.checked_add(record.amount).ok_or(Error::<T>::OverFlow)?;
 
             let rbalance = rtoken_rate::Module::<T>::token_to_rtoken(symbol, record.amount);
-            if let Some(mut swap) = op_swap {
-                let resource = <bridge::Module<T>>::rsymbol_resource(&symbol).ok_or(bridge::Error::<T>::RsymbolNotMapped)?;
+            // a refunded swap is not paid for any more, so the rtoken goes to the bonder
+            if let Some(mut swap) = op_swap.filter(|swap| !swap.refunded) {
+                let resource = <bridge::Module<T>>::rsymbol_resource(symbol).ok_or(bridge::Error::<T>::RsymbolNotMapped)?;
                 <T as Trait>::Currency::transfer(&swap.bridger, &swap.swap_receiver, swap.swap_fee.saturated_into(), KeepAlive)?;
                 <T as Trait>::RCurrency::mint(&swap.bridger, symbol, rbalance)?;
                 swap.bond_state = BondState::Success;
@@ -463,90 +698,106 @@ decl_module! {
         }
 
         /// liquitidy unbond to redeem token with rtoken
-        #[weight = 30_000_000_000]
+        #[weight = <T as Trait>::WeightInfo::liquidity_unbond()]
         pub fn liquidity_unbond(origin, symbol: RSymbol, pool: Vec<u8>, value: u128, recipient: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(value > 0, Error::<T>::LiquidityUnbondZero);
+            Self::do_liquidity_unbond(who, symbol, pool, value, recipient)
+        }
+
+        /// rebond unbonding chunks of the pool not taken into an era snapshot yet, latest first, to get rtoken
+        /// at the current rate. the unbond commission and unbond fees already charged are not refunded
+        #[weight = <T as Trait>::WeightInfo::rebond()]
+        pub fn rebond(origin, symbol: RSymbol, pool: Vec<u8>, value: u128) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(value > 0, Error::<T>::RebondZero);
+            ensure!(Self::bond_switch(), Error::<T>::BondSwitchClosed);
             ensure!(Self::rtoken_bond_switch(symbol), Error::<T>::BondSwitchClosed);
-            ensure!(ledger::BondedPools::get(symbol).contains(&pool), ledger::Error::<T>::PoolNotFound);
-            match verify_recipient(symbol, &recipient) {
-                false => Err(Error::<T>::InvalidPubkey)?,
-                _ => (),
-            }
+            ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
 
+            // unbonds of the current era are still in the pipeline of the pool
             let current_era = rtoken_ledger::ChainEras::get(symbol).ok_or(Error::<T>::NoCurrentEra)?;
             let bonding_duration = rtoken_ledger::ChainBondingDuration::get(symbol).ok_or(Error::<T>::BondingDurationNotSet)?;
             let unlock_era = current_era + bonding_duration;
 
-            let op_receiver = ledger::Module::<T>::receiver();
-            ensure!(op_receiver.is_some(), ledger::Error::<T>::NoReceiver);
-            let receiver = op_receiver.unwrap();
-
-            let op_relay_fees_receiver = Self::relay_fees_receiver();
-            ensure!(op_relay_fees_receiver.is_some(), Error::<T>::NoRelayFeesReceiver);
-            let relay_fees_receiver = op_relay_fees_receiver.unwrap();
-
-            let free = <T as Trait>::RCurrency::free_balance(&who, symbol);
-            free.checked_sub(value).ok_or(Error::<T>::Insufficient)?;
-
-            let fee = Self::protocol_unbond_fee(value);
-            let left_value = value.checked_sub(fee).ok_or(Error::<T>::Insufficient)?;
-            ensure!(left_value > 0, Error::<T>::Insufficient);
-            let balance = rtoken_rate::Module::<T>::rtoken_to_token(symbol, left_value);
-
-            let mut pipe = ledger::BondPipelines::get(symbol, &pool).unwrap_or_default();
-            pipe.unbond = pipe.unbond.checked_add(balance).ok_or(Error::<T>::OverFlow)?;
-            pipe.active = pipe.active.checked_sub(balance).ok_or(Error::<T>::Insufficient)?;
-
-            let user_unlocking = Self::account_unbonds(&who, symbol).unwrap_or(vec![]);
-            let mut ac_unbonds: Vec<UserUnlockChunk> = user_unlocking.clone();
-            if ac_unbonds.len() >= MAX_UNLOCKING_CHUNKS {
-                let ac_unbonds_filter: Vec<UserUnlockChunk> = user_unlocking.into_iter()
-                    .filter(|chunk| if chunk.unlock_era >= current_era {
-                        true
-                    } else {
-                        false
-                    })
-                    .collect();   
-
-                if ac_unbonds_filter.len() < MIN_UNLOCKING_CHUNKS {
-                    let remove_len = MAX_UNLOCKING_CHUNKS - MIN_UNLOCKING_CHUNKS + 1;
-                    ac_unbonds.drain(0..remove_len);
-                } else {
-                    ac_unbonds = ac_unbonds_filter;
+            let mut ac_unbonds = Self::account_unbonds(&who, symbol).unwrap_or(vec![]);
+            let mut pool_unbonds = ledger::PoolUnbonds::<T>::get(symbol, (&pool, unlock_era)).unwrap_or(vec![]);
+            let mut left = value;
+            for chunk in ac_unbonds.iter_mut().rev().filter(|chunk| chunk.pool == pool && chunk.unlock_era == unlock_era) {
+                if left == 0 {
+                    break;
                 }
+                let rebonded = chunk.value.min(left);
+                let unbond = pool_unbonds.iter_mut().rev()
+                    .find(|unbond| unbond.who == who && unbond.recipient == chunk.recipient && unbond.value >= rebonded)
+                    .ok_or(Error::<T>::RebondTooBig)?;
+                unbond.value -= rebonded;
+                chunk.value -= rebonded;
+                left -= rebonded;
             }
+            ensure!(left == 0, Error::<T>::RebondTooBig);
+            ac_unbonds.retain(|chunk| chunk.value > 0);
+            pool_unbonds.retain(|unbond| unbond.value > 0);
 
-            ensure!(ac_unbonds.len() < MAX_UNLOCKING_CHUNKS, Error::<T>::NoMoreUnbondingChunks);
-
-            let mut pool_unbonds = ledger::PoolUnbonds::<T>::get(symbol, (&pool, unlock_era)).unwrap_or(vec![]);
-            let limit = ledger::EraUnbondLimit::get(symbol);
-            ensure!(limit == 0 || pool_unbonds.len() <= usize::from(limit), Error::<T>::PoolLimitReached);
-
-            ac_unbonds.push(UserUnlockChunk { pool: pool.clone(), unlock_era: unlock_era, value: balance, recipient: recipient.clone() });
-            pool_unbonds.push(Unbonding { who: who.clone(), value: balance, recipient: recipient.clone() });
-
-            let fees = Self::unbond_fees(symbol);
-            if fees > 0 {
-                <T as Trait>::Currency::transfer(&who, &relay_fees_receiver, fees.saturated_into(), KeepAlive)?;
+            let mut pipe = ledger::BondPipelines::get(symbol, &pool).unwrap_or_default();
+            pipe.unbond = pipe.unbond.checked_sub(value).ok_or(Error::<T>::RebondTooBig)?;
+            pipe.active = pipe.active.checked_add(value).ok_or(Error::<T>::OverFlow)?;
+            if Self::pool_selection(symbol) != PoolSelection::Manual {
+                // rebonds fill the pool as bonds do, see `bondable`
+                let limit = Self::pool_balance_limit(symbol);
+                ensure!(limit == 0 || pipe.active <= limit, Error::<T>::PoolLimitReached);
             }
 
-            <T as Trait>::RCurrency::transfer(&who, &receiver, symbol, fee)?;
-            <T as Trait>::RCurrency::burn(&who, symbol, left_value)?;
+            let rbalance = rtoken_rate::Module::<T>::token_to_rtoken(symbol, value);
+            <T as Trait>::RCurrency::mint(&who, symbol, rbalance)?;
+            //update claim info
+            rclaim::Module::<T>::update_claim_info(&who, symbol, rbalance, value);
             ledger::BondPipelines::insert(symbol, &pool, pipe);
             AccountUnbonds::<T>::insert(&who, symbol, &ac_unbonds);
-            ledger::PoolUnbonds::<T>::insert(symbol, (&pool, unlock_era), &pool_unbonds);
+            if pool_unbonds.is_empty() {
+                ledger::PoolUnbonds::<T>::remove(symbol, (&pool, unlock_era));
+            } else {
+                ledger::PoolUnbonds::<T>::insert(symbol, (&pool, unlock_era), &pool_unbonds);
+            }
+
+            Self::deposit_event(RawEvent::Rebond(who, symbol, pool, value, rbalance));
+            Ok(())
+        }
+
+        /// redeem rtoken at once by swapping into the rdex pool, up to the instant unbond cap of the pool,
+        /// the remainder is unbonded as liquidity_unbond does. min_swap_rate is the least fis per rtoken
+        /// (over RATEBASE) accepted for the swapped part, as the swapped amount is only known on chain
+        #[weight = <T as Trait>::WeightInfo::instant_unbond()]
+        #[transactional]
+        pub fn instant_unbond(origin, symbol: RSymbol, pool: Vec<u8>, value: u128, recipient: Vec<u8>, min_swap_rate: rtoken_rate::RateType) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(value > 0, Error::<T>::LiquidityUnbondZero);
+            ensure!(Self::rtoken_bond_switch(symbol), Error::<T>::BondSwitchClosed);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
 
-            Self::deposit_event(RawEvent::LiquidityUnBond(who, symbol, pool, value, left_value, balance, recipient));
+            let depth = rdex_swap::Module::<T>::swap_pools(symbol).map_or(0, |swap_pool| swap_pool.rtoken_balance);
+            let instant = value.min(Self::instant_unbond_cap(symbol) * depth);
+            let fis_out = if instant > 0 {
+                let min_fis_out = multiply_by_rational(instant, min_swap_rate.into(), rtoken_rate::RATEBASE.into()).unwrap_or(u128::MAX).max(1);
+                rdex_swap::Module::<T>::do_swap(&who, symbol, instant, min_fis_out, false)?
+            } else {
+                0
+            };
+
+            let remainder = value - instant;
+            if remainder > 0 {
+                Self::do_liquidity_unbond(who.clone(), symbol, pool, remainder, recipient)?;
+            }
 
+            Self::deposit_event(RawEvent::InstantUnbond(who, symbol, value, instant, fis_out, remainder));
             Ok(())
         }
 
-        /// Submit tx signatures
-        #[weight = 10_000_000]
+        /// Submit tx signatures, the signature must be of a sub account of the pool over the proposal_id
+        #[weight = <T as Trait>::WeightInfo::submit_signatures()]
         pub fn submit_signatures(origin, symbol: RSymbol, era: u32, pool: Vec<u8>, tx_type: OriginalTxType, proposal_id: Vec<u8>, signature: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(symbol.chain_type() != ChainType::Substrate, Error::<T>::InvalidRSymbol);
+            let chain_type = registry::Module::<T>::chain_type(symbol)?;
+            ensure!(chain_type != ChainType::Substrate, Error::<T>::InvalidRSymbol);
             ensure!(relayers::Module::<T>::is_relayer(symbol, &who), relayers::Error::<T>::MustBeRelayer);
             ensure!(ledger::BondedPools::get(symbol).contains(&pool), ledger::Error::<T>::PoolNotFound);
 
@@ -558,10 +809,19 @@ decl_module! {
             let mut signatures = Signatures::get(symbol, (era, &pool, tx_type, &proposal_id)).unwrap_or(vec![]);
             ensure!(!signatures.contains(&signature), Error::<T>::SignatureRepeated);
 
+            let signer = ledger::SubAccounts::get(symbol, &pool).into_iter()
+                .find(|pubkey| verify_proposal_signature(chain_type, pubkey, &signature, &proposal_id) == SigVerifyResult::Pass)
+                .ok_or(Error::<T>::InvalidSignature)?;
+            let mut signers = SignatureSigners::get(symbol, (era, &pool, tx_type, &proposal_id));
+            ensure!(!signers.contains(&signer), Error::<T>::SignatureRepeated);
+
             signatures.push(signature.clone());
+            signers.push(signer);
             Signatures::insert(symbol, (era, &pool, tx_type, &proposal_id), &signatures);
+            SignatureSigners::insert(symbol, (era, &pool, tx_type, &proposal_id), signers);
 
             <AccountSignature<T>>::insert((&who, symbol, era, &pool, tx_type, &proposal_id), &signature);
+            <EraSignatures<T>>::append(symbol, era, (who.clone(), pool.clone(), tx_type, proposal_id.clone()));
 
             if signatures.len() == ledger::MultiThresholds::get(symbol, &pool).unwrap_or(0) as usize {
                 Self::deposit_event(RawEvent::SignaturesEnough(symbol, era, pool.clone(), tx_type, proposal_id.clone()));
@@ -572,7 +832,7 @@ decl_module! {
         }
 
         /// refund swap fee if bond state fail
-        #[weight = 5_000_000_000]
+        #[weight = <T as Trait>::WeightInfo::refund_swap_fee()]
         pub fn refund_swap_fee(origin, symbol: RSymbol, bond_id: T::Hash) -> DispatchResult {
             ensure_signed(origin)?;
 
@@ -588,14 +848,53 @@ decl_module! {
             Ok(())
         }
 
+        /// cancel a bond record not executed before its dealing expire, the bond fee and swap fee are refunded
+        /// and the record fails, so the bonder may submit it again or relayers may still execute it
+        #[weight = <T as Trait>::WeightInfo::cancel_bond_record()]
+        pub fn cancel_bond_record(origin, symbol: RSymbol, bond_id: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let record = Self::bond_records(symbol, &bond_id).ok_or(Error::<T>::BondNotFound)?;
+            ensure!(record.bonder == who, Error::<T>::NotBonder);
+            let state = Self::bond_states(symbol, (&record.blockhash, &record.txhash));
+            ensure!(state == Some(BondState::Dealing), Error::<T>::BondNotDealing);
+            let dealing = Self::bond_dealings(symbol, &bond_id).ok_or(Error::<T>::BondDealingNotExpirable)?;
+            let now = system::Module::<T>::block_number();
+            ensure!(dealing.cancellable(now), Error::<T>::BondDealingNotExpired);
+
+            if dealing.bond_fee > 0 {
+                <T as Trait>::Currency::transfer(&dealing.fee_receiver, &who, dealing.bond_fee.saturated_into(), KeepAlive)?;
+            }
+
+            let mut swap_fee = 0;
+            if let Some(mut swap) = Self::bond_swaps(symbol, &bond_id) {
+                if !swap.refunded {
+                    swap_fee = swap.swap_fee;
+                    if swap_fee > 0 {
+                        <T as Trait>::Currency::transfer(&swap.bridger, &swap.bonder, swap_fee.saturated_into(), KeepAlive)?;
+                    }
+                    swap.bond_state = BondState::Fail;
+                    swap.refunded = true;
+                    <BondSwaps<T>>::insert(symbol, &bond_id, swap);
+                }
+            }
+
+            <BondDealings<T>>::remove(symbol, &bond_id);
+            <BondReasons<T>>::insert(symbol, &bond_id, BondReason::Expired);
+            <BondStates>::insert(symbol, (&record.blockhash, &record.txhash), BondState::Fail);
+
+            Self::deposit_event(RawEvent::BondRecordCancelled(who, symbol, bond_id, dealing.bond_fee, swap_fee));
+            Ok(())
+        }
+
         /// execute bond and swap
-        #[weight = 100_000]
+        #[weight = <T as Trait>::WeightInfo::execute_bond_and_swap()]
         pub fn execute_bond_and_swap(origin, pool: Vec<u8>, blockhash: Vec<u8>, txhash: Vec<u8>, amount: u128,
             symbol: RSymbol, stafi_recipient: T::AccountId, dest_recipient: Vec<u8>, dest_id: ChainId, reason: BondReason) -> DispatchResult {
             T::VoterOrigin::ensure_origin(origin)?;
            
             ensure!(Self::bond_switch(), Error::<T>::BondSwitchClosed);
             ensure!(Self::rtoken_bond_switch(symbol), Error::<T>::BondSwitchClosed);
+            ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
             ensure!(amount > 0, Error::<T>::LiquidityBondZero);
             ensure!(Self::is_txhash_available(symbol, &blockhash, &txhash), Error::<T>::TxhashUnavailable);
             ensure!(ledger::BondedPools::get(symbol).contains(&pool), ledger::Error::<T>::PoolNotBonded);
@@ -609,6 +908,7 @@ decl_module! {
             if reason != BondReason::Pass {
                 <BondReasons<T>>::insert(symbol, &bond_id, reason);
                 <BondStates>::insert(symbol, (&blockhash, &txhash), BondState::Fail);
+                Self::index_bond(symbol, &bond_id);
                 return Ok(())
             }
 
@@ -620,7 +920,7 @@ decl_module! {
 
             if dest_id != T::ChainIdentity::get() {
                 let (_, _, bridger) = <bridge::Module<T>>::swapable(&dest_recipient, dest_id)?;
-                let resource = <bridge::Module<T>>::rsymbol_resource(&symbol).ok_or(bridge::Error::<T>::RsymbolNotMapped)?;
+                let resource = <bridge::Module<T>>::rsymbol_resource(symbol).ok_or(bridge::Error::<T>::RsymbolNotMapped)?;
                 
                 <T as Trait>::RCurrency::mint(&bridger, symbol, rbalance)?;
                 <bridge::Module<T>>::transfer_fungible(stafi_recipient.clone(), dest_id.clone(), resource, dest_recipient.clone(), U256::from(rbalance))?;
@@ -631,6 +931,7 @@ decl_module! {
             <AccountBondCount<T>>::insert(symbol, &stafi_recipient, new_count);
             <AccountBondRecords<T>>::insert(symbol, (&stafi_recipient, old_count), &bond_id);
             <BondRecords<T>>::insert(symbol, &bond_id, &record);
+            Self::index_bond(symbol, &bond_id);
 
             <BondReasons<T>>::insert(symbol, &bond_id, BondReason::Pass);
             <BondStates>::insert(symbol, (&blockhash, &txhash), BondState::Success);
@@ -646,15 +947,164 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    /// records the bond fee of a dealing bond record, if dealing records of the symbol expire
+    fn insert_dealing(symbol: RSymbol, bond_id: &T::Hash, bond_fee: Balance, fee_receiver: T::AccountId) {
+        if let Some(expire) = Self::bond_dealing_expire(symbol) {
+            let expire = expire.saturating_add(system::Module::<T>::block_number());
+            <BondDealings<T>>::insert(symbol, bond_id, BondDealing { bond_fee, fee_receiver, expire });
+        }
+    }
+
+    /// index of a new bond record in the records of `who`, none if the record failed and is submitted again
+    fn bond_record_index(who: &T::AccountId, symbol: RSymbol, bond_id: &T::Hash) -> Result<Option<u64>, DispatchError> {
+        if let Some(record) = Self::bond_records(symbol, bond_id) {
+            let state = Self::bond_states(symbol, (&record.blockhash, &record.txhash));
+            ensure!(state == Some(BondState::Fail), Error::<T>::BondRepeated);
+            return Ok(None)
+        }
+        let old_count = Self::account_bond_count(symbol, who);
+        old_count.checked_add(1).ok_or(Error::<T>::OverFlow)?;
+        Ok(Some(old_count))
+    }
+
+    /// marks the bond record dealing, a failed record submitted again is already stored and indexed
+    fn insert_bond_record(who: &T::AccountId, symbol: RSymbol, bond_id: &T::Hash, record: &BondRecord<T::AccountId>, index: Option<u64>) {
+        <BondStates>::insert(symbol, (&record.blockhash, &record.txhash), BondState::Dealing);
+        match index {
+            Some(old_count) => {
+                <AccountBondCount<T>>::insert(symbol, who, old_count + 1);
+                <AccountBondRecords<T>>::insert(symbol, (who, old_count), bond_id);
+                <BondRecords<T>>::insert(symbol, bond_id, record);
+                Self::index_bond(symbol, bond_id);
+            },
+            None => <BondReasons<T>>::remove(symbol, bond_id),
+        }
+    }
+
     fn is_txhash_available(symbol: RSymbol, blockhash: &Vec<u8>, txhash: &Vec<u8>) -> bool {
         let op_state = Self::bond_states(symbol, (&blockhash, &txhash));
         if op_state.is_none() {
-            return true
+            return !Self::pruned_txhashes(symbol, Self::txhash_key(blockhash, txhash))
         }
         let state = op_state.unwrap();
         state == BondState::Fail
     }
 
+    fn txhash_key(blockhash: &Vec<u8>, txhash: &Vec<u8>) -> T::Hash {
+        <T::Hashing as Hash>::hash_of(&(blockhash, txhash))
+    }
+
+    fn index_bond(symbol: RSymbol, bond_id: &T::Hash) {
+        let era = ledger::ChainEras::get(symbol).unwrap_or(0);
+        <EraBonds<T>>::append(symbol, era, bond_id);
+    }
+
+    /// prunes the eras which left the retention window of their symbol, MAX_PRUNE_ITEMS items a block at most
+    fn prune_expired_eras() -> Weight {
+        let mut budget = MAX_PRUNE_ITEMS;
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+        for (symbol, retention) in RetentionEras::iter() {
+            reads += 3;
+            if budget == 0 {
+                break;
+            }
+            let current_era = match ledger::ChainEras::get(symbol) {
+                Some(era) => era,
+                None => continue,
+            };
+
+            let end_era = current_era.saturating_sub(retention);
+            let start_era = Self::prune_cursor(symbol);
+            let mut era = start_era;
+            while era < end_era && budget > 0 {
+                let (pruned, done) = Self::prune_era(symbol, era, current_era, budget);
+                budget = budget.saturating_sub(pruned);
+                if done {
+                    era += 1;
+                }
+            }
+            if era != start_era {
+                <PruneCursor>::insert(symbol, era);
+                writes += 1;
+            }
+        }
+
+        let pruned = (MAX_PRUNE_ITEMS - budget) as Weight;
+        T::DbWeight::get().reads_writes(reads + pruned * 3, writes + pruned * 6)
+    }
+
+    /// prunes up to `limit` items of a past era, returns the items pruned and whether the era is done.
+    /// the era itself counts as an item so that empty eras are paid for
+    fn prune_era(symbol: RSymbol, era: u32, current_era: u32, limit: u32) -> (u32, bool) {
+        let mut pruned = 0;
+
+        let mut bond_ids = Self::era_bonds(symbol, era);
+        let count = bond_ids.len().min(limit as usize);
+        for bond_id in bond_ids.drain(..count) {
+            Self::prune_bond(symbol, &bond_id, current_era);
+        }
+        pruned += count as u32;
+        if !bond_ids.is_empty() {
+            <EraBonds<T>>::insert(symbol, era, bond_ids);
+            return (pruned, false)
+        }
+        <EraBonds<T>>::remove(symbol, era);
+
+        let mut keys = Self::era_signatures(symbol, era);
+        let count = keys.len().min(limit.saturating_sub(pruned) as usize);
+        for (who, pool, tx_type, proposal_id) in keys.drain(..count) {
+            Signatures::remove(symbol, (era, &pool, tx_type, &proposal_id));
+            SignatureSigners::remove(symbol, (era, &pool, tx_type, &proposal_id));
+            <AccountSignature<T>>::remove((&who, symbol, era, &pool, tx_type, &proposal_id));
+        }
+        pruned += count as u32;
+        if !keys.is_empty() {
+            <EraSignatures<T>>::insert(symbol, era, keys);
+            return (pruned, false)
+        }
+        <EraSignatures<T>>::remove(symbol, era);
+
+        let (count, done) = ledger::Module::<T>::prune_era_snapshots(symbol, era, limit.saturating_sub(pruned));
+        pruned += count;
+        if !done || pruned >= limit {
+            return (pruned, false)
+        }
+
+        for pool in ledger::Pools::get(symbol) {
+            EraNominated::remove(symbol, (&pool, era));
+        }
+        (pruned + 1, true)
+    }
+
+    /// drops a bond and its record, bonds still dealing or refundable are moved to the current era.
+    /// a successful txhash is kept in PrunedTxhashes
+    fn prune_bond(symbol: RSymbol, bond_id: &T::Hash, current_era: u32) {
+        let record = match Self::bond_records(symbol, bond_id) {
+            Some(record) => record,
+            None => {
+                <BondReasons<T>>::remove(symbol, bond_id);
+                return
+            },
+        };
+        let state = Self::bond_states(symbol, (&record.blockhash, &record.txhash));
+        let now = system::Module::<T>::block_number();
+        let refundable = Self::bond_swaps(symbol, bond_id).map_or(false, |swap| swap.refundable(now));
+        if state == Some(BondState::Dealing) || refundable {
+            <EraBonds<T>>::append(symbol, current_era, bond_id);
+            return
+        }
+
+        if state == Some(BondState::Success) {
+            <PrunedTxhashes<T>>::insert(symbol, Self::txhash_key(&record.blockhash, &record.txhash), true);
+        }
+        <BondStates>::remove(symbol, (&record.blockhash, &record.txhash));
+        <BondRecords<T>>::remove(symbol, bond_id);
+        <BondReasons<T>>::remove(symbol, bond_id);
+        <BondSwaps<T>>::remove(symbol, bond_id);
+        <BondDealings<T>>::remove(symbol, bond_id);
+    }
+
     fn is_txhash_executable(symbol: RSymbol, blockhash: &Vec<u8>, txhash: &Vec<u8>) -> bool {
         let op_state = Self::bond_states(symbol, (&blockhash, &txhash));
         if op_state.is_none() {
@@ -664,6 +1114,224 @@ impl<T: Trait> Module<T> {
         state == BondState::Dealing || state == BondState::Fail
     }
 
+    /// unbonds of a user with their unlock state
+    pub fn unbond_status(who: &T::AccountId, symbol: RSymbol) -> Vec<(UserUnlockChunk, UnlockState)> {
+        let current_era = ledger::ChainEras::get(symbol).unwrap_or(0);
+        Self::account_unbonds(who, symbol).unwrap_or(vec![]).into_iter()
+            .map(|chunk| {
+                let state = if Self::is_unlock_transferred(symbol, &chunk) {
+                    UnlockState::Transferred
+                } else if chunk.unlock_era <= current_era {
+                    UnlockState::Withdrawable
+                } else {
+                    UnlockState::Pending
+                };
+                (chunk, state)
+            })
+            .collect()
+    }
+
+    /// bond records of a user with their bond state
+    pub fn account_bonds(who: &T::AccountId, symbol: RSymbol) -> Vec<(T::Hash, BondRecord<T::AccountId>, Option<BondState>)> {
+        let count = Self::account_bond_count(symbol, who);
+        (0..count)
+            .filter_map(|i| Self::account_bond_records(symbol, (who.clone(), i)))
+            .filter_map(|bond_id| Self::bond_records(symbol, &bond_id).map(|record| {
+                let state = Self::bond_states(symbol, (&record.blockhash, &record.txhash));
+                (bond_id, record, state)
+            }))
+            .collect()
+    }
+
+    /// the unlock era's snapshot of the chunk's pool has been transfer reported
+    fn is_unlock_transferred(symbol: RSymbol, chunk: &UserUnlockChunk) -> bool {
+        ledger::Module::<T>::era_snapshots(symbol, chunk.unlock_era).iter()
+            .any(|snap| snap.pool == chunk.pool && snap.bond_state == ledger::PoolBondState::TransferReported)
+    }
+
+    /// checks the accounting of ledger and series for a symbol, it iterates over all account unbonds
+    /// and so is meant for tests and the runtime api only
+    pub fn check_invariants(symbol: RSymbol) -> Vec<InvariantViolation> {
+        let mut violations = vec![];
+        let era = ledger::ChainEras::get(symbol).unwrap_or(0);
+        let violation = |pool: Option<Vec<u8>>, check: InvariantCheck, era: u32, actual: u128, expected: u128| InvariantViolation {
+            symbol, pool, check, era, actual, expected,
+        };
+
+        // unbonds not yet unlocked are kept on both sides
+        let mut account_unbonds: BTreeMap<(Vec<u8>, u32), u128> = BTreeMap::new();
+        for (_, sym, chunks) in AccountUnbonds::<T>::iter() {
+            if sym != symbol {
+                continue;
+            }
+            for chunk in chunks.into_iter().filter(|chunk| chunk.unlock_era >= era) {
+                let total = account_unbonds.entry((chunk.pool, chunk.unlock_era)).or_insert(0);
+                *total = total.saturating_add(chunk.value);
+            }
+        }
+        let mut pool_unbonds: BTreeMap<(Vec<u8>, u32), u128> = BTreeMap::new();
+        for ((pool, unlock_era), unbonds) in ledger::PoolUnbonds::<T>::iter_prefix(symbol) {
+            if unlock_era < era {
+                continue;
+            }
+            let total = unbonds.iter().fold(0u128, |acc, unbond| acc.saturating_add(unbond.value));
+            pool_unbonds.insert((pool, unlock_era), total);
+        }
+        for key in account_unbonds.keys() {
+            pool_unbonds.entry(key.clone()).or_insert(0);
+        }
+        for ((pool, unlock_era), pool_total) in pool_unbonds.iter() {
+            let account_total = account_unbonds.get(&(pool.clone(), *unlock_era)).cloned().unwrap_or(0);
+            if *pool_total != account_total {
+                violations.push(violation(Some(pool.clone()), InvariantCheck::PoolUnbonds, *unlock_era, *pool_total, account_total));
+            }
+        }
+
+        // the rest only hold once every pool has reported its active for the era
+        let reported = ledger::EraSnapShots::<T>::get(symbol, era).unwrap_or(vec![]).is_empty();
+        let op_total_expected = ledger::TotalExpectedActive::get(symbol, era);
+        if !reported || op_total_expected.is_none() {
+            return violations;
+        }
+        let total_expected = op_total_expected.unwrap();
+
+        let pipelines = ledger::Module::<T>::pool_pipelines(symbol);
+        let pools_active = pipelines.iter().fold(0u128, |acc, (_, pipe)| acc.saturating_add(pipe.active));
+        let pools_bond = pipelines.iter().fold(0u128, |acc, (_, pipe)| acc.saturating_add(pipe.bond));
+
+        // after the report active only goes up by the bonds and down by the unbonds of this era
+        let unlock_era = era.saturating_add(ledger::ChainBondingDuration::get(symbol).unwrap_or(0));
+        let era_unbonds = pool_unbonds.iter()
+            .filter(|((_, e), _)| *e == unlock_era)
+            .fold(0u128, |acc, (_, total)| acc.saturating_add(*total));
+        if pools_active > total_expected.saturating_add(pools_bond) || pools_active.saturating_add(era_unbonds) < total_expected {
+            violations.push(violation(None, InvariantCheck::PoolsActive, era, pools_active, total_expected));
+        }
+
+        let rbalance = <T as Trait>::RCurrency::total_issuance(symbol);
+        if let Some(rate) = rtoken_rate::Rate::get(symbol) {
+            if rbalance > 0 {
+                // executed bonds are in active already
+                let expected_rate = multiply_by_rational(pools_active, rtoken_rate::RATEBASE.into(), rbalance).unwrap_or(0);
+                let rate = u128::from(rate);
+                let diff = if rate > expected_rate { rate - expected_rate } else { expected_rate - rate };
+                if diff > Perbill::from_parts(1_000_000) * expected_rate {
+                    violations.push(violation(None, InvariantCheck::Rate, era, rate, expected_rate));
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// unbond `value` rtoken of `who` from the pool, paid to the recipient after the bonding duration
+    fn do_liquidity_unbond(who: T::AccountId, symbol: RSymbol, pool: Vec<u8>, value: u128, recipient: Vec<u8>) -> DispatchResult {
+        ensure!(value > 0, Error::<T>::LiquidityUnbondZero);
+        ensure!(Self::rtoken_bond_switch(symbol), Error::<T>::BondSwitchClosed);
+        ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
+        let pool = Self::unbond_pool(symbol, pool, value)?;
+        let chain_type = registry::Module::<T>::chain_type(symbol)?;
+        match verify_recipient(chain_type, &recipient) {
+            false => Err(Error::<T>::InvalidPubkey)?,
+            _ => (),
+        }
+
+        let current_era = rtoken_ledger::ChainEras::get(symbol).ok_or(Error::<T>::NoCurrentEra)?;
+        let bonding_duration = rtoken_ledger::ChainBondingDuration::get(symbol).ok_or(Error::<T>::BondingDurationNotSet)?;
+        let unlock_era = current_era + bonding_duration;
+
+        let op_receiver = ledger::Module::<T>::receiver();
+        ensure!(op_receiver.is_some(), ledger::Error::<T>::NoReceiver);
+        let receiver = op_receiver.unwrap();
+
+        let op_relay_fees_receiver = Self::relay_fees_receiver();
+        ensure!(op_relay_fees_receiver.is_some(), Error::<T>::NoRelayFeesReceiver);
+        let relay_fees_receiver = op_relay_fees_receiver.unwrap();
+
+        let free = <T as Trait>::RCurrency::free_balance(&who, symbol);
+        free.checked_sub(value).ok_or(Error::<T>::Insufficient)?;
+
+        let fee = Self::protocol_unbond_fee(value);
+        let left_value = value.checked_sub(fee).ok_or(Error::<T>::Insufficient)?;
+        ensure!(left_value > 0, Error::<T>::Insufficient);
+        let balance = rtoken_rate::Module::<T>::rtoken_to_token(symbol, left_value);
+
+        let mut pipe = ledger::BondPipelines::get(symbol, &pool).unwrap_or_default();
+        pipe.unbond = pipe.unbond.checked_add(balance).ok_or(Error::<T>::OverFlow)?;
+        pipe.active = pipe.active.checked_sub(balance).ok_or(Error::<T>::Insufficient)?;
+
+        let user_unlocking = Self::account_unbonds(&who, symbol).unwrap_or(vec![]);
+        let mut ac_unbonds: Vec<UserUnlockChunk> = user_unlocking.clone();
+        if ac_unbonds.len() >= MAX_UNLOCKING_CHUNKS {
+            let ac_unbonds_filter: Vec<UserUnlockChunk> = user_unlocking.into_iter()
+                .filter(|chunk| if chunk.unlock_era >= current_era {
+                    true
+                } else {
+                    false
+                })
+                .collect();   
+
+            if ac_unbonds_filter.len() < MIN_UNLOCKING_CHUNKS {
+                let remove_len = MAX_UNLOCKING_CHUNKS - MIN_UNLOCKING_CHUNKS + 1;
+                ac_unbonds.drain(0..remove_len);
+            } else {
+                ac_unbonds = ac_unbonds_filter;
+            }
+        }
+
+        ensure!(ac_unbonds.len() < MAX_UNLOCKING_CHUNKS, Error::<T>::NoMoreUnbondingChunks);
+
+        let mut pool_unbonds = ledger::PoolUnbonds::<T>::get(symbol, (&pool, unlock_era)).unwrap_or(vec![]);
+        let limit = ledger::EraUnbondLimit::get(symbol);
+        ensure!(limit == 0 || pool_unbonds.len() <= usize::from(limit), Error::<T>::PoolLimitReached);
+
+        ac_unbonds.push(UserUnlockChunk { pool: pool.clone(), unlock_era: unlock_era, value: balance, recipient: recipient.clone() });
+        pool_unbonds.push(Unbonding { who: who.clone(), value: balance, recipient: recipient.clone() });
+
+        let fees = Self::unbond_fees(symbol);
+        if fees > 0 {
+            <T as Trait>::Currency::transfer(&who, &relay_fees_receiver, fees.saturated_into(), KeepAlive)?;
+        }
+
+        <T as Trait>::RCurrency::transfer(&who, &receiver, symbol, fee)?;
+        <T as Trait>::RCurrency::burn(&who, symbol, left_value)?;
+        ledger::BondPipelines::insert(symbol, &pool, pipe);
+        AccountUnbonds::<T>::insert(&who, symbol, &ac_unbonds);
+        ledger::PoolUnbonds::<T>::insert(symbol, (&pool, unlock_era), &pool_unbonds);
+
+        Self::deposit_event(RawEvent::LiquidityUnBond(who, symbol, pool, value, left_value, balance, recipient));
+
+        Ok(())
+    }
+
+    /// pool the strategy of the symbol wants `amount` to be bonded into, for front-ends to send the bond to,
+    /// none in manual pool selection
+    pub fn select_bond_pool(symbol: RSymbol, amount: u128) -> Option<Vec<u8>> {
+        let strategy = match Self::pool_selection(symbol) {
+            PoolSelection::Auto(strategy) => strategy,
+            PoolSelection::Manual => return None,
+        };
+        let pipelines = ledger::Module::<T>::pool_pipelines(symbol);
+        strategy.bond_pool(&pipelines, amount, Self::pool_balance_limit(symbol))
+    }
+
+    /// the pool passed by the user, or the one selected on chain in auto pool selection
+    fn unbond_pool(symbol: RSymbol, pool: Vec<u8>, value: u128) -> Result<Vec<u8>, DispatchError> {
+        let strategy = match Self::pool_selection(symbol) {
+            PoolSelection::Auto(strategy) => strategy,
+            PoolSelection::Manual => {
+                ensure!(ledger::BondedPools::get(symbol).contains(&pool), ledger::Error::<T>::PoolNotFound);
+                return Ok(pool);
+            },
+        };
+
+        ensure!(pool.is_empty(), Error::<T>::PoolSelectedOnChain);
+        let amount = rtoken_rate::Module::<T>::rtoken_to_token(symbol, value);
+        let pipelines = ledger::Module::<T>::pool_pipelines(symbol);
+        let selected = strategy.unbond_pool(&pipelines, amount).ok_or(Error::<T>::NoPoolAvailable)?;
+        Ok(selected)
+    }
+
     fn protocol_unbond_fee(value: u128) -> u128 {
         Self::unbond_commission() * value
     }
@@ -671,15 +1339,24 @@ impl<T: Trait> Module<T> {
     fn bondable(who: &T::AccountId, pubkey: &Vec<u8>, signature: &Vec<u8>, pool: &Vec<u8>, blockhash: &Vec<u8>, txhash: &Vec<u8>, amount: u128, symbol: RSymbol) -> DispatchResult {
         ensure!(Self::bond_switch(), Error::<T>::BondSwitchClosed);
         ensure!(Self::rtoken_bond_switch(symbol), Error::<T>::BondSwitchClosed);
+        ensure!(!rtoken_rate::Module::<T>::is_paused(symbol), Error::<T>::RatePaused);
+        registry::Module::<T>::ensure_enabled(symbol)?;
         ensure!(amount > 0, Error::<T>::LiquidityBondZero);
         ensure!(Self::is_txhash_available(symbol, &blockhash, &txhash), Error::<T>::TxhashUnavailable);
         ensure!(ledger::BondedPools::get(symbol).contains(&pool), ledger::Error::<T>::PoolNotBonded);
+        if Self::pool_selection(symbol) != PoolSelection::Manual {
+            // the same room `select_bond_pool` looks for, bonds are not left to pile up in one pool
+            let limit = Self::pool_balance_limit(symbol);
+            let active = ledger::Module::<T>::bond_pipelines(symbol, pool).map_or(0, |pipe| pipe.active);
+            ensure!(limit == 0 || active.saturating_add(amount) <= limit, Error::<T>::PoolLimitReached);
+        }
 
+        let chain_type = registry::Module::<T>::chain_type(symbol)?;
         let mut sig_msg = who.encode();
-        if symbol.chain_type() == ChainType::Ethereum {
+        if chain_type == ChainType::Ethereum {
             sig_msg = who.using_encoded(to_ascii_hex);
         }
-        match verify_signature(symbol, &pubkey, &signature, &sig_msg) {
+        match verify_signature(chain_type, &pubkey, &signature, &sig_msg) {
             SigVerifyResult::InvalidPubkey => Err(Error::<T>::InvalidPubkey)?,
             SigVerifyResult::Fail => Err(Error::<T>::InvalidSignature)?,
             _ => (),
@@ -0,0 +1,189 @@
+use crate::{Module, Trait};
+use frame_support::{
+    impl_outer_dispatch, impl_outer_origin, parameter_types,
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+use node_primitives::{ChainId, RSymbol};
+use rtoken_ledger::LinkChunk;
+
+pub(crate) type Balance = u128;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+impl_outer_dispatch! {
+    pub enum Call for Test where origin: Origin {
+        frame_system::System,
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const ExistentialDeposit: Balance = 1;
+    pub const ChainIdentity: ChainId = 1;
+    pub const ProposalLifetime: u64 = 50;
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Test;
+
+impl frame_system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = ();
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+}
+
+impl Trait for Test {
+    type Event = ();
+    type Currency = Balances;
+    type RCurrency = RBalances;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Trait for Test {
+    type MaxLocks = ();
+    type Balance = Balance;
+    type DustRemoval = ();
+    type Event = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+impl rtoken_ledger::Trait for Test {
+    type Event = ();
+    type RCurrency = RBalances;
+    // any signed account stands for the votes pallet
+    type VoterOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_rate::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_registry::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_relayers::Trait for Test {
+    type Event = ();
+    type WeightInfo = ();
+}
+
+impl rtoken_balances::Trait for Test {
+    type Event = ();
+}
+
+impl rclaim::Trait for Test {
+    type Event = ();
+    type Currency = Balances;
+    type WeightInfo = ();
+}
+
+impl bridge_relayers::Trait for Test {
+    type Event = ();
+}
+
+impl bridge_common::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type Proposal = Call;
+    type ChainIdentity = ChainIdentity;
+    type ProposalLifetime = ProposalLifetime;
+    type WeightInfo = ();
+}
+
+impl rdex_swap::Trait for Test {
+    type Event = ();
+    type RCurrency = RBalances;
+    type Currency = Balances;
+    type LpCurrency = LpBalances;
+    type WeightInfo = ();
+}
+
+impl rdex_balances::Trait for Test {
+    type Event = ();
+}
+
+pub const SYMBOL: RSymbol = RSymbol::RDOT;
+/// receiver of the rtokens minted by the initial bonds of pools
+pub const POOL_OWNER: u64 = 100;
+
+/// fis balances of accounts 1 to 3, at block 1
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000_000), (2, 1_000_000), (3, 1_000_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    rtoken_registry::GenesisConfig {
+        assets: rtoken_registry::builtin_assets(),
+    }
+    .assimilate_storage::<Test>(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// adds a pool of SYMBOL bonded with `active`, the rtokens of which go to POOL_OWNER
+pub fn bond_pool(pool: &[u8], active: u128) {
+    RTokenLedger::add_new_pool(Origin::root(), SYMBOL, pool.to_vec()).unwrap();
+    RTokenLedger::set_init_bond(Origin::root(), SYMBOL, pool.to_vec(), POOL_OWNER, active).unwrap();
+}
+
+pub fn set_pipeline(pool: &[u8], bond: u128, unbond: u128, active: u128) {
+    rtoken_ledger::BondPipelines::insert(SYMBOL, pool.to_vec(), LinkChunk { bond, unbond, active });
+}
+
+pub type RTokenSeries = Module<Test>;
+pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type RBalances = rtoken_balances::Module<Test>;
+pub type RClaim = rclaim::Module<Test>;
+pub type LpBalances = rdex_balances::Module<Test>;
+pub type RTokenLedger = rtoken_ledger::Module<Test>;
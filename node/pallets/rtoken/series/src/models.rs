@@ -2,6 +2,8 @@ use sp_std::prelude::*;
 use codec::{Decode, Encode};
 use sp_runtime::RuntimeDebug;
 use node_primitives::{RSymbol, ChainId, Balance};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 /// Rtoken Identifier
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
@@ -18,10 +20,13 @@ pub enum BondReason {
     PoolUnmatch,
     /// amount not match
     AmountUnmatch,
+    /// not executed before the dealing expire and cancelled by the bonder
+    Expired,
 }
 
 /// Rtoken Identifier
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum BondState {
     /// dealing
     Dealing,
@@ -79,6 +84,18 @@ pub struct UserUnlockChunk {
     pub recipient: Vec<u8>
 }
 
+/// Unlock state of a user unbond
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum UnlockState {
+    /// unlock era not reached
+    Pending,
+    /// unlock era reached, waiting for the pool to withdraw and transfer
+    Withdrawable,
+    /// transferred to the recipient
+    Transferred,
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 pub struct BondSwap<AccountId, BlockNumber> {
     pub bonder: AccountId,
@@ -97,3 +114,63 @@ impl<A, B: PartialOrd> BondSwap<A, B> {
         !self.refunded && self.bond_state == BondState::Fail && self.expire > now
     }
 }
+
+/// fees paid by a dealing bond record, refunded if the record is cancelled
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct BondDealing<AccountId, BlockNumber> {
+    pub bond_fee: Balance,
+    pub fee_receiver: AccountId,
+    pub expire: BlockNumber,
+}
+
+impl<A, B: PartialOrd> BondDealing<A, B> {
+    pub fn cancellable(&self, now: B) -> bool {
+        self.expire <= now
+    }
+}
+
+/// Accounting invariant checked by `check_invariants`
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum InvariantCheck {
+    /// active of the pools against the total expected active of the current era
+    PoolsActive,
+    /// pool unbonds against account unbonds of an unlock era
+    PoolUnbonds,
+    /// rate against (active + bond) of the pools over the rtoken issuance
+    Rate,
+}
+
+/// A broken invariant, `pool` is none for the symbol wide checks
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct InvariantViolation {
+    pub symbol: RSymbol,
+    pub pool: Option<Vec<u8>>,
+    pub check: InvariantCheck,
+    /// current era, or the unlock era for `PoolUnbonds`
+    pub era: u32,
+    pub actual: u128,
+    pub expected: u128,
+}
+
+/// How the pool of bonds and unbonds of a symbol is chosen
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum PoolSelection {
+    /// users pass the pool
+    Manual,
+    /// unbonds are given a pool on chain by the strategy, bonds are sent to the pool `select_bond_pool` returns
+    Auto(PoolStrategy),
+}
+
+impl Default for PoolSelection {
+    fn default() -> Self {
+        PoolSelection::Manual
+    }
+}
+
+/// How pools are picked in `PoolSelection::Auto`
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum PoolStrategy {
+    /// see `LeastLoaded`
+    LeastLoaded,
+}
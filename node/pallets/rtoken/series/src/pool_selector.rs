@@ -0,0 +1,77 @@
+use sp_std::prelude::*;
+use rtoken_ledger::LinkChunk;
+use crate::PoolStrategy;
+
+/// Picks the pools of a symbol in `PoolSelection::Auto`, from the bond pipelines of its bonded pools
+pub trait SelectPool {
+    /// pool to bond `amount` into, none if no pool has room for it under `limit`, zero `limit` means no limit
+    fn bond_pool(pipelines: &[(Vec<u8>, LinkChunk)], amount: u128, limit: u128) -> Option<Vec<u8>>;
+    /// pool to unbond `amount` from, none if no pool has enough active
+    fn unbond_pool(pipelines: &[(Vec<u8>, LinkChunk)], amount: u128) -> Option<Vec<u8>>;
+}
+
+/// Bonds go to the pool with the least active, unbonds to the one with the most
+pub struct LeastLoaded;
+
+impl SelectPool for LeastLoaded {
+    fn bond_pool(pipelines: &[(Vec<u8>, LinkChunk)], amount: u128, limit: u128) -> Option<Vec<u8>> {
+        pipelines.iter()
+            .filter(|(_, pipe)| limit == 0 || pipe.active.saturating_add(amount) <= limit)
+            .min_by_key(|(_, pipe)| pipe.active)
+            .map(|(pool, _)| pool.clone())
+    }
+
+    fn unbond_pool(pipelines: &[(Vec<u8>, LinkChunk)], amount: u128) -> Option<Vec<u8>> {
+        pipelines.iter()
+            .filter(|(_, pipe)| pipe.active >= amount)
+            .max_by_key(|(_, pipe)| pipe.active)
+            .map(|(pool, _)| pool.clone())
+    }
+}
+
+impl PoolStrategy {
+    /// pool the strategy bonds `amount` into, see `SelectPool::bond_pool`
+    pub fn bond_pool(&self, pipelines: &[(Vec<u8>, LinkChunk)], amount: u128, limit: u128) -> Option<Vec<u8>> {
+        match self {
+            PoolStrategy::LeastLoaded => LeastLoaded::bond_pool(pipelines, amount, limit),
+        }
+    }
+
+    /// pool the strategy unbonds `amount` from, see `SelectPool::unbond_pool`
+    pub fn unbond_pool(&self, pipelines: &[(Vec<u8>, LinkChunk)], amount: u128) -> Option<Vec<u8>> {
+        match self {
+            PoolStrategy::LeastLoaded => LeastLoaded::unbond_pool(pipelines, amount),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pipelines() -> Vec<(Vec<u8>, LinkChunk)> {
+        vec![
+            (b"a".to_vec(), LinkChunk { bond: 0, unbond: 0, active: 300 }),
+            (b"b".to_vec(), LinkChunk { bond: 50, unbond: 0, active: 100 }),
+            (b"c".to_vec(), LinkChunk { bond: 0, unbond: 20, active: 200 }),
+        ]
+    }
+
+    #[test]
+    fn bond_should_go_to_least_active_pool_with_room() {
+        assert_eq!(LeastLoaded::bond_pool(&pipelines(), 10, 0), Some(b"b".to_vec()));
+        // bonds pending in the pipeline are not counted against the limit
+        assert_eq!(LeastLoaded::bond_pool(&pipelines(), 10, 110), Some(b"b".to_vec()));
+        assert_eq!(LeastLoaded::bond_pool(&pipelines(), 10, 109), None);
+        assert_eq!(LeastLoaded::bond_pool(&pipelines(), 201, 300), None);
+        assert_eq!(LeastLoaded::bond_pool(&[], 10, 0), None);
+    }
+
+    #[test]
+    fn unbond_should_go_to_most_active_pool() {
+        assert_eq!(LeastLoaded::unbond_pool(&pipelines(), 10), Some(b"a".to_vec()));
+        assert_eq!(LeastLoaded::unbond_pool(&pipelines(), 300), Some(b"a".to_vec()));
+        assert_eq!(LeastLoaded::unbond_pool(&pipelines(), 301), None);
+        assert_eq!(LeastLoaded::unbond_pool(&[], 10), None);
+    }
+}
@@ -0,0 +1,336 @@
+use super::*;
+use super::mock::*;
+use frame_support::{assert_ok, assert_noop};
+use sp_core::H256;
+
+const POOL: &[u8] = b"pool";
+
+/// era 1 of SYMBOL reported, `POOL` with an active of 100 backing the 100 rtokens issued
+fn reported_era() {
+    bond_pool(POOL, 100);
+    ledger::ChainEras::insert(SYMBOL, 1);
+    ledger::ChainBondingDuration::insert(SYMBOL, 2);
+    ledger::TotalExpectedActive::insert(SYMBOL, 1, 100);
+}
+
+fn checks(symbol: RSymbol) -> Vec<InvariantCheck> {
+    RTokenSeries::check_invariants(symbol).into_iter().map(|violation| violation.check).collect()
+}
+
+#[test]
+fn consistent_accounts_should_hold_invariants() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        let chunk = UserUnlockChunk { pool: POOL.to_vec(), unlock_era: 3, value: 10, recipient: vec![1] };
+        AccountUnbonds::<Test>::insert(1, SYMBOL, vec![chunk]);
+        ledger::PoolUnbonds::<Test>::insert(SYMBOL, (POOL.to_vec(), 3), vec![Unbonding { who: 1, value: 10, recipient: vec![1] }]);
+        // the unbond of this era has left the active already
+        set_pipeline(POOL, 0, 10, 90);
+        assert_ok!(RBalances::burn(&POOL_OWNER, SYMBOL, 10));
+
+        assert_eq!(RTokenSeries::check_invariants(SYMBOL), vec![]);
+    });
+}
+
+#[test]
+fn bond_executed_after_report_should_hold_invariants() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        // a bond of 20 executed after the report is in both the bond and the active of the pool
+        set_pipeline(POOL, 20, 0, 120);
+        assert_ok!(RBalances::mint(&1, SYMBOL, 20));
+
+        assert_eq!(RTokenSeries::check_invariants(SYMBOL), vec![]);
+    });
+}
+
+#[test]
+fn unmatched_pool_unbonds_should_be_reported() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        ledger::PoolUnbonds::<Test>::insert(SYMBOL, (POOL.to_vec(), 5), vec![Unbonding { who: 1, value: 10, recipient: vec![1] }]);
+        let chunk = UserUnlockChunk { pool: b"other".to_vec(), unlock_era: 5, value: 7, recipient: vec![1] };
+        AccountUnbonds::<Test>::insert(2, SYMBOL, vec![chunk]);
+        // unbonds unlocked before the current era are not checked
+        ledger::PoolUnbonds::<Test>::insert(SYMBOL, (POOL.to_vec(), 0), vec![Unbonding { who: 1, value: 10, recipient: vec![1] }]);
+
+        let violations = RTokenSeries::check_invariants(SYMBOL);
+        assert_eq!(violations, vec![
+            InvariantViolation { symbol: SYMBOL, pool: Some(b"other".to_vec()), check: InvariantCheck::PoolUnbonds, era: 5, actual: 0, expected: 7 },
+            InvariantViolation { symbol: SYMBOL, pool: Some(POOL.to_vec()), check: InvariantCheck::PoolUnbonds, era: 5, actual: 10, expected: 0 },
+        ]);
+        // violations are per symbol
+        assert_eq!(checks(RSymbol::RKSM), vec![]);
+    });
+}
+
+#[test]
+fn drifted_pools_active_should_be_reported() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        ledger::TotalExpectedActive::insert(SYMBOL, 1, 90);
+
+        let violations = RTokenSeries::check_invariants(SYMBOL);
+        assert_eq!(violations, vec![
+            InvariantViolation { symbol: SYMBOL, pool: None, check: InvariantCheck::PoolsActive, era: 1, actual: 100, expected: 90 },
+        ]);
+
+        // not checked while the era is being reported
+        ledger::EraSnapShots::<Test>::insert(SYMBOL, 1, vec![H256::repeat_byte(1)]);
+        assert_eq!(checks(SYMBOL), vec![]);
+    });
+}
+
+#[test]
+fn drifted_rate_should_be_reported() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        assert_ok!(RBalances::mint(&1, SYMBOL, 100));
+
+        let violations = RTokenSeries::check_invariants(SYMBOL);
+        assert_eq!(violations, vec![
+            InvariantViolation {
+                symbol: SYMBOL,
+                pool: None,
+                check: InvariantCheck::Rate,
+                era: 1,
+                actual: rtoken_rate::RATEBASE.into(),
+                expected: u128::from(rtoken_rate::RATEBASE) / 2,
+            },
+        ]);
+    });
+}
+
+#[test]
+fn auto_selection_should_reject_bonds_over_pool_limit() {
+    new_test_ext().execute_with(|| {
+        bond_pool(POOL, 100);
+        assert_ok!(RTokenSeries::set_balance_limit(Origin::root(), SYMBOL, 150));
+        let bond = |amount: u128| RTokenSeries::liquidity_bond(
+            Origin::signed(1), vec![], vec![], POOL.to_vec(), vec![1], vec![1], amount, SYMBOL,
+        );
+
+        // manual selection leaves pools to users, the signature is checked next
+        assert_noop!(bond(51), Error::<Test>::InvalidPubkey);
+        assert_eq!(RTokenSeries::select_bond_pool(SYMBOL, 50), None);
+
+        assert_ok!(RTokenSeries::set_pool_selection(Origin::root(), SYMBOL, PoolSelection::Auto(PoolStrategy::LeastLoaded)));
+        assert_noop!(bond(51), Error::<Test>::PoolLimitReached);
+        assert_noop!(bond(50), Error::<Test>::InvalidPubkey);
+        assert_eq!(RTokenSeries::select_bond_pool(SYMBOL, 50), Some(POOL.to_vec()));
+        assert_eq!(RTokenSeries::select_bond_pool(SYMBOL, 51), None);
+    });
+}
+
+/// a 10_000 by 10_000 rdex pool of SYMBOL with an instant unbond cap of 10%, unbonds without fees
+fn instant_unbond_setup(swap_pool: bool) {
+    bond_pool(POOL, 10_000);
+    ledger::ChainEras::insert(SYMBOL, 1);
+    ledger::ChainBondingDuration::insert(SYMBOL, 2);
+    <ledger::Receiver<Test>>::put(9);
+    <RelayFeesReceiver<Test>>::put(8);
+    UnbondFees::insert(SYMBOL, 0);
+    assert_ok!(RTokenSeries::set_unbond_commission(Origin::root(), 0));
+    assert_ok!(RTokenSeries::set_instant_unbond_cap(Origin::root(), SYMBOL, 100_000_000));
+    if swap_pool {
+        assert_ok!(RBalances::mint(&2, SYMBOL, 10_000));
+        assert_ok!(rdex_swap::Module::<Test>::create_pool(Origin::root(), 2, SYMBOL, 10_000, 10_000));
+    }
+    assert_ok!(RBalances::mint(&1, SYMBOL, 1_500));
+}
+
+fn instant_unbond(value: u128, min_swap_rate: rtoken_rate::RateType) -> DispatchResult {
+    RTokenSeries::instant_unbond(Origin::signed(1), SYMBOL, POOL.to_vec(), value, vec![1u8; 32], min_swap_rate)
+}
+
+fn queued_unbonds() -> Vec<u128> {
+    RTokenSeries::account_unbonds(1, SYMBOL).unwrap_or_default().into_iter().map(|chunk| chunk.value).collect()
+}
+
+#[test]
+fn instant_unbond_should_swap_up_to_cap_and_queue_the_remainder() {
+    new_test_ext().execute_with(|| {
+        instant_unbond_setup(true);
+        let swap_pool = rdex_swap::Module::<Test>::swap_pools(SYMBOL).unwrap();
+        let (_, fis_out, _, _) = rdex_swap::Module::<Test>::cal_pool_after_swap(&swap_pool, 1_000, false).unwrap();
+        let fis_before = Balances::free_balance(1);
+
+        assert_ok!(instant_unbond(1_500, rtoken_rate::RATEBASE / 2));
+        assert_eq!(Balances::free_balance(1), fis_before + fis_out);
+        assert_eq!(RBalances::free_balance(&1, SYMBOL), 0);
+        assert_eq!(queued_unbonds(), vec![500]);
+        assert_eq!(ledger::BondPipelines::get(SYMBOL, POOL.to_vec()).unwrap().unbond, 500);
+    });
+}
+
+#[test]
+fn instant_unbond_should_apply_min_swap_rate_per_swapped_unit() {
+    new_test_ext().execute_with(|| {
+        instant_unbond_setup(true);
+
+        // only the 1_000 under the cap are swapped, so a 1:1 rate asks 1_000 fis which slips
+        assert_noop!(instant_unbond(1_500, rtoken_rate::RATEBASE), rdex_swap::Error::<Test>::LessThanMinOutAmount);
+        assert_ok!(instant_unbond(100, rtoken_rate::RATEBASE * 9 / 10));
+        assert_eq!(queued_unbonds(), Vec::<u128>::new());
+    });
+}
+
+#[test]
+fn instant_unbond_without_swap_pool_should_queue_all() {
+    new_test_ext().execute_with(|| {
+        instant_unbond_setup(false);
+        let fis_before = Balances::free_balance(1);
+
+        assert_ok!(instant_unbond(1_500, rtoken_rate::RATEBASE));
+        assert_eq!(Balances::free_balance(1), fis_before);
+        assert_eq!(queued_unbonds(), vec![1_500]);
+    });
+}
+
+#[test]
+fn rebond_should_restore_queued_unbonds_and_update_claim_info() {
+    new_test_ext().execute_with(|| {
+        instant_unbond_setup(false);
+        assert_ok!(instant_unbond(1_500, rtoken_rate::RATEBASE));
+        assert_ok!(RClaim::add_rtoken_reward_act(Origin::root(), 1, 100, SYMBOL, 1_000_000, 0, 10, rtoken_rate::RATEBASE));
+        let rebond = |value: u128| RTokenSeries::rebond(Origin::signed(1), SYMBOL, POOL.to_vec(), value);
+
+        assert_noop!(rebond(1_501), Error::<Test>::RebondTooBig);
+        assert_ok!(RTokenSeries::toggle_bond_switch(Origin::root()));
+        assert_noop!(rebond(1_000), Error::<Test>::BondSwitchClosed);
+        assert_ok!(RTokenSeries::toggle_bond_switch(Origin::root()));
+        assert_ok!(RTokenSeries::set_pool_selection(Origin::root(), SYMBOL, PoolSelection::Auto(PoolStrategy::LeastLoaded)));
+        assert_ok!(RTokenSeries::set_balance_limit(Origin::root(), SYMBOL, 10_999));
+        assert_noop!(rebond(1_000), Error::<Test>::PoolLimitReached);
+        assert_ok!(RTokenSeries::set_balance_limit(Origin::root(), SYMBOL, 11_000));
+        assert_ok!(rebond(1_000));
+
+        assert_eq!(queued_unbonds(), vec![500]);
+        assert_eq!(ledger::BondPipelines::get(SYMBOL, POOL.to_vec()).unwrap().unbond, 500);
+        assert_eq!(RBalances::free_balance(&1, SYMBOL), 1_000);
+        assert_eq!(RClaim::user_mints_count((1, SYMBOL, 1)), 1);
+    });
+}
+
+/// a dealing bond record of `amount` by 1 into POOL, of tx (blockhash [1], txhash [2])
+fn dealing_bond(amount: u128) -> H256 {
+    let record = BondRecord::new(1, SYMBOL, vec![1], POOL.to_vec(), vec![1], vec![2], amount);
+    let bond_id = <Test as frame_system::Trait>::Hashing::hash_of(&record);
+    <BondRecords<Test>>::insert(SYMBOL, &bond_id, &record);
+    BondStates::insert(SYMBOL, (vec![1u8], vec![2u8]), BondState::Dealing);
+    bond_id
+}
+
+fn dealing_state() -> Option<BondState> {
+    RTokenSeries::bond_states(SYMBOL, (vec![1u8], vec![2u8]))
+}
+
+#[test]
+fn paused_rate_should_keep_bonds_dealing() {
+    new_test_ext().execute_with(|| {
+        bond_pool(POOL, 100);
+        let bond_id = dealing_bond(10);
+        rtoken_rate::PausedRate::insert(SYMBOL, rtoken_rate::RATEBASE);
+
+        assert_noop!(
+            RTokenSeries::execute_bond_record(Origin::signed(1), SYMBOL, bond_id, BondReason::Pass),
+            Error::<Test>::RatePaused
+        );
+        assert_noop!(
+            RTokenSeries::execute_bond_and_swap(Origin::signed(1), POOL.to_vec(), vec![3], vec![4], 10, SYMBOL, 1, vec![], 1, BondReason::Pass),
+            Error::<Test>::RatePaused
+        );
+        assert_eq!(dealing_state(), Some(BondState::Dealing));
+
+        assert_ok!(rtoken_rate::Module::<Test>::confirm_rate(Origin::root(), SYMBOL, rtoken_rate::RATEBASE));
+        assert_ok!(RTokenSeries::execute_bond_record(Origin::signed(1), SYMBOL, bond_id, BondReason::Pass));
+        assert_eq!(dealing_state(), Some(BondState::Success));
+        assert_eq!(RBalances::free_balance(&1, SYMBOL), 10);
+    });
+}
+
+#[test]
+fn cancel_bond_record_should_refund_fees_and_allow_retry() {
+    new_test_ext().execute_with(|| {
+        let bond_id = dealing_bond(10);
+        <BondDealings<Test>>::insert(SYMBOL, bond_id, BondDealing { bond_fee: 10, fee_receiver: 2, expire: 5 });
+        let swap = BondSwap {
+            bonder: 1, swap_fee: 20, swap_receiver: 3, bridger: 3, recipient: vec![], dest_id: 2,
+            expire: 0, bond_state: BondState::Dealing, refunded: false,
+        };
+        <BondSwaps<Test>>::insert(SYMBOL, bond_id, swap);
+
+        assert_noop!(RTokenSeries::cancel_bond_record(Origin::signed(2), SYMBOL, bond_id), Error::<Test>::NotBonder);
+        assert_noop!(RTokenSeries::cancel_bond_record(Origin::signed(1), SYMBOL, bond_id), Error::<Test>::BondDealingNotExpired);
+        assert_noop!(RTokenSeries::bond_record_index(&1, SYMBOL, &bond_id), Error::<Test>::BondRepeated);
+
+        System::set_block_number(5);
+        assert_ok!(RTokenSeries::cancel_bond_record(Origin::signed(1), SYMBOL, bond_id));
+        assert_eq!(Balances::free_balance(1), 1_000_030);
+        assert_eq!(Balances::free_balance(2), 999_990);
+        assert_eq!(Balances::free_balance(3), 999_980);
+        assert_eq!(dealing_state(), Some(BondState::Fail));
+        assert_eq!(RTokenSeries::bond_reasons(SYMBOL, bond_id), Some(BondReason::Expired));
+        assert!(RTokenSeries::bond_dealings(SYMBOL, bond_id).is_none());
+        let swap = RTokenSeries::bond_swaps(SYMBOL, bond_id).unwrap();
+        assert!(swap.refunded);
+        assert_eq!(swap.bond_state, BondState::Fail);
+
+        // the failed record is submitted again, it stays indexed once
+        assert_eq!(RTokenSeries::bond_record_index(&1, SYMBOL, &bond_id), Ok(None));
+        let record = RTokenSeries::bond_records(SYMBOL, bond_id).unwrap();
+        RTokenSeries::insert_bond_record(&1, SYMBOL, &bond_id, &record, None);
+        assert_eq!(dealing_state(), Some(BondState::Dealing));
+        assert!(RTokenSeries::bond_reasons(SYMBOL, bond_id).is_none());
+        assert_eq!(RTokenSeries::account_bond_count(SYMBOL, &1), 0);
+    });
+}
+
+#[test]
+fn dealing_expire_should_saturate() {
+    new_test_ext().execute_with(|| {
+        let bond_id = H256::repeat_byte(1);
+        assert_ok!(RTokenSeries::set_bond_dealing_expire(Origin::root(), SYMBOL, u64::max_value()));
+        RTokenSeries::insert_dealing(SYMBOL, &bond_id, 10, 8);
+
+        assert_eq!(RTokenSeries::bond_dealings(SYMBOL, bond_id).unwrap().expire, u64::max_value());
+    });
+}
+
+#[test]
+fn legacy_history_should_be_indexed_and_pruned() {
+    new_test_ext().execute_with(|| {
+        reported_era();
+        ledger::ChainEras::insert(SYMBOL, 3);
+        let record = BondRecord::new(1, SYMBOL, vec![1], POOL.to_vec(), vec![2], vec![3], 10);
+        let bond_id = <<Test as frame_system::Trait>::Hashing as Hash>::hash_of(&record);
+        BondStates::insert(SYMBOL, (vec![2], vec![3]), BondState::Success);
+        BondRecords::<Test>::insert(SYMBOL, bond_id, record);
+        let key = (1, POOL.to_vec(), OriginalTxType::Bond, vec![4]);
+        AccountSignature::<Test>::insert((1, SYMBOL, 1, POOL.to_vec(), OriginalTxType::Bond, vec![4]), vec![5]);
+
+        assert_noop!(
+            RTokenSeries::index_legacy_history(Origin::root(), SYMBOL, 1, vec![H256::repeat_byte(9)], vec![], vec![]),
+            Error::<Test>::LegacyKeyNotFound,
+        );
+        assert_noop!(
+            RTokenSeries::index_legacy_history(Origin::root(), SYMBOL, 2, vec![], vec![key.clone()], vec![]),
+            Error::<Test>::LegacyKeyNotFound,
+        );
+        assert_noop!(
+            RTokenSeries::index_legacy_history(Origin::root(), SYMBOL, 3, vec![bond_id], vec![], vec![]),
+            Error::<Test>::InvalidEraRange,
+        );
+
+        assert_ok!(RTokenSeries::index_legacy_history(Origin::root(), SYMBOL, 1, vec![bond_id], vec![key.clone()], vec![]));
+        // indexing twice keeps a single entry
+        assert_ok!(RTokenSeries::index_legacy_history(Origin::root(), SYMBOL, 1, vec![bond_id], vec![key], vec![]));
+        assert_eq!(RTokenSeries::era_bonds(SYMBOL, 1), vec![bond_id]);
+        assert_eq!(RTokenSeries::era_signatures(SYMBOL, 1).len(), 1);
+
+        assert_ok!(RTokenSeries::prune_eras(Origin::root(), SYMBOL, 1, 2));
+        assert!(RTokenSeries::bond_records(SYMBOL, bond_id).is_none());
+        assert!(RTokenSeries::account_signature((1, SYMBOL, 1, POOL.to_vec(), OriginalTxType::Bond, vec![4])).is_none());
+        assert!(RTokenSeries::pruned_txhashes(SYMBOL, RTokenSeries::txhash_key(&vec![2], &vec![3])));
+    });
+}
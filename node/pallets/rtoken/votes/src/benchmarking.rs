@@ -0,0 +1,44 @@
+//! Benchmarks for the RTokenVotes Pallet
+
+use super::*;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::storage::IterableStorageDoubleMap;
+use sp_runtime::traits::Hash;
+
+const SYMBOL: RSymbol = RSymbol::RDOT;
+
+fn remark<T: Trait>() -> T::Proposal where T::Proposal: From<frame_system::Call<T>> {
+    frame_system::Call::<T>::remark(vec![]).into()
+}
+
+benchmarks! {
+    where_clause { where T::Proposal: From<frame_system::Call<T>> }
+
+    _ { }
+
+    // a single relayer executes the proposal at once
+    acknowledge_proposal {
+        let caller: T::AccountId = whitelisted_caller();
+        relayers::Relayers::<T>::insert(SYMBOL, &caller, true);
+        relayers::RelayerCount::insert(SYMBOL, 1);
+        relayers::RelayerThreshold::insert(SYMBOL, 1);
+        let prop_id = T::Hashing::hash_of(&0u32);
+    }: _(RawOrigin::Signed(caller), SYMBOL, prop_id, true, Box::new(remark::<T>()))
+    verify {
+        assert_eq!(Votes::<T>::get(SYMBOL, (prop_id, remark::<T>())).map(|votes| votes.status), Some(RproposalStatus::Executed));
+    }
+
+    remove_completed_votes {
+        let n in 1 .. MAX_REMOVE_VOTES;
+        let keys: Vec<(T::Hash, T::Proposal)> = (0..n).map(|i| (T::Hashing::hash_of(&i), remark::<T>())).collect();
+        for key in keys.iter() {
+            let mut votes = RproposalVotes::default();
+            votes.status = RproposalStatus::Rejected;
+            Votes::<T>::insert(SYMBOL, key, votes);
+        }
+    }: _(RawOrigin::Root, SYMBOL, keys)
+    verify {
+        assert_eq!(Votes::<T>::iter_prefix(SYMBOL).count(), 0);
+    }
+}
@@ -9,16 +9,25 @@ use frame_support::{
     weights::{GetDispatchInfo, Pays},
 };
 
-use frame_system::{self as system, ensure_signed};
+use frame_system::{self as system, ensure_signed, ensure_root};
 use sp_runtime::{
-    RuntimeDebug, ModuleId,
+    RuntimeDebug, ModuleId, DispatchError,
     traits::{AccountIdConversion, Dispatchable}
 };
 use node_primitives::{RSymbol};
 use rtoken_relayers as relayers;
 use rtoken_ledger as ledger;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 const MODULE_ID: ModuleId = ModuleId(*b"rtk/vote");
+/// max proposals removed by one remove_completed_votes
+pub const MAX_REMOVE_VOTES: u32 = 100;
 
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
 pub enum RproposalStatus {
@@ -26,6 +35,7 @@ pub enum RproposalStatus {
     Approved,
     Rejected,
     Expired,
+    Executed,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
@@ -55,6 +65,16 @@ impl<A: PartialEq, B: PartialOrd + Default> RproposalVotes<A, B> {
         self.status != RproposalStatus::Initiated
     }
 
+    /// Returns true if the proposal was rejected or expired without being approved, so it can be removed.
+    /// Executed proposals are kept, so that the same call is never dispatched again.
+    fn is_removable(&self, now: B) -> bool {
+        match self.status {
+            RproposalStatus::Rejected | RproposalStatus::Expired => true,
+            RproposalStatus::Initiated => self.is_expired(now),
+            _ => false,
+        }
+    }
+
     /// Returns true if `who` has voted for or against the proposal
     fn has_voted(&self, who: &A) -> bool {
         self.votes_for.contains(&who) || self.votes_against.contains(&who)
@@ -64,6 +84,11 @@ impl<A: PartialEq, B: PartialOrd + Default> RproposalVotes<A, B> {
     fn is_expired(&self, now: B) -> bool {
         self.expiry <= now
     }
+
+    /// Set status to Executed
+    fn to_be_executed(&mut self) {
+        self.status = RproposalStatus::Executed;
+    }
 }
 
 impl<AccountId, BlockNumber: Default> Default for RproposalVotes<AccountId, BlockNumber> {
@@ -94,6 +119,18 @@ decl_event! {
         VoteFor(AccountId, RSymbol, Hash),
         /// Vot submitted against proposal
         VoteAgainst(AccountId, RSymbol, Hash),
+        /// Voting successful for a proposal
+        ProposalApproved(RSymbol, Hash),
+        /// Voting rejected a proposal
+        ProposalRejected(RSymbol, Hash),
+        /// Lifetime of a proposal exceeded before it was resolved
+        ProposalExpired(RSymbol, Hash),
+        /// Execution of call succeeded
+        ProposalExecuted(RSymbol, Hash),
+        /// Execution of call failed, it will be retried by the next acknowledge
+        ProposalFailed(RSymbol, Hash, DispatchError),
+        /// Completed proposals removed: symbol, number
+        VotesRemoved(RSymbol, u32),
     }
 }
 
@@ -103,6 +140,8 @@ decl_error! {
         ProposalDoesNotExist,
         /// Lifetime of proposal has been exceeded
         ProposalExpired,
+        /// more proposals than MAX_REMOVE_VOTES
+        TooManyVotes,
     }
 }
 
@@ -136,6 +175,25 @@ decl_module! {
             Self::commit_vote(who, symbol, prop_id, in_favour, call.clone())?;
             Self::try_resolve_proposal(symbol, prop_id, call)
         }
+
+        /// Removes the given proposals of a symbol which were rejected or expired, others are skipped.
+        #[weight = 10_000_000 + T::DbWeight::get().reads_writes(keys.len() as u64, keys.len() as u64)]
+        pub fn remove_completed_votes(origin, symbol: RSymbol, keys: Vec<(T::Hash, T::Proposal)>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(keys.len() <= MAX_REMOVE_VOTES as usize, Error::<T>::TooManyVotes);
+            let now = system::Module::<T>::block_number();
+
+            let mut removed: u32 = 0;
+            for key in keys.iter() {
+                if <Votes<T>>::get(symbol, key).map_or(false, |votes| votes.is_removable(now)) {
+                    <Votes<T>>::remove(symbol, key);
+                    removed += 1;
+                }
+            }
+
+            Self::deposit_event(RawEvent::VotesRemoved(symbol, removed));
+            Ok(())
+        }
     }
 }
 
@@ -175,26 +233,38 @@ impl<T: Trait> Module<T> {
         if votes.is_expired(now) {
             votes.status = RproposalStatus::Expired;
             <Votes<T>>::insert(symbol, (prop_id, prop.clone()), votes.clone());
+            Self::deposit_event(RawEvent::ProposalExpired(symbol, prop_id));
             Err(Error::<T>::ProposalExpired)?;
         }
 
-        votes.derivate(relayers::RelayerThreshold::get(symbol), relayers::RelayerCount::get(symbol));
+        match votes.derivate(relayers::RelayerThreshold::get(symbol), relayers::RelayerCount::get(symbol)) {
+            RproposalStatus::Approved => Self::deposit_event(RawEvent::ProposalApproved(symbol, prop_id)),
+            RproposalStatus::Rejected => Self::deposit_event(RawEvent::ProposalRejected(symbol, prop_id)),
+            _ => (),
+        }
         <Votes<T>>::insert(symbol, (prop_id, prop.clone()), votes);
 
         Ok(())
     }
 
-    /// Attempts to finalize or cancel the proposal if the vote count allows.
+    /// Attempts to execute the proposal once it is approved. Rejected and expired
+    /// proposals are already finalized by the vote and are never dispatched.
     fn try_resolve_proposal(symbol: RSymbol, prop_id: T::Hash, prop: Box<T::Proposal>) -> DispatchResult {
         let op_votes = <Votes<T>>::get(symbol, (prop_id, prop.clone()));
         ensure!(op_votes.is_some(), Error::<T>::ProposalDoesNotExist);
 
-        let votes = op_votes.unwrap();
+        let mut votes = op_votes.unwrap();
         match votes.status {
-            RproposalStatus::Approved | RproposalStatus::Rejected => {
-                prop.dispatch(system::RawOrigin::Signed(Self::account_id()).into())
-                    .map(|_| ())
-                    .map_err(|e| e.error)?;
+            RproposalStatus::Approved => {
+                let call = prop.clone();
+                match call.dispatch(system::RawOrigin::Signed(Self::account_id()).into()) {
+                    Ok(_) => {
+                        votes.to_be_executed();
+                        <Votes<T>>::insert(symbol, (prop_id, prop), votes);
+                        Self::deposit_event(RawEvent::ProposalExecuted(symbol, prop_id));
+                    },
+                    Err(e) => Self::deposit_event(RawEvent::ProposalFailed(symbol, prop_id, e.error)),
+                }
                 Ok(())
             },
             _ => Ok(()),
@@ -213,4 +283,9 @@ impl<T: Trait> EnsureOrigin<T::Origin> for EnsureVoter<T> {
             r => Err(T::Origin::from(r)),
         })
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> T::Origin {
+        T::Origin::from(system::RawOrigin::Signed(<Module<T>>::account_id()))
+    }
 }
\ No newline at end of file
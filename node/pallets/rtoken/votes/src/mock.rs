@@ -0,0 +1,121 @@
+use crate::{Module, Trait, EnsureVoter};
+use frame_support::{
+    impl_outer_dispatch, impl_outer_origin, parameter_types,
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+use node_primitives::RSymbol;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+impl_outer_dispatch! {
+    pub enum Call for Test where origin: Origin {
+        frame_system::System,
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const ProposalLifetime: u64 = 10;
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Test;
+
+impl frame_system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = ();
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+}
+
+impl Trait for Test {
+    type Event = ();
+    type Proposal = Call;
+    type ProposalLifetime = ProposalLifetime;
+}
+
+impl rtoken_relayers::Trait for Test {
+    type Event = ();
+    type WeightInfo = ();
+}
+
+impl rtoken_ledger::Trait for Test {
+    type Event = ();
+    type RCurrency = RBalances;
+    type VoterOrigin = EnsureVoter<Test>;
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_rate::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_registry::Trait for Test {
+    type Event = ();
+    type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type WeightInfo = ();
+}
+
+impl rtoken_balances::Trait for Test {
+    type Event = ();
+}
+
+pub const SYMBOL: RSymbol = RSymbol::RDOT;
+pub const RELAYERS: [u64; 3] = [1, 2, 3];
+
+/// three relayers of SYMBOL with a threshold of two
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for relayer in RELAYERS.iter() {
+            rtoken_relayers::Relayers::<Test>::insert(SYMBOL, relayer, true);
+        }
+        rtoken_relayers::RelayerCount::insert(SYMBOL, RELAYERS.len() as u32);
+        rtoken_relayers::RelayerThreshold::insert(SYMBOL, 2);
+    });
+    ext
+}
+
+pub type RTokenVotes = Module<Test>;
+pub type System = frame_system::Module<Test>;
+pub type RBalances = rtoken_balances::Module<Test>;
@@ -0,0 +1,123 @@
+use super::*;
+use super::mock::{*, Call};
+use frame_support::{assert_ok, assert_noop, assert_err};
+use sp_core::H256;
+
+fn remark() -> Box<Call> {
+    Box::new(Call::System(frame_system::Call::remark(vec![1])))
+}
+
+fn root_call() -> Box<Call> {
+    Box::new(Call::System(frame_system::Call::set_heap_pages(1)))
+}
+
+fn status(prop_id: H256, call: &Box<Call>) -> Option<RproposalStatus> {
+    RTokenVotes::votes(SYMBOL, (prop_id, *call.clone())).map(|votes| votes.status)
+}
+
+#[test]
+fn approved_proposal_should_be_executed_once() {
+    new_test_ext().execute_with(|| {
+        let prop_id = H256::repeat_byte(1);
+
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, prop_id, true, remark()));
+        assert_eq!(status(prop_id, &remark()), Some(RproposalStatus::Initiated));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(2), SYMBOL, prop_id, true, remark()));
+        assert_eq!(status(prop_id, &remark()), Some(RproposalStatus::Executed));
+
+        // a late vote neither changes the state nor dispatches again
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(3), SYMBOL, prop_id, true, remark()));
+        let votes = RTokenVotes::votes(SYMBOL, (prop_id, *remark())).unwrap();
+        assert_eq!(votes.status, RproposalStatus::Executed);
+        assert_eq!(votes.votes_for, vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn failed_dispatch_should_stay_approved() {
+    new_test_ext().execute_with(|| {
+        let prop_id = H256::repeat_byte(1);
+
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, prop_id, true, root_call()));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(2), SYMBOL, prop_id, true, root_call()));
+        assert_eq!(status(prop_id, &root_call()), Some(RproposalStatus::Approved));
+    });
+}
+
+#[test]
+fn rejected_proposal_should_not_be_executed() {
+    new_test_ext().execute_with(|| {
+        let prop_id = H256::repeat_byte(1);
+
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, prop_id, false, remark()));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(2), SYMBOL, prop_id, false, remark()));
+        assert_eq!(status(prop_id, &remark()), Some(RproposalStatus::Rejected));
+
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(3), SYMBOL, prop_id, true, remark()));
+        assert_eq!(status(prop_id, &remark()), Some(RproposalStatus::Rejected));
+    });
+}
+
+#[test]
+fn expired_proposal_should_not_be_executed() {
+    new_test_ext().execute_with(|| {
+        let prop_id = H256::repeat_byte(1);
+
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, prop_id, true, remark()));
+        System::set_block_number(1 + ProposalLifetime::get());
+        assert_err!(
+            RTokenVotes::acknowledge_proposal(Origin::signed(2), SYMBOL, prop_id, true, remark()),
+            Error::<Test>::ProposalExpired
+        );
+        assert_eq!(status(prop_id, &remark()), Some(RproposalStatus::Expired));
+    });
+}
+
+#[test]
+fn only_rejected_and_expired_votes_should_be_removed() {
+    new_test_ext().execute_with(|| {
+        let executed = H256::repeat_byte(1);
+        let rejected = H256::repeat_byte(2);
+        let expired = H256::repeat_byte(3);
+        let pending = H256::repeat_byte(4);
+
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, executed, true, remark()));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(2), SYMBOL, executed, true, remark()));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, rejected, false, remark()));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(2), SYMBOL, rejected, false, remark()));
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, expired, true, remark()));
+        System::set_block_number(1 + ProposalLifetime::get());
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(1), SYMBOL, pending, true, remark()));
+
+        let keys: Vec<(H256, Call)> = vec![executed, rejected, expired, pending].into_iter()
+            .map(|prop_id| (prop_id, *remark()))
+            .collect();
+        assert_ok!(RTokenVotes::remove_completed_votes(Origin::root(), SYMBOL, keys));
+
+        assert_eq!(status(executed, &remark()), Some(RproposalStatus::Executed));
+        assert_eq!(status(rejected, &remark()), None);
+        assert_eq!(status(expired, &remark()), None);
+        assert_eq!(status(pending, &remark()), Some(RproposalStatus::Initiated));
+
+        // the executed call may not be dispatched again
+        assert_ok!(RTokenVotes::acknowledge_proposal(Origin::signed(3), SYMBOL, executed, true, remark()));
+        assert_eq!(RTokenVotes::votes(SYMBOL, (executed, *remark())).unwrap().votes_for, vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn remove_completed_votes_should_be_bounded() {
+    new_test_ext().execute_with(|| {
+        let keys: Vec<(H256, Call)> = (0..=MAX_REMOVE_VOTES)
+            .map(|i| (H256::from_low_u64_be(i as u64), *remark()))
+            .collect();
+        assert_noop!(
+            RTokenVotes::remove_completed_votes(Origin::root(), SYMBOL, keys),
+            Error::<Test>::TooManyVotes
+        );
+        assert_noop!(
+            RTokenVotes::remove_completed_votes(Origin::signed(1), SYMBOL, vec![]),
+            sp_runtime::traits::BadOrigin
+        );
+    });
+}
@@ -4,6 +4,9 @@ use node_primitives::XSymbol;
 pub trait Currency<AccountId> {
 	/// The 'free' balance of a given account.
 	fn free_balance(who: &AccountId, symbol: XSymbol) -> u128;
+
+	/// The amount `spender` may still transfer out of the free balance of `owner`.
+	fn allowance(owner: &AccountId, spender: &AccountId, symbol: XSymbol) -> u128;
 	
 	/// Returns `Ok` iff the account is able to make a withdrawal of the given amount
 	/// for the given reason. Basically, it's just a dry-run of `withdraw`.
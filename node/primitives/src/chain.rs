@@ -16,4 +16,6 @@ pub type ChainId = u8;
 pub const ETH_CHAIN_ID: ChainId = 2;
 
 /// BSC Chain
-pub const BSC_CHAIN_ID: ChainId = 3;
\ No newline at end of file
+pub const BSC_CHAIN_ID: ChainId = 3;
+/// Resource ID of an asset on the bridge, 31 bytes unique ID + 1 byte chain ID
+pub type ResourceId = [u8; 32];
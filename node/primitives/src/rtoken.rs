@@ -11,30 +11,99 @@
 
 use codec::{Decode, Encode};
 use sp_runtime::RuntimeDebug;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 /// Rtoken Identifier
+///
+/// Assets are registered on chain, so an rtoken is only an index here. The
+/// constants below are the rtokens that existed before the registry.
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
-pub enum RSymbol {
+pub struct RSymbol(pub u8);
+
+impl RSymbol {
 	/// rFIS
-	RFIS,
+	pub const RFIS: RSymbol = RSymbol(0);
 	/// rDOT
-	RDOT,
+	pub const RDOT: RSymbol = RSymbol(1);
 	/// rKSM
-	RKSM,
+	pub const RKSM: RSymbol = RSymbol(2);
 	/// rATOM
-	RATOM,
+	pub const RATOM: RSymbol = RSymbol(3);
 	/// rSOL
-	RSOL,
+	pub const RSOL: RSymbol = RSymbol(4);
 	/// rMatic
-	RMATIC,
+	pub const RMATIC: RSymbol = RSymbol(5);
 	/// rBNB
-	RBNB,
+	pub const RBNB: RSymbol = RSymbol(6);
 	/// rETH
-	RETH,
+	pub const RETH: RSymbol = RSymbol(7);
+
+	/// rtokens that existed before the registry, with their names
+	pub const BUILTIN: [(RSymbol, &'static str); 8] = [
+		(RSymbol::RFIS, "RFIS"),
+		(RSymbol::RDOT, "RDOT"),
+		(RSymbol::RKSM, "RKSM"),
+		(RSymbol::RATOM, "RATOM"),
+		(RSymbol::RSOL, "RSOL"),
+		(RSymbol::RMATIC, "RMATIC"),
+		(RSymbol::RBNB, "RBNB"),
+		(RSymbol::RETH, "RETH"),
+	];
+
+	/// name of a builtin rsymbol, eg: RDOT => "RDOT"
+	pub fn builtin_name(&self) -> Option<&'static str> {
+		Self::BUILTIN.iter().find(|(symbol, _)| symbol == self).map(|(_, name)| *name)
+	}
+
+	/// builtin rsymbol of a name, eg: "RDOT" => RDOT
+	pub fn from_builtin_name(name: &str) -> Option<RSymbol> {
+		Self::BUILTIN.iter().find(|(_, n)| *n == name).map(|(symbol, _)| *symbol)
+	}
+}
+
+/// builtin rsymbols keep their names in json, registered ones are plain indexes
+#[cfg(feature = "std")]
+impl Serialize for RSymbol {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.builtin_name() {
+			Some(name) => serializer.serialize_str(name),
+			None => serializer.serialize_u8(self.0),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for RSymbol {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct RSymbolVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for RSymbolVisitor {
+			type Value = RSymbol;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+				formatter.write_str("a builtin rsymbol name or an rsymbol index")
+			}
+
+			fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<RSymbol, E> {
+				RSymbol::from_builtin_name(name).ok_or_else(|| E::custom(format!("unknown rsymbol {}", name)))
+			}
+
+			fn visit_u64<E: serde::de::Error>(self, index: u64) -> Result<RSymbol, E> {
+				if index > u8::max_value() as u64 {
+					return Err(E::custom(format!("rsymbol index {} out of range", index)));
+				}
+				Ok(RSymbol(index as u8))
+			}
+		}
+
+		deserializer.deserialize_any(RSymbolVisitor)
+	}
 }
 
 /// Chain Type
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ChainType {
 	/// substrate
 	Substrate,
@@ -45,15 +114,3 @@ pub enum ChainType {
 	/// ethereum
 	Ethereum,
 }
-
-impl RSymbol {
-	/// get chain type of rsymbol, eg: RDOT => Substrate
-	pub fn chain_type(&self) -> ChainType {
-		match self {
-			RSymbol::RFIS | RSymbol::RDOT | RSymbol::RKSM => ChainType::Substrate,
-			RSymbol::RATOM => ChainType::Tendermint,
-			RSymbol::RSOL => ChainType::Solana,
-			RSymbol::RMATIC | RSymbol::RBNB | RSymbol::RETH => ChainType::Ethereum,
-		}
-	}
-}
@@ -12,9 +12,12 @@
 
 use codec::{Encode, Decode};
 use sp_runtime::RuntimeDebug;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
 
 /// Xtoken Identifier
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum XSymbol {
     /// WRA
     WRA,
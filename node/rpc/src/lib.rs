@@ -108,6 +108,9 @@ pub fn create_full<C, P, SC, B>(
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: rtoken_ledger_rpc::RTokenLedgerRuntimeApi<Block, AccountId, Hash>,
+	C::Api: rtoken_series_rpc::RTokenSeriesRuntimeApi<Block, AccountId, Hash>,
+	C::Api: rdex_swap_rpc::RDexSwapRuntimeApi<Block>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
@@ -117,6 +120,9 @@ pub fn create_full<C, P, SC, B>(
 {
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+	use rtoken_ledger_rpc::{RTokenLedger, RTokenLedgerApi};
+	use rtoken_series_rpc::{RTokenSeries, RTokenSeriesApi};
+	use rdex_swap_rpc::{RDexSwap, RDexSwapApi};
 
 	let mut io = jsonrpc_core::IoHandler::default();
 	let FullDeps {
@@ -150,6 +156,15 @@ pub fn create_full<C, P, SC, B>(
 	io.extend_with(
 		TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone()))
 	);
+	io.extend_with(
+		RTokenLedgerApi::to_delegate(RTokenLedger::new(client.clone()))
+	);
+	io.extend_with(
+		RTokenSeriesApi::to_delegate(RTokenSeries::new(client.clone()))
+	);
+	io.extend_with(
+		RDexSwapApi::to_delegate(RDexSwap::new(client.clone()))
+	);
 	io.extend_with(
 		sc_consensus_babe_rpc::BabeApi::to_delegate(
 			BabeRpcHandler::new(
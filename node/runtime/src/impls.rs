@@ -11,10 +11,10 @@
 
 //! Some configurable implementations as associated type for the stafi runtime.
 
-use node_primitives::Balance;
+use node_primitives::{AccountId, Balance};
 use sp_runtime::traits::Convert;
 use frame_support::traits::{OnUnbalanced, Currency};
-use crate::{Balances, Authorship, NegativeImbalance};
+use crate::{Balances, Authorship, NegativeImbalance, Session, ImOnline};
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -39,6 +39,17 @@ impl Convert<u128, Balance> for CurrencyToVoteHandler {
 	fn convert(x: u128) -> Balance { x * Self::factor() }
 }
 
+/// Liveness from im-online, for the rfis nomination score.
+pub struct ValidatorStatusProvider;
+
+impl rfis::ValidatorStatus<AccountId> for ValidatorStatusProvider {
+	fn is_online(who: &AccountId) -> bool {
+		Session::validators().iter()
+			.position(|v| v == who)
+			.map_or(false, |index| ImOnline::is_online(index as u32))
+	}
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use super::*;
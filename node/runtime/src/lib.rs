@@ -35,7 +35,7 @@ use sp_core::{
 };
 use sp_io::hashing::blake2_128;
 pub use node_primitives::{AccountId, Signature};
-use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment, ChainId};
+use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment, ChainId, RSymbol};
 use sp_api::impl_runtime_apis;
 use sp_runtime::{
 	Permill, Perbill, Perquintill, Percent, ApplyExtrinsicResult,
@@ -71,7 +71,7 @@ pub use pallet_staking::StakerStatus;
 
 /// Implementations of some helper traits passed into runtime modules as associated types.
 pub mod impls;
-use impls::{CurrencyToVoteHandler, Author};
+use impls::{CurrencyToVoteHandler, Author, ValidatorStatusProvider};
 
 /// Constant values used within the runtime.
 pub mod constants;
@@ -357,12 +357,21 @@ impl rdex_balances::Trait for Runtime {
 
 impl rtoken_rate::Trait for Runtime {
 	type Event = Event;
+	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = weights::rtoken_rate::WeightInfo;
+}
+
+impl rtoken_registry::Trait for Runtime {
+	type Event = Event;
+	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = weights::rtoken_registry::WeightInfo;
 }
 
 impl rfis::Trait for Runtime {
 	type Event = Event;
 	type RCurrency = RBalances;
 	type UnsignedPriority = RFisUnsignedPriority;
+	type ValidatorStatus = ValidatorStatusProvider;
 }
 
 parameter_types! {
@@ -758,7 +767,7 @@ parameter_types! {
 impl pallet_offences::Trait for Runtime {
 	type Event = Event;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
-	type OnOffenceHandler = Staking;
+	type OnOffenceHandler = rfis::SlashRecorder<Self, Staking>;
 	type WeightSoftLimit = OffencesWeightSoftLimit;
 }
 
@@ -863,6 +872,7 @@ impl bridge_common::Trait for Runtime {
 	type Proposal = Call;
 	type ChainIdentity = ChainIdentity;
 	type ProposalLifetime = ProposalLifetime;
+	type WeightInfo = weights::bridge_common::WeightInfo;
 }
 
 parameter_types! {
@@ -879,6 +889,7 @@ impl bridge_swap::Trait for Runtime {
 
 impl rtoken_relayers::Trait for Runtime {
 	type Event = Event;
+	type WeightInfo = weights::rtoken_relayers::WeightInfo;
 }
 
 impl rtoken_votes::Trait for Runtime {
@@ -891,12 +902,15 @@ impl rtoken_ledger::Trait for Runtime {
 	type Event = Event;
 	type RCurrency = RBalances;
 	type VoterOrigin = rtoken_votes::EnsureVoter<Runtime>;
+	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = weights::rtoken_ledger::WeightInfo;
 }
 
 impl rtoken_series::Trait for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type RCurrency = RBalances;
+	type WeightInfo = weights::rtoken_series::WeightInfo;
 }
 
 impl xclaim::Trait for Runtime {
@@ -907,6 +921,7 @@ impl xclaim::Trait for Runtime {
 impl rclaim::Trait for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type WeightInfo = weights::rclaim::WeightInfo;
 }
 
 
@@ -917,12 +932,14 @@ impl rdexn_payers::Trait for Runtime {
 impl rdexn_signatures::Trait for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type WeightInfo = weights::rdexn_signatures::WeightInfo;
 }
 
 impl rdexn_swap::Trait for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type RCurrency = RBalances;
+	type WeightInfo = weights::rdexn_swap::WeightInfo;
 }
 
 impl rdex_swap::Trait for Runtime {
@@ -930,12 +947,14 @@ impl rdex_swap::Trait for Runtime {
 	type Currency = Balances;
 	type RCurrency = RBalances;
 	type LpCurrency = LpBalances;
+	type WeightInfo = weights::rdex_swap::WeightInfo;
 }
 
 impl rdex_mining::Trait for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type LpCurrency = LpBalances;
+	type WeightInfo = weights::rdex_mining::WeightInfo;
 }
 
 construct_runtime!(
@@ -990,9 +1009,10 @@ construct_runtime!(
 		RDexnPayers: rdexn_payers::{Module, Call, Storage, Event<T>},
 		RDexnSignatures: rdexn_signatures::{Module, Call, Storage, Event<T>},
 		RDexnSwap: rdexn_swap::{Module, Call, Storage, Event<T>},
-		RDexSwap: rdex_swap::{Module, Call, Storage, Event<T>},
+		RDexSwap: rdex_swap::{Module, Call, Storage, Event<T>, Config},
 		LpBalances: rdex_balances::{Module, Call, Storage, Event<T>},
 		RDexMining: rdex_mining::{Module, Call, Storage, Event<T>},
+		RTokenRegistry: rtoken_registry::{Module, Call, Storage, Event, Config},
 	}
 );
 
@@ -1185,6 +1205,72 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl rtoken_ledger_rpc_runtime_api::RTokenLedgerApi<Block, AccountId, Hash> for Runtime {
+		fn chain_era(symbol: RSymbol) -> Option<u32> {
+			RTokenLedger::chain_eras(symbol)
+		}
+
+		fn bond_pipelines(symbol: RSymbol) -> Vec<(Vec<u8>, rtoken_ledger::LinkChunk)> {
+			RTokenLedger::pool_pipelines(symbol)
+		}
+
+		fn pending_snapshots(symbol: RSymbol) -> Vec<(Hash, rtoken_ledger::BondSnapshot<AccountId>)> {
+			RTokenLedger::pending_snapshots(symbol)
+		}
+
+		fn rate(symbol: RSymbol) -> Option<rtoken_rate::RateType> {
+			RTokenRate::rate(symbol)
+		}
+
+		fn era_rate(symbol: RSymbol, era: u32) -> Option<rtoken_rate::RateType> {
+			RTokenRate::era_rate(symbol, era)
+		}
+
+		fn token_to_rtoken(symbol: RSymbol, amount: u128) -> u128 {
+			RTokenRate::token_to_rtoken(symbol, amount)
+		}
+
+		fn rtoken_to_token(symbol: RSymbol, amount: u128) -> u128 {
+			RTokenRate::rtoken_to_token(symbol, amount)
+		}
+	}
+
+	impl rtoken_series_rpc_runtime_api::RTokenSeriesApi<Block, AccountId, Hash> for Runtime {
+		fn unbond_status(who: AccountId, symbol: RSymbol) -> Vec<(rtoken_series::UserUnlockChunk, rtoken_series::UnlockState)> {
+			RTokenSeries::unbond_status(&who, symbol)
+		}
+
+		fn bond_records(who: AccountId, symbol: RSymbol) -> Vec<(Hash, rtoken_series::BondRecord<AccountId>, Option<rtoken_series::BondState>)> {
+			RTokenSeries::account_bonds(&who, symbol)
+		}
+
+		fn check_invariants(symbol: RSymbol) -> Vec<rtoken_series::InvariantViolation> {
+			RTokenSeries::check_invariants(symbol)
+		}
+
+		fn select_bond_pool(symbol: RSymbol, amount: u128) -> Option<Vec<u8>> {
+			RTokenSeries::select_bond_pool(symbol, amount)
+		}
+	}
+
+	impl rdex_swap_rpc_runtime_api::RDexSwapApi<Block> for Runtime {
+		fn quote_swap(symbol: RSymbol, input_amount: u128, input_is_fis: bool) -> Option<(u128, u128)> {
+			RDexSwap::quote_swap(symbol, input_amount, input_is_fis)
+		}
+
+		fn quote_add_liquidity(symbol: RSymbol, rtoken_amount: u128, fis_amount: u128) -> Option<(u128, u128)> {
+			RDexSwap::quote_add_liquidity(symbol, rtoken_amount, fis_amount)
+		}
+
+		fn quote_remove_liquidity(symbol: RSymbol, rm_unit: u128, swap_unit: u128, input_is_fis: bool) -> Option<(u128, u128)> {
+			RDexSwap::quote_remove_liquidity(symbol, rm_unit, swap_unit, input_is_fis)
+		}
+
+		fn quote_swap_via_fis(input_symbol: RSymbol, output_symbol: RSymbol, input_amount: u128) -> Option<(u128, u128, u128, u128)> {
+			RDexSwap::quote_swap_via_fis(input_symbol, output_symbol, input_amount)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -1252,6 +1338,18 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_treasury, Treasury);
 			add_benchmark!(params, batches, pallet_utility, Utility);
 			add_benchmark!(params, batches, pallet_vesting, Vesting);
+			add_benchmark!(params, batches, bridge_common, BridgeCommon);
+			add_benchmark!(params, batches, rtoken_registry, RTokenRegistry);
+			add_benchmark!(params, batches, rtoken_rate, RTokenRate);
+			add_benchmark!(params, batches, rtoken_relayers, RTokenRelayers);
+			add_benchmark!(params, batches, rtoken_ledger, RTokenLedger);
+			add_benchmark!(params, batches, rtoken_votes, RTokenVotes);
+			add_benchmark!(params, batches, rtoken_series, RTokenSeries);
+			add_benchmark!(params, batches, rclaim, RClaim);
+			add_benchmark!(params, batches, rdexn_swap, RDexnSwap);
+			add_benchmark!(params, batches, rdexn_signatures, RDexnSignatures);
+			add_benchmark!(params, batches, rdex_swap, RDexSwap);
+			add_benchmark!(params, batches, rdex_mining, RDexMining);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
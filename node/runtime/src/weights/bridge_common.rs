@@ -0,0 +1,89 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for the BridgeCommon Pallet
+//!
+//! Not benchmarked yet: each base weight is the larger of the constant the call had before
+//! and an estimate, on top of the database reads and writes of the call. Regenerate this
+//! file with `scripts/benchmark.sh bridge_common`.
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+pub struct WeightInfo;
+impl bridge_common::WeightInfo for WeightInfo {
+	fn add_resource() -> Weight {
+		(195000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_resource() -> Weight {
+		(195000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn map_resource_and_rsymbol() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn unmap_resource_and_rsymbol() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn map_resource_and_xsymbol() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn unmap_resource_and_xsymbol() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn acknowledge_proposal() -> Weight {
+		(195000000 as Weight)
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn whitelist_chain() -> Weight {
+		(195000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_whitelist_chain() -> Weight {
+		(195000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_proxy_accounts() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_proxy_accounts() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_chain_fees() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_fees_recipient_account() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_is_pasued() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_migrate_target() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+}
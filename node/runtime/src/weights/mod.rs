@@ -28,3 +28,14 @@ pub mod pallet_timestamp;
 pub mod pallet_utility;
 pub mod pallet_vesting;
 pub mod pallet_elections_phragmen;
+pub mod bridge_common;
+pub mod rtoken_registry;
+pub mod rtoken_rate;
+pub mod rtoken_relayers;
+pub mod rtoken_ledger;
+pub mod rtoken_series;
+pub mod rclaim;
+pub mod rdexn_swap;
+pub mod rdexn_signatures;
+pub mod rdex_swap;
+pub mod rdex_mining;
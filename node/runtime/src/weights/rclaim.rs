@@ -0,0 +1,65 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for the RClaim Pallet
+//!
+//! Not benchmarked yet: each base weight is the larger of the constant the call had before
+//! and an estimate, on top of the database reads and writes of the call. Regenerate this
+//! file with `scripts/benchmark.sh rclaim`.
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+pub struct WeightInfo;
+impl rclaim::WeightInfo for WeightInfo {
+	fn set_reth_rewarder() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_fund_address() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn add_rtoken_reward_act() -> Weight {
+		(35000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn update_rtoken_reward_act() -> Weight {
+		(30000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn add_reth_reward_act() -> Weight {
+		(35000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn update_reth_reward_act() -> Weight {
+		(30000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn claim_rtoken_reward() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn claim_reth_reward() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn update_reth_claim_info() -> Weight {
+		(2400000000 as Weight)
+			.saturating_add(DbWeight::get().reads(603 as Weight))
+			.saturating_add(DbWeight::get().writes(799 as Weight))
+	}
+}
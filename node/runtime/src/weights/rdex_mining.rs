@@ -0,0 +1,93 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for the RDexMining Pallet
+//!
+//! Not benchmarked yet: each base weight is the larger of the constant the call had before
+//! and an estimate, on top of the database reads and writes of the call. Regenerate this
+//! file with `scripts/benchmark.sh rdex_mining`.
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+pub struct WeightInfo;
+impl rdex_mining::WeightInfo for WeightInfo {
+	fn deposit() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(6 as Weight))
+	}
+	fn withdraw() -> Weight {
+		(20000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(11 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn claim_reward() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn emergency_withdraw() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn add_pool() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn update_stake_pool() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn rm_pool() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn increase_pool_index() -> Weight {
+		(20000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn emergency_switch() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn withdraw_guard_fund() -> Weight {
+		(70000000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn set_guard_line() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_guard_reserve() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn add_lp_to_whitelist() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_lp_from_whitelist() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn toggle_lp_switch() -> Weight {
+		(20000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,74 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for the RDexSwap Pallet
+//!
+//! Not benchmarked yet: each base weight is the larger of the constant the call had before
+//! and an estimate, on top of the database reads and writes of the call. Regenerate this
+//! file with `scripts/benchmark.sh rdex_swap`.
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+pub struct WeightInfo;
+impl rdex_swap::WeightInfo for WeightInfo {
+	fn swap() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(6 as Weight))
+	}
+	fn swap_via_fis() -> Weight {
+		(20000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(10 as Weight))
+			.saturating_add(DbWeight::get().writes(8 as Weight))
+	}
+	fn add_liquidity() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(9 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn remove_liquidity() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(10 as Weight))
+			.saturating_add(DbWeight::get().writes(8 as Weight))
+	}
+	fn create_pool() -> Weight {
+		(100000000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn add_lp_to_whitelist() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_lp_from_whitelist() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_fee_model() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee_share() -> Weight {
+		(20000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee_receiver() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn toggle_lp_switch() -> Weight {
+		(20000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+}
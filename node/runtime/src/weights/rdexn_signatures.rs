@@ -0,0 +1,32 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for the RDexnSignatures Pallet
+//!
+//! Not benchmarked yet: each base weight is an estimate on top of the database reads and
+//! writes of the call, a signature is verified against each sub account of the payer pool.
+//! Regenerate this file with `scripts/benchmark.sh rdexn_signatures`.
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+pub struct WeightInfo;
+impl rdexn_signatures::WeightInfo for WeightInfo {
+	fn set_payer_pool() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn submit_signatures(s: u32, ) -> Weight {
+		(50000000 as Weight)
+			.saturating_add((65000000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(7 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+}
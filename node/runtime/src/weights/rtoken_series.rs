@@ -0,0 +1,160 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for the RTokenSeries Pallet
+//!
+//! Not benchmarked yet: each base weight is the larger of the constant the call had before
+//! and an estimate, on top of the database reads and writes of the call. Regenerate this
+//! file with `scripts/benchmark.sh rtoken_series`.
+
+use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
+
+pub struct WeightInfo;
+impl rtoken_series::WeightInfo for WeightInfo {
+	fn toggle_bond_switch() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn toggle_rtoken_bond_switch() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_relay_fees_receiver() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_proxy_accounts() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_proxy_accounts() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_bond_fees() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_unbond_fees() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_balance_limit() -> Weight {
+		(20000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_unbond_commission() -> Weight {
+		(20000000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn init_nominations() -> Weight {
+		(35000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn update_nominations() -> Weight {
+		(50000000 as Weight)
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn update_validator() -> Weight {
+		(40000000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn swap_refund_expire() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn liquidity_bond() -> Weight {
+		(10000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(12 as Weight))
+			.saturating_add(DbWeight::get().writes(8 as Weight))
+	}
+	fn liquidity_bond_and_swap() -> Weight {
+		(30000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(18 as Weight))
+			.saturating_add(DbWeight::get().writes(10 as Weight))
+	}
+	fn execute_bond_record() -> Weight {
+		(180000000 as Weight)
+			.saturating_add(DbWeight::get().reads(16 as Weight))
+			.saturating_add(DbWeight::get().writes(13 as Weight))
+	}
+	fn liquidity_unbond() -> Weight {
+		(30000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(27 as Weight))
+			.saturating_add(DbWeight::get().writes(10 as Weight))
+	}
+	fn submit_signatures() -> Weight {
+		(1100000000 as Weight)
+			.saturating_add(DbWeight::get().reads(9 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn refund_swap_fee() -> Weight {
+		(5000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn execute_bond_and_swap() -> Weight {
+		(170000000 as Weight)
+			.saturating_add(DbWeight::get().reads(17 as Weight))
+			.saturating_add(DbWeight::get().writes(13 as Weight))
+	}
+	fn set_pool_selection() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn instant_unbond() -> Weight {
+		(30000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(35 as Weight))
+			.saturating_add(DbWeight::get().writes(16 as Weight))
+	}
+	fn set_instant_unbond_cap() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_bond_dealing_expire() -> Weight {
+		(15000000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_bond_record() -> Weight {
+		(5000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn set_retention_eras() -> Weight {
+		(25000000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn prune_eras() -> Weight {
+		(5000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(200 as Weight))
+			.saturating_add(DbWeight::get().writes(390 as Weight))
+	}
+	fn index_legacy_history() -> Weight {
+		(1000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(68 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn rebond() -> Weight {
+		(30000000000 as Weight)
+			.saturating_add(DbWeight::get().reads(9 as Weight))
+			.saturating_add(DbWeight::get().writes(5 as Weight))
+	}
+}
@@ -16,7 +16,7 @@ use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
 use node_runtime::{
 	GenesisConfig, BalancesConfig, SessionConfig, StakingConfig, SystemConfig,
 	GrandpaConfig, IndicesConfig, wasm_binary_unwrap,
-	AccountId, StakerStatus,
+	AccountId, StakerStatus, RTokenRegistryConfig,
 };
 use node_runtime::constants::currency::*;
 use sp_core::ChangesTrieConfiguration;
@@ -104,5 +104,9 @@ pub fn config_endowed(
 		pallet_sudo: Some(Default::default()),
 		pallet_treasury: Some(Default::default()),
 		pallet_vesting: Some(Default::default()),
+		rtoken_registry: Some(RTokenRegistryConfig {
+			assets: rtoken_registry::builtin_assets(),
+		}),
+		rdex_swap: Some(Default::default()),
 	}
 }
@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+# Benchmarks the Stafi pallets and writes their weight files into node/runtime/src/weights.
+# Usage: scripts/benchmark.sh [pallet ...], all the Stafi pallets by default.
+
+set -e
+
+cd "$(dirname "$0")/.."
+
+PALLETS=${@:-"bridge_common rclaim rdex_mining rdex_swap rdexn_signatures rdexn_swap rtoken_ledger rtoken_rate rtoken_registry rtoken_relayers rtoken_series"}
+WEIGHTS=node/runtime/src/weights
+HEADER=$(sed -n '1,10p' $WEIGHTS/pallet_balances.rs)
+
+cargo build --release --features runtime-benchmarks -p node-cli
+STAFI=$(pwd)/target/release/stafi
+
+for pallet in $PALLETS; do
+	echo "Benchmarking $pallet"
+	(
+		cd $WEIGHTS
+		$STAFI benchmark \
+			--chain dev \
+			--execution wasm \
+			--wasm-execution compiled \
+			--pallet "$pallet" \
+			--extrinsic '*' \
+			--steps 50 \
+			--repeat 20 \
+			--output
+		{ echo "$HEADER"; echo; cat "$pallet.rs"; } > "$pallet.rs.tmp"
+		mv "$pallet.rs.tmp" "$pallet.rs"
+	)
+done